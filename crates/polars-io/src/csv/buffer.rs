@@ -9,8 +9,18 @@ use polars_time::prelude::string::infer::{
 };
 
 use crate::csv::parser::{is_whitespace, skip_whitespace};
-use crate::csv::utils::escape_field;
-use crate::csv::CsvEncoding;
+use crate::csv::utils::{decode_latin1, escape_field};
+use crate::csv::{CsvEncoding, CsvError};
+
+/// The parser fills in where the field is.
+fn invalid_utf8() -> PolarsError {
+    CsvError::InvalidUtf8 {
+        line: 0,
+        offset: 0,
+        col: 0,
+    }
+    .into()
+}
 
 pub(crate) trait PrimitiveParser: PolarsNumericType {
     fn parse(bytes: &[u8]) -> Option<Self::Native>;
@@ -150,16 +160,24 @@ pub(crate) struct Utf8Field {
     mutable: MutableBinaryViewArray<str>,
     scratch: Vec<u8>,
     quote_char: u8,
+    escape_char: Option<u8>,
     encoding: CsvEncoding,
 }
 
 impl Utf8Field {
-    fn new(name: &str, capacity: usize, quote_char: Option<u8>, encoding: CsvEncoding) -> Self {
+    fn new(
+        name: &str,
+        capacity: usize,
+        quote_char: Option<u8>,
+        escape_char: Option<u8>,
+        encoding: CsvEncoding,
+    ) -> Self {
         Self {
             name: name.to_string(),
             mutable: MutableBinaryViewArray::with_capacity(capacity),
             scratch: vec![],
             quote_char: quote_char.unwrap_or(b'"'),
+            escape_char,
             encoding,
         }
     }
@@ -198,8 +216,12 @@ impl ParsedBuffer for Utf8Field {
             // SAFETY:
             // we just allocated enough capacity and data_len is correct.
             unsafe {
-                let n_written =
-                    escape_field(bytes, self.quote_char, self.scratch.spare_capacity_mut());
+                let n_written = escape_field(
+                    bytes,
+                    self.quote_char,
+                    self.escape_char,
+                    self.scratch.spare_capacity_mut(),
+                );
                 self.scratch.set_len(n_written);
             }
             self.scratch.as_slice()
@@ -207,6 +229,12 @@ impl ParsedBuffer for Utf8Field {
             bytes
         };
 
+        if matches!(self.encoding, CsvEncoding::Latin1) {
+            self.mutable
+                .push_value(decode_latin1(escaped_bytes).as_ref());
+            return Ok(());
+        }
+
         // It is important that this happens after escaping, as invalid escaped string can produce
         // invalid utf8.
         let parse_result = validate_utf8(escaped_bytes);
@@ -228,7 +256,7 @@ impl ParsedBuffer for Utf8Field {
                     if needs_escaping && validate_utf8(bytes) {
                         polars_bail!(ComputeError: "string field is not properly escaped");
                     } else {
-                        polars_bail!(ComputeError: "invalid utf-8 sequence");
+                        return Err(invalid_utf8());
                     }
                 }
             },
@@ -247,6 +275,8 @@ pub(crate) struct CategoricalField {
 pub(crate) struct CategoricalField {
     escape_scratch: Vec<u8>,
     quote_char: u8,
+    escape_char: Option<u8>,
+    encoding: CsvEncoding,
     builder: CategoricalChunkedBuilder,
 }
 
@@ -256,6 +286,8 @@ impl CategoricalField {
         name: &str,
         capacity: usize,
         quote_char: Option<u8>,
+        escape_char: Option<u8>,
+        encoding: CsvEncoding,
         ordering: CategoricalOrdering,
     ) -> Self {
         let builder = CategoricalChunkedBuilder::new(name, capacity, ordering);
@@ -263,6 +295,8 @@ impl CategoricalField {
         Self {
             escape_scratch: vec![],
             quote_char: quote_char.unwrap_or(b'"'),
+            escape_char,
+            encoding,
             builder,
         }
     }
@@ -281,38 +315,46 @@ impl CategoricalField {
             return Ok(());
         }
 
-        if validate_utf8(bytes) {
-            if needs_escaping {
-                polars_ensure!(bytes.len() > 1, ComputeError: "invalid csv file\n\nField `{}` is not properly escaped.", std::str::from_utf8(bytes).map_err(to_compute_err)?);
-                self.escape_scratch.clear();
-                self.escape_scratch.reserve(bytes.len());
-                // SAFETY:
-                // we just allocated enough capacity and data_len is correct.
-                unsafe {
-                    let n_written = escape_field(
-                        bytes,
-                        self.quote_char,
-                        self.escape_scratch.spare_capacity_mut(),
-                    );
-                    self.escape_scratch.set_len(n_written);
-                }
+        // the other encodings can't fail
+        if matches!(self.encoding, CsvEncoding::Utf8) && !validate_utf8(bytes) {
+            if ignore_errors {
+                self.builder.append_null();
+                return Ok(());
+            }
+            return Err(invalid_utf8());
+        }
 
-                // SAFETY:
-                // just did utf8 check
-                let key = unsafe { std::str::from_utf8_unchecked(&self.escape_scratch) };
-                self.builder.append_value(key);
-            } else {
-                // SAFETY:
-                // just did utf8 check
-                unsafe {
-                    self.builder
-                        .append_value(std::str::from_utf8_unchecked(bytes))
-                }
+        let key = if needs_escaping {
+            polars_ensure!(bytes.len() > 1, ComputeError: "invalid csv file\n\nField `{}` is not properly escaped.", String::from_utf8_lossy(bytes));
+            self.escape_scratch.clear();
+            self.escape_scratch.reserve(bytes.len());
+            // SAFETY:
+            // we just allocated enough capacity and data_len is correct.
+            unsafe {
+                let n_written = escape_field(
+                    bytes,
+                    self.quote_char,
+                    self.escape_char,
+                    self.escape_scratch.spare_capacity_mut(),
+                );
+                self.escape_scratch.set_len(n_written);
             }
-        } else if ignore_errors {
-            self.builder.append_null()
+            self.escape_scratch.as_slice()
         } else {
-            polars_bail!(ComputeError: "invalid utf-8 sequence");
+            bytes
+        };
+
+        match self.encoding {
+            // SAFETY:
+            // just did utf8 check, escaping only removes quotes
+            CsvEncoding::Utf8 => unsafe {
+                self.builder
+                    .append_value(std::str::from_utf8_unchecked(key))
+            },
+            CsvEncoding::LossyUtf8 => self
+                .builder
+                .append_value(String::from_utf8_lossy(key).as_ref()),
+            CsvEncoding::Latin1 => self.builder.append_value(decode_latin1(key).as_ref()),
         }
         Ok(())
     }
@@ -389,7 +431,7 @@ where
                     buf.builder.append_null();
                     return Ok(());
                 } else {
-                    polars_bail!(ComputeError: "invalid utf-8 sequence");
+                    return Err(invalid_utf8());
                 }
             },
         }
@@ -484,6 +526,7 @@ pub(crate) fn init_buffers(
     capacity: usize,
     schema: &Schema,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     encoding: CsvEncoding,
 ) -> PolarsResult<Vec<Buffer>> {
     projection
@@ -506,9 +549,13 @@ pub(crate) fn init_buffers(
                 &DataType::UInt64 => Buffer::UInt64(PrimitiveChunkedBuilder::new(name, capacity)),
                 &DataType::Float32 => Buffer::Float32(PrimitiveChunkedBuilder::new(name, capacity)),
                 &DataType::Float64 => Buffer::Float64(PrimitiveChunkedBuilder::new(name, capacity)),
-                &DataType::String => {
-                    Buffer::Utf8(Utf8Field::new(name, capacity, quote_char, encoding))
-                },
+                &DataType::String => Buffer::Utf8(Utf8Field::new(
+                    name,
+                    capacity,
+                    quote_char,
+                    escape_char,
+                    encoding,
+                )),
                 #[cfg(feature = "dtype-datetime")]
                 DataType::Datetime(time_unit, time_zone) => Buffer::Datetime {
                     buf: DatetimeField::new(name, capacity),
@@ -519,7 +566,12 @@ pub(crate) fn init_buffers(
                 &DataType::Date => Buffer::Date(DatetimeField::new(name, capacity)),
                 #[cfg(feature = "dtype-categorical")]
                 DataType::Categorical(_, ordering) => Buffer::Categorical(CategoricalField::new(
-                    name, capacity, quote_char, *ordering,
+                    name,
+                    capacity,
+                    quote_char,
+                    escape_char,
+                    encoding,
+                    *ordering,
                 )),
                 // TODO (ENUM) support writing to Enum
                 dt => polars_bail!(
@@ -660,6 +712,14 @@ impl Buffer {
         };
     }
 
+    pub(crate) fn is_float(&self) -> bool {
+        matches!(self, Buffer::Float32(_) | Buffer::Float64(_))
+    }
+
+    pub(crate) fn is_numeric(&self) -> bool {
+        self.dtype().is_numeric()
+    }
+
     pub(crate) fn dtype(&self) -> DataType {
         match self {
             Buffer::Boolean(_) => DataType::Boolean,
@@ -52,7 +52,7 @@ pub(super) mod write_impl;
 
 use std::fs::File;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub use parser::count_rows;
 use polars_core::prelude::*;
@@ -60,10 +60,13 @@ use polars_core::prelude::*;
 use polars_time::prelude::*;
 #[cfg(feature = "temporal")]
 use rayon::prelude::*;
-pub use read::{CommentPrefix, CsvEncoding, CsvReader, NullValues};
+pub use read::{
+    BoolValues, CoercionMode, CommentPrefix, CsvEncoding, CsvError, CsvParseError, CsvReader,
+    NullValues, ProgressCallback, RaggedPolicy, ReadStats,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-pub use write::{BatchedWriter, CsvWriter, QuoteStyle};
+pub use write::{BatchedWriter, CsvCompression, CsvWriter, HeaderTransform, QuoteStyle};
 pub use write_impl::SerializeOptions;
 
 use crate::csv::read_impl::CoreReader;
@@ -1,4 +1,6 @@
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
 
 use memchr::memchr2_iter;
 use num_traits::Pow;
@@ -12,7 +14,9 @@ use super::buffer::*;
 use crate::csv::read::NullValuesCompiled;
 use crate::csv::splitfields::SplitFields;
 use crate::csv::utils::get_file_chunks;
-use crate::csv::CommentPrefix;
+use crate::csv::{
+    BoolValues, CoercionMode, CommentPrefix, CsvEncoding, CsvError, CsvParseError, RaggedPolicy,
+};
 use crate::utils::get_reader_bytes;
 
 /// Read the number of rows without parsing columns
@@ -21,6 +25,7 @@ pub fn count_rows(
     path: &PathBuf,
     separator: u8,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
     has_header: bool,
@@ -38,6 +43,7 @@ pub fn count_rows(
         None,
         separator,
         quote_char,
+        escape_char,
     )
     .map(|(mean, std)| {
         let n_rows = (reader_bytes.len() as f32 / (mean - 0.01 * std)) as usize;
@@ -51,15 +57,23 @@ pub fn count_rows(
         None,
         separator,
         quote_char,
+        escape_char,
         eol_char,
     );
 
     let iter = file_chunks.into_par_iter().map(|(start, stop)| {
         let local_bytes = &reader_bytes[start..stop];
-        let row_iterator = SplitLines::new(local_bytes, quote_char.unwrap_or(b'"'), eol_char);
+        let row_iterator = SplitLines::new(
+            local_bytes,
+            quote_char.unwrap_or(b'"'),
+            escape_char,
+            eol_char,
+        );
         if comment_prefix.is_some() {
             Ok(row_iterator
-                .filter(|line| !line.is_empty() && !is_comment_line(line, comment_prefix))
+                .filter(|line| {
+                    !line.is_empty() && !is_comment_line(line, comment_prefix, separator)
+                })
                 .count())
         } else {
             Ok(row_iterator.count())
@@ -84,10 +98,30 @@ pub(crate) fn skip_bom(input: &[u8]) -> &[u8] {
     }
 }
 
+/// Raise an error on a utf-16 (LE or BE) Byte Order Mark, as such files cannot be parsed as utf-8.
+pub(crate) fn check_no_utf16_bom(input: &[u8]) -> PolarsResult<()> {
+    polars_ensure!(
+        !(input.starts_with(b"\xff\xfe") || input.starts_with(b"\xfe\xff")),
+        ComputeError: "CSV file starts with a utf-16 byte order mark; \
+        only utf-8 encoded files can be read, see the `encoding` option"
+    );
+    Ok(())
+}
+
 /// Checks if a line in a CSV file is a comment based on the given comment prefix configuration.
 ///
 /// This function is used during CSV parsing to determine whether a line should be ignored based on its starting characters.
-pub(crate) fn is_comment_line(line: &[u8], comment_prefix: Option<&CommentPrefix>) -> bool {
+/// Leading whitespace before the comment prefix is ignored, unless it is the separator: a line
+/// starting with an empty field is not a comment.
+pub(crate) fn is_comment_line(
+    line: &[u8],
+    comment_prefix: Option<&CommentPrefix>,
+    separator: u8,
+) -> bool {
+    if comment_prefix.is_none() {
+        return false;
+    }
+    let line = skip_whitespace_exclude(line, separator);
     match comment_prefix {
         Some(CommentPrefix::Single(c)) => line.starts_with(&[*c]),
         Some(CommentPrefix::Multi(s)) => line.starts_with(s.as_bytes()),
@@ -95,6 +129,15 @@ pub(crate) fn is_comment_line(line: &[u8], comment_prefix: Option<&CommentPrefix
     }
 }
 
+/// Checks if a line is empty or contains only whitespace. The separator never counts as
+/// whitespace, so a line of empty fields like `\t\t` isn't blank.
+pub(crate) fn is_blank_line(line: &[u8], separator: u8, eol_char: u8) -> bool {
+    let line = skip_condition(line, |b| {
+        b != separator && b != eol_char && (is_whitespace(b) || b == b'\r')
+    });
+    line.first().map_or(true, |b| *b == eol_char)
+}
+
 /// Find the nearest next line position.
 /// Does not check for new line characters embedded in String fields.
 pub(crate) fn next_line_position_naive(input: &[u8], eol_char: u8) -> Option<usize> {
@@ -111,6 +154,7 @@ pub(crate) fn next_line_position(
     mut expected_fields: Option<usize>,
     separator: u8,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 ) -> Option<usize> {
     fn accept_line(
@@ -119,9 +163,10 @@ pub(crate) fn next_line_position(
         separator: u8,
         eol_char: u8,
         quote_char: Option<u8>,
+        escape_char: Option<u8>,
     ) -> bool {
         let mut count = 0usize;
-        for (field, _) in SplitFields::new(line, separator, quote_char, eol_char) {
+        for (field, _) in SplitFields::new(line, separator, quote_char, escape_char, eol_char) {
             if memchr2_iter(separator, eol_char, field).count() >= expected_fields {
                 return false;
             }
@@ -168,16 +213,31 @@ pub(crate) fn next_line_position(
         }
         debug_assert!(pos <= input.len());
         let new_input = unsafe { input.get_unchecked(pos..) };
-        let mut lines = SplitLines::new(new_input, quote_char.unwrap_or(b'"'), eol_char);
+        let mut lines =
+            SplitLines::new(new_input, quote_char.unwrap_or(b'"'), escape_char, eol_char);
         let line = lines.next();
 
         match (line, expected_fields) {
             // count the fields, and determine if they are equal to what we expect from the schema
             (Some(line), Some(expected_fields)) => {
-                if accept_line(line, expected_fields, separator, eol_char, quote_char) {
+                if accept_line(
+                    line,
+                    expected_fields,
+                    separator,
+                    eol_char,
+                    quote_char,
+                    escape_char,
+                ) {
                     let mut valid = true;
                     for line in lines.take(2) {
-                        if !accept_line(line, expected_fields, separator, eol_char, quote_char) {
+                        if !accept_line(
+                            line,
+                            expected_fields,
+                            separator,
+                            eol_char,
+                            quote_char,
+                            escape_char,
+                        ) {
                             valid = false;
                             break;
                         }
@@ -245,6 +305,75 @@ pub(crate) fn skip_line_ending(input: &[u8], eol_char: u8) -> &[u8] {
     skip_condition(input, |b| is_line_ending(b, eol_char))
 }
 
+/// Remove ascii whitespace from the start and the end of the buffer.
+#[inline]
+pub(crate) fn trim_whitespace(input: &[u8]) -> &[u8] {
+    let start = input
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(input.len());
+    let end = input
+        .iter()
+        .rposition(|b| !b.is_ascii_whitespace())
+        .map_or(start, |i| i + 1);
+    &input[start..end]
+}
+
+/// Check that the thousands separator only occurs between groups of digits in the integer part
+/// of a number: a first group of one to three digits followed by groups of exactly three, as in
+/// `-1,234,567.8`.
+pub(crate) fn is_valid_digit_grouping(number: &[u8], sep: u8) -> bool {
+    let unsigned = match number.first() {
+        Some(b'-' | b'+') => &number[1..],
+        _ => number,
+    };
+    let int_len = unsigned
+        .iter()
+        .position(|&b| !(b.is_ascii_digit() || b == sep))
+        .unwrap_or(unsigned.len());
+    let (int_part, rest) = unsigned.split_at(int_len);
+
+    let mut groups = int_part.split(|&b| b == sep);
+    let first = groups.next().unwrap();
+    (1..=3).contains(&first.len()) && groups.all(|g| g.len() == 3) && !rest.contains(&sep)
+}
+
+/// Prepare a numeric field for parsing: remove the thousands separator and replace a decimal
+/// comma by a decimal point. A field with an invalid digit grouping, like `1,2,3`, keeps its
+/// separators so that it fails to parse.
+#[inline]
+fn normalize_number<'b>(
+    field: &'b [u8],
+    quoted: bool,
+    thousands_separator: Option<u8>,
+    decimal_comma: bool,
+    scratch: &'b mut Vec<u8>,
+) -> &'b [u8] {
+    let has_separator = thousands_separator.map_or(false, |sep| field.contains(&sep));
+    let has_decimal_comma = decimal_comma && field.contains(&b',');
+    if !(has_separator || has_decimal_comma) {
+        return field;
+    }
+    if let Some(sep) = thousands_separator.filter(|_| has_separator) {
+        let number = if quoted && field.len() >= 2 {
+            &field[1..field.len() - 1]
+        } else {
+            field
+        };
+        if !is_valid_digit_grouping(number, sep) {
+            return field;
+        }
+    }
+    scratch.clear();
+    for &b in field {
+        if Some(b) == thousands_separator {
+            continue;
+        }
+        scratch.push(if decimal_comma && b == b',' { b'.' } else { b });
+    }
+    scratch
+}
+
 /// Get the mean and standard deviation of length of lines in bytes
 pub(crate) fn get_line_stats(
     bytes: &[u8],
@@ -253,6 +382,7 @@ pub(crate) fn get_line_stats(
     expected_fields: Option<usize>,
     separator: u8,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
 ) -> Option<(f32, f32)> {
     let mut lengths = Vec::with_capacity(n_lines);
 
@@ -269,6 +399,7 @@ pub(crate) fn get_line_stats(
             expected_fields,
             separator,
             quote_char,
+            escape_char,
             eol_char,
         )?;
         bytes_trunc = &bytes_trunc[pos + 1..];
@@ -304,14 +435,21 @@ pub(crate) fn get_line_stats(
 pub(crate) struct SplitLines<'a> {
     v: &'a [u8],
     quote_char: u8,
+    escape_char: Option<u8>,
     end_line_char: u8,
 }
 
 impl<'a> SplitLines<'a> {
-    pub(crate) fn new(slice: &'a [u8], quote_char: u8, end_line_char: u8) -> Self {
+    pub(crate) fn new(
+        slice: &'a [u8],
+        quote_char: u8,
+        escape_char: Option<u8>,
+        end_line_char: u8,
+    ) -> Self {
         Self {
             v: slice,
             quote_char,
+            escape_char,
             end_line_char,
         }
     }
@@ -335,7 +473,12 @@ impl<'a> Iterator for SplitLines<'a> {
                 Some(&c) => {
                     pos += 1;
 
-                    if c == self.quote_char {
+                    if in_field && Some(c) == self.escape_char && c != self.quote_char {
+                        // the escaped byte is taken literally
+                        if iter.next().is_some() {
+                            pos += 1;
+                        }
+                    } else if c == self.quote_char {
                         // toggle between string field enclosure
                         //      if we encounter a starting '"' -> in_field = true;
                         //      if we encounter a closing '"' -> in_field = false;
@@ -365,33 +508,92 @@ impl<'a> Iterator for SplitLines<'a> {
     }
 }
 
+/// Find the first `needle` outside of a quoted field, `bytes` starts inside one if `in_field`.
+/// This jumps between the bytes that matter with `memchr`, so skipping the unprojected tail of a
+/// wide row doesn't look at every byte.
 #[inline]
-fn find_quoted(bytes: &[u8], quote_char: u8, needle: u8) -> Option<usize> {
-    let mut in_field = false;
-
-    let mut idx = 0u32;
-    // micro optimizations
-    #[allow(clippy::explicit_counter_loop)]
-    for &c in bytes.iter() {
+fn find_quoted(
+    bytes: &[u8],
+    mut in_field: bool,
+    quote_char: u8,
+    escape_char: Option<u8>,
+    needle: u8,
+) -> Option<usize> {
+    let escape_char = escape_char.filter(|&c| c != quote_char);
+    let mut pos = 0;
+    loop {
+        let rest = bytes.get(pos..)?;
+        let found = match (in_field, escape_char) {
+            (false, _) => memchr::memchr2(quote_char, needle, rest),
+            (true, Some(escape_char)) => memchr::memchr2(quote_char, escape_char, rest),
+            (true, None) => memchr::memchr(quote_char, rest),
+        }?;
+        pos += found;
+        let c = rest[found];
         if c == quote_char {
             // toggle between string field enclosure
             //      if we encounter a starting '"' -> in_field = true;
             //      if we encounter a closing '"' -> in_field = false;
             in_field = !in_field;
+            pos += 1;
+        } else if in_field {
+            // the escaped byte is taken literally
+            pos += 2;
+        } else {
+            return Some(pos);
         }
+    }
+}
 
-        if !in_field && c == needle {
-            return Some(idx as usize);
+/// Find the position after the first line ending at or after `from` that doesn't end inside a
+/// quoted field, `bytes` must start at the beginning of a record. Unlike
+/// [`next_line_position`] this doesn't guess the quote state at `from` from the lines that
+/// follow, the quotes before it are counted like [`SplitLines`] does. So a quoted field with
+/// embedded new lines is never cut, even if its lines look like records.
+pub(crate) fn next_record_position(
+    bytes: &[u8],
+    from: usize,
+    quote_char: u8,
+    escape_char: Option<u8>,
+    eol_char: u8,
+) -> Option<usize> {
+    let escape_char = escape_char.filter(|&c| c != quote_char);
+    let mut in_field = false;
+    let mut pos = 0;
+    while pos < from {
+        let rest = &bytes[pos..from];
+        let found = match (in_field, escape_char) {
+            (true, Some(escape_char)) => memchr::memchr2(quote_char, escape_char, rest),
+            _ => memchr::memchr(quote_char, rest),
+        };
+        let Some(found) = found else {
+            break;
+        };
+        pos += found;
+        if rest[found] == quote_char {
+            in_field = !in_field;
+            pos += 1;
+        } else {
+            // the escaped byte is taken literally
+            pos += 2;
         }
-        idx += 1;
     }
-    None
+    let start = pos.max(from);
+    let rest = bytes.get(start..)?;
+    let pos = start + find_quoted(rest, in_field, quote_char, escape_char, eol_char)? + 1;
+    // like `next_line_position`, a line ending at the end isn't a new record
+    (pos < bytes.len()).then_some(pos)
 }
 
 #[inline]
-pub(crate) fn skip_this_line(bytes: &[u8], quote: Option<u8>, eol_char: u8) -> &[u8] {
+pub(crate) fn skip_this_line(
+    bytes: &[u8],
+    quote: Option<u8>,
+    escape: Option<u8>,
+    eol_char: u8,
+) -> &[u8] {
     let pos = match quote {
-        Some(quote) => find_quoted(bytes, quote, eol_char),
+        Some(quote) => find_quoted(bytes, false, quote, escape, eol_char),
         None => bytes.iter().position(|x| *x == eol_char),
     };
     match pos {
@@ -400,6 +602,59 @@ pub(crate) fn skip_this_line(bytes: &[u8], quote: Option<u8>, eol_char: u8) -> &
     }
 }
 
+/// Remove the last `n` lines from `bytes`. A trailing line ending does not count as a line.
+/// This looks at physical lines, so quoted fields with embedded new lines are not taken into
+/// account.
+pub(crate) fn skip_footer_lines(bytes: &[u8], n: usize, eol_char: u8) -> &[u8] {
+    if n == 0 {
+        return bytes;
+    }
+    let mut end = bytes.len();
+    if end > 0 && bytes[end - 1] == eol_char {
+        end -= 1;
+    }
+    for _ in 0..n {
+        end = bytes[..end]
+            .iter()
+            .rposition(|&c| c == eol_char)
+            .unwrap_or(0);
+    }
+    let mut bytes = &bytes[..end];
+    if let Some(stripped) = bytes.strip_suffix(b"\r") {
+        bytes = stripped;
+    }
+    bytes
+}
+
+/// Counts the rows and fields that [`parse_lines`] loses, shared by all threads.
+#[derive(Default)]
+pub(crate) struct ReadCounters {
+    pub(crate) rows_skipped: AtomicUsize,
+    pub(crate) fields_coerced_to_null: AtomicUsize,
+}
+
+/// How the lines of a file are split into fields and how the fields are parsed. These are the
+/// same for every chunk of the file.
+#[derive(Clone)]
+pub(crate) struct ParseOptions {
+    pub(crate) separator: u8,
+    pub(crate) comment_prefix: Option<CommentPrefix>,
+    pub(crate) quote_char: Option<u8>,
+    pub(crate) escape_char: Option<u8>,
+    pub(crate) eol_char: u8,
+    pub(crate) encoding: CsvEncoding,
+    pub(crate) missing_is_null: bool,
+    pub(crate) bool_values: Option<BoolValues>,
+    pub(crate) thousands_separator: Option<u8>,
+    pub(crate) decimal_comma: bool,
+    pub(crate) truncate_whitespace: bool,
+    pub(crate) ignore_errors: bool,
+    pub(crate) ragged_lines: RaggedPolicy,
+    pub(crate) skip_blank_lines: bool,
+    pub(crate) max_field_len: Option<usize>,
+    pub(crate) numeric_coercion: CoercionMode,
+}
+
 /// Parse CSV.
 ///
 /// # Arguments
@@ -413,13 +668,7 @@ pub(crate) fn skip_this_line(bytes: &[u8], quote: Option<u8>, eol_char: u8) -> &
 pub(super) fn parse_lines(
     mut bytes: &[u8],
     offset: usize,
-    separator: u8,
-    comment_prefix: Option<&CommentPrefix>,
-    quote_char: Option<u8>,
-    eol_char: u8,
-    missing_is_null: bool,
-    ignore_errors: bool,
-    mut truncate_ragged_lines: bool,
+    options: &ParseOptions,
     null_values: Option<&NullValuesCompiled>,
     projection: &[usize],
     buffers: &mut [Buffer],
@@ -427,24 +676,46 @@ pub(super) fn parse_lines(
     // length of original schema
     schema_len: usize,
     schema: &Schema,
+    error_report: Option<&Mutex<Vec<CsvParseError>>>,
+    counters: Option<&ReadCounters>,
 ) -> PolarsResult<usize> {
     assert!(
         !projection.is_empty(),
         "at least one column should be projected"
     );
+    let ParseOptions {
+        separator,
+        quote_char,
+        escape_char,
+        eol_char,
+        missing_is_null,
+        thousands_separator,
+        decimal_comma,
+        truncate_whitespace,
+        ignore_errors,
+        mut ragged_lines,
+        skip_blank_lines,
+        max_field_len,
+        numeric_coercion,
+        ..
+    } = *options;
+    let comment_prefix = options.comment_prefix.as_ref();
+    let bool_values = options.bool_values.as_ref();
     // During projection pushdown we are not checking other csv fields.
     // This would be very expensive and we don't care as we only want
     // the projected columns.
-    if projection.len() != schema_len {
-        truncate_ragged_lines = true
+    if projection.len() != schema_len && ragged_lines == RaggedPolicy::Error {
+        ragged_lines = RaggedPolicy::Truncate
     }
+    // With a single column a blank line is a missing value.
+    let skip_blank_lines = skip_blank_lines && schema_len > 1;
 
     // we use the pointers to track the no of bytes read.
     let start = bytes.as_ptr() as usize;
     let original_bytes_len = bytes.len();
-    let n_lines = n_lines as u32;
 
-    let mut line_count = 0u32;
+    let mut line_count = 0usize;
+    let mut scratch = vec![];
     loop {
         if line_count > n_lines {
             let end = bytes.as_ptr() as usize;
@@ -453,11 +724,26 @@ pub(super) fn parse_lines(
 
         if bytes.is_empty() {
             return Ok(original_bytes_len);
-        } else if is_comment_line(bytes, comment_prefix) {
+        } else if is_comment_line(bytes, comment_prefix, separator) {
             // deal with comments
-            let bytes_rem = skip_this_line(bytes, quote_char, eol_char);
+            let bytes_rem = skip_this_line(bytes, quote_char, escape_char, eol_char);
             bytes = bytes_rem;
             continue;
+        } else if skip_blank_lines && is_blank_line(bytes, separator, eol_char) {
+            bytes = skip_this_line(bytes, quote_char, escape_char, eol_char);
+            continue;
+        }
+
+        if ragged_lines == RaggedPolicy::Skip {
+            let n_fields =
+                SplitFields::new(bytes, separator, quote_char, escape_char, eol_char).count();
+            if n_fields != schema_len {
+                if let Some(counters) = counters {
+                    counters.rows_skipped.fetch_add(1, Ordering::Relaxed);
+                }
+                bytes = skip_this_line(bytes, quote_char, escape_char, eol_char);
+                continue;
+            }
         }
 
         // Every line we only need to parse the columns that are projected.
@@ -467,11 +753,52 @@ pub(super) fn parse_lines(
         let mut next_projected = unsafe { projection_iter.next().unwrap_unchecked() };
         let mut processed_fields = 0;
 
-        let mut iter = SplitFields::new(bytes, separator, quote_char, eol_char);
+        let mut iter = SplitFields::new(bytes, separator, quote_char, escape_char, eol_char)
+            .with_max_field_len(max_field_len);
         let mut idx = 0u32;
         let mut read_sol = 0;
         loop {
             match iter.next() {
+                None if iter.field_too_long => {
+                    let max_field_len = max_field_len.unwrap();
+                    let field_offset = offset + iter.v.as_ptr() as usize - start;
+                    let column_name = schema
+                        .get_at_index(idx as usize)
+                        .map_or("", |(name, _)| name.as_str());
+                    if !ignore_errors {
+                        return Err(CsvError::FieldTooLong {
+                            line: 0,
+                            offset: field_offset,
+                            column: column_name.to_string(),
+                            max_field_len,
+                        }
+                        .into());
+                    }
+                    if let Some(error_report) = error_report {
+                        error_report.lock().unwrap().push(CsvParseError {
+                            line: 0,
+                            offset: field_offset,
+                            column: column_name.to_string(),
+                            raw: String::from_utf8_lossy(&iter.v[..max_field_len]).into_owned(),
+                            reason: format!(
+                                "quoted field exceeds `max_field_len` of {} bytes",
+                                max_field_len
+                            ),
+                        });
+                    }
+                    if let Some(counters) = counters {
+                        counters
+                            .fields_coerced_to_null
+                            .fetch_add(1, Ordering::Relaxed);
+                    }
+                    // the quote can't be trusted, so resume at the next line ending; the
+                    // remaining fields of the row are added as nulls below
+                    bytes = match memchr::memchr(eol_char, iter.v) {
+                        Some(pos) => &iter.v[pos + 1..],
+                        None => &[],
+                    };
+                    break;
+                },
                 // end of line
                 None => {
                     bytes = unsafe {
@@ -480,6 +807,10 @@ pub(super) fn parse_lines(
                     break;
                 },
                 Some((mut field, needs_escaping)) => {
+                    if iter.unterminated_quote && !ignore_errors {
+                        let offset = offset + field.as_ptr() as usize - start;
+                        return Err(CsvError::UnterminatedQuote { line: 0, offset }.into());
+                    }
                     let field_len = field.len();
 
                     // +1 is the split character that is consumed by the iterator.
@@ -494,6 +825,9 @@ pub(super) fn parse_lines(
                                 field = field.get_unchecked_release(..field_len - 1);
                             }
                         }
+                        if truncate_whitespace && !needs_escaping {
+                            field = trim_whitespace(field);
+                        }
 
                         debug_assert!(processed_fields < buffers.len());
                         let buf = unsafe {
@@ -501,6 +835,23 @@ pub(super) fn parse_lines(
                             buffers.get_unchecked_mut(processed_fields)
                         };
                         let mut add_null = false;
+                        let ignore_field_errors = ignore_errors
+                            || (numeric_coercion == CoercionMode::LenientNull && buf.is_numeric());
+                        // the field as it is handed to the parser, `field` is kept for errors
+                        let decimal_comma = decimal_comma && buf.is_float();
+                        let parse_field = if decimal_comma
+                            || (thousands_separator.is_some() && buf.is_numeric())
+                        {
+                            normalize_number(
+                                field,
+                                needs_escaping,
+                                thousands_separator,
+                                decimal_comma,
+                                &mut scratch,
+                            )
+                        } else {
+                            field
+                        };
 
                         // if we have null values argument, check if this field equal null value
                         if let Some(null_values) = null_values {
@@ -516,32 +867,71 @@ pub(super) fn parse_lines(
                         }
                         if add_null {
                             buf.add_null(!missing_is_null && field.is_empty())
-                        } else {
-                            buf.add(field, ignore_errors, needs_escaping, missing_is_null)
-                                .map_err(|e| {
-                                    let bytes_offset = offset + field.as_ptr() as usize - start;
-                                    let unparsable = String::from_utf8_lossy(field);
+                        } else if let (Some(bool_values), Buffer::Boolean(builder)) =
+                            (bool_values, &mut *buf)
+                        {
+                            let field = if needs_escaping && field.len() >= 2 {
+                                unsafe { field.get_unchecked_release(1..field.len() - 1) }
+                            } else {
+                                field
+                            };
+                            builder.append_option(bool_values.parse(field));
+                        } else if ignore_field_errors
+                            && (error_report.is_some() || counters.is_some())
+                        {
+                            // Parse strictly so that we learn about the failure, then
+                            // record it and continue as `ignore_errors` would.
+                            if let Err(e) =
+                                buf.add(parse_field, false, needs_escaping, missing_is_null)
+                            {
+                                if let Some(error_report) = error_report {
+                                    let field_offset = offset + field.as_ptr() as usize - start;
+                                    let e = locate_field_error(e, field_offset, idx as usize);
                                     let column_name = schema.get_at_index(idx as usize).unwrap().0;
-                                    polars_err!(
-                                        ComputeError:
-                                        "could not parse `{}` as dtype `{}` at column '{}' (column number {})\n\n\
-                                        The current offset in the file is {} bytes.\n\
-                                        \n\
-                                        You might want to try:\n\
-                                        - increasing `infer_schema_length` (e.g. `infer_schema_length=10000`),\n\
-                                        - specifying correct dtype with the `dtypes` argument\n\
-                                        - setting `ignore_errors` to `True`,\n\
-                                        - adding `{}` to the `null_values` list.\n\n\
-                                        Original error: ```{}```",
-                                        &unparsable,
-                                        buf.dtype(),
-                                        column_name,
-                                        idx + 1,
-                                        bytes_offset,
-                                        &unparsable,
-                                        e
-                                    )
-                                })?;
+                                    error_report.lock().unwrap().push(CsvParseError {
+                                        line: 0,
+                                        offset: field_offset,
+                                        column: column_name.to_string(),
+                                        raw: String::from_utf8_lossy(field).into_owned(),
+                                        reason: format!(
+                                            "could not parse as dtype `{}`: {}",
+                                            buf.dtype(),
+                                            e
+                                        ),
+                                    });
+                                }
+                                if let Some(counters) = counters {
+                                    counters
+                                        .fields_coerced_to_null
+                                        .fetch_add(1, Ordering::Relaxed);
+                                }
+                                buf.add_null(false)
+                            }
+                        } else {
+                            buf.add(
+                                parse_field,
+                                ignore_field_errors,
+                                needs_escaping,
+                                missing_is_null,
+                            )
+                            .map_err(|e| {
+                                let bytes_offset = offset + field.as_ptr() as usize - start;
+                                let e = locate_field_error(e, bytes_offset, idx as usize);
+                                if CsvError::from_polars(&e).is_some() {
+                                    return e;
+                                }
+                                let column_name = schema.get_at_index(idx as usize).unwrap().0;
+                                CsvError::SchemaMismatch {
+                                    line: 0,
+                                    offset: bytes_offset,
+                                    column: column_name.to_string(),
+                                    col: idx as usize,
+                                    dtype: buf.dtype(),
+                                    value: String::from_utf8_lossy(field).into_owned(),
+                                    reason: e.to_string(),
+                                }
+                                .into()
+                            })?;
                         }
                         processed_fields += 1;
 
@@ -552,14 +942,34 @@ pub(super) fn parse_lines(
                                 if bytes.get(read_sol - 1) == Some(&eol_char) {
                                     bytes = &bytes[read_sol..];
                                 } else {
-                                    if !truncate_ragged_lines && read_sol < bytes.len() {
-                                        polars_bail!(ComputeError: r#"found more fields than defined in 'Schema'
-
-Consider setting 'truncate_ragged_lines={}'."#, polars_error::constants::TRUE)
+                                    if ragged_lines == RaggedPolicy::Error && read_sol < bytes.len()
+                                    {
+                                        let found = SplitFields::new(
+                                            bytes,
+                                            separator,
+                                            quote_char,
+                                            escape_char,
+                                            eol_char,
+                                        )
+                                        .count();
+                                        return Err(CsvError::RaggedRow {
+                                            line: 0,
+                                            offset: offset + bytes.as_ptr() as usize - start,
+                                            expected: schema_len,
+                                            found,
+                                        }
+                                        .into());
+                                    }
+                                    if max_field_len.is_some() {
+                                        // split the remaining fields as well, to check their
+                                        // length
+                                        idx += 1;
+                                        continue;
                                     }
                                     let bytes_rem = skip_this_line(
                                         unsafe { bytes.get_unchecked_release(read_sol - 1..) },
                                         quote_char,
+                                        escape_char,
                                         eol_char,
                                     );
                                     bytes = bytes_rem;
@@ -589,6 +999,20 @@ Consider setting 'truncate_ragged_lines={}'."#, polars_error::constants::TRUE)
     }
 }
 
+/// Fill in where the field is for the errors that a buffer raises without knowing it.
+fn locate_field_error(mut err: PolarsError, offset: usize, col: usize) -> PolarsError {
+    if let Some(CsvError::InvalidUtf8 {
+        offset: err_offset,
+        col: err_col,
+        ..
+    }) = CsvError::from_polars_mut(&mut err)
+    {
+        *err_offset = offset;
+        *err_col = col;
+    }
+    err
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -596,14 +1020,14 @@ mod test {
     #[test]
     fn test_splitfields() {
         let input = "\"foo\",\"bar\"";
-        let mut fields = SplitFields::new(input.as_bytes(), b',', Some(b'"'), b'\n');
+        let mut fields = SplitFields::new(input.as_bytes(), b',', Some(b'"'), None, b'\n');
 
         assert_eq!(fields.next(), Some(("\"foo\"".as_bytes(), true)));
         assert_eq!(fields.next(), Some(("\"bar\"".as_bytes(), true)));
         assert_eq!(fields.next(), None);
 
         let input2 = "\"foo\n bar\";\"baz\";12345";
-        let mut fields2 = SplitFields::new(input2.as_bytes(), b';', Some(b'"'), b'\n');
+        let mut fields2 = SplitFields::new(input2.as_bytes(), b';', Some(b'"'), None, b'\n');
 
         assert_eq!(fields2.next(), Some(("\"foo\n bar\"".as_bytes(), true)));
         assert_eq!(fields2.next(), Some(("\"baz\"".as_bytes(), true)));
@@ -611,16 +1035,26 @@ mod test {
         assert_eq!(fields2.next(), None);
     }
 
+    #[test]
+    fn test_splitfields_escape_char() {
+        let input = r#""a\"b,c",d"#;
+        let mut fields = SplitFields::new(input.as_bytes(), b',', Some(b'"'), Some(b'\\'), b'\n');
+
+        assert_eq!(fields.next(), Some((r#""a\"b,c""#.as_bytes(), true)));
+        assert_eq!(fields.next(), Some(("d".as_bytes(), false)));
+        assert_eq!(fields.next(), None);
+    }
+
     #[test]
     fn test_splitlines() {
         let input = "1,\"foo\n\"\n2,\"foo\n\"\n";
-        let mut lines = SplitLines::new(input.as_bytes(), b'"', b'\n');
+        let mut lines = SplitLines::new(input.as_bytes(), b'"', None, b'\n');
         assert_eq!(lines.next(), Some("1,\"foo\n\"".as_bytes()));
         assert_eq!(lines.next(), Some("2,\"foo\n\"".as_bytes()));
         assert_eq!(lines.next(), None);
 
         let input2 = "1,'foo\n'\n2,'foo\n'\n";
-        let mut lines2 = SplitLines::new(input2.as_bytes(), b'\'', b'\n');
+        let mut lines2 = SplitLines::new(input2.as_bytes(), b'\'', None, b'\n');
         assert_eq!(lines2.next(), Some("1,'foo\n'".as_bytes()));
         assert_eq!(lines2.next(), Some("2,'foo\n'".as_bytes()));
         assert_eq!(lines2.next(), None);
@@ -1,9 +1,17 @@
+use std::io::{Cursor, Read};
+
+use regex::Regex;
+
 use super::*;
+use crate::csv::parser::{skip_footer_lines, ParseOptions};
 use crate::csv::read_impl::{
     to_batched_owned_mmap, to_batched_owned_read, BatchedCsvReaderMmap, BatchedCsvReaderRead,
     OwnedBatchedCsvReader, OwnedBatchedCsvReaderMmap,
 };
-use crate::csv::utils::infer_file_schema;
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+use crate::csv::utils::decompress;
+use crate::csv::utils::{infer_file_schema, sniff_separator};
+use crate::mmap::ReaderBytes;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -12,6 +20,8 @@ pub enum CsvEncoding {
     Utf8,
     /// Utf8 encoding and unknown bytes are replaced with �
     LossyUtf8,
+    /// Latin-1 (ISO-8859-1) encoding, string fields are transcoded to utf8
+    Latin1,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -25,6 +35,27 @@ pub enum NullValues {
     Named(Vec<(String, String)>),
 }
 
+/// Tokens that are parsed as `true` and `false` in boolean columns.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BoolValues {
+    pub truthy: Vec<String>,
+    pub falsy: Vec<String>,
+}
+
+impl BoolValues {
+    /// Returns `None` if `field` is neither a truthy nor a falsy token.
+    pub(super) fn parse(&self, field: &[u8]) -> Option<bool> {
+        if self.truthy.iter().any(|v| v.as_bytes() == field) {
+            Some(true)
+        } else if self.falsy.iter().any(|v| v.as_bytes() == field) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CommentPrefix {
@@ -52,6 +83,245 @@ impl CommentPrefix {
     }
 }
 
+/// How to handle rows whose number of fields differs from the schema.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RaggedPolicy {
+    /// Raise an error on rows with more fields than the schema.
+    /// Rows with fewer fields are padded with nulls.
+    #[default]
+    Error,
+    /// Ignore the extra fields of rows with more fields than the schema.
+    /// Rows with fewer fields are padded with nulls.
+    Truncate,
+    /// Drop every row that does not have exactly as many fields as the schema.
+    Skip,
+}
+
+/// How to handle a field of a numeric column that can't be parsed as its dtype.
+///
+/// This only applies to integer and float columns, [`CsvReader::with_ignore_errors`] applies
+/// to all columns.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CoercionMode {
+    /// Raise an error, unless errors are ignored.
+    #[default]
+    Strict,
+    /// Read the field as null.
+    LenientNull,
+}
+
+/// A field that could not be parsed while reading with `ignore_errors`.
+///
+/// Collected by [`CsvReader::finish_with_error_report`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CsvParseError {
+    /// 1-based line number of the offending field in the (decompressed) file.
+    pub line: usize,
+    /// Byte offset of the offending field in the (decompressed) file.
+    pub offset: usize,
+    /// Name of the column the field belongs to.
+    pub column: String,
+    /// The raw field as found in the file.
+    pub raw: String,
+    /// Why the field could not be parsed.
+    pub reason: String,
+}
+
+/// Why a CSV file could not be parsed.
+///
+/// The readers return it wrapped in a [`PolarsError::Io`] of kind
+/// [`InvalidData`](std::io::ErrorKind::InvalidData), use [`CsvError::from_polars`] to match on
+/// it. Lines are 1-based and offsets are in bytes, both refer to the (decompressed) file. The
+/// line is 0 if it isn't known, e.g. for the batched readers that don't see the whole file.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CsvError {
+    /// A field could not be parsed as the dtype of its column.
+    SchemaMismatch {
+        line: usize,
+        offset: usize,
+        /// Name of the column.
+        column: String,
+        /// 0-based index of the column in the file.
+        col: usize,
+        dtype: DataType,
+        /// The raw field as found in the file.
+        value: String,
+        /// Why the parser rejected the field.
+        reason: String,
+    },
+    /// A row has more fields than the schema, see [`RaggedPolicy`].
+    RaggedRow {
+        line: usize,
+        offset: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A quoted field isn't closed before the end of the file.
+    UnterminatedQuote { line: usize, offset: usize },
+    /// A quoted field is still open after `max_field_len` bytes, see
+    /// [`CsvReader::with_max_field_len`].
+    FieldTooLong {
+        line: usize,
+        offset: usize,
+        /// Name of the column.
+        column: String,
+        max_field_len: usize,
+    },
+    /// A field that is read as a string isn't valid utf-8. `col` is the 0-based index of the
+    /// column in the file.
+    InvalidUtf8 {
+        line: usize,
+        offset: usize,
+        col: usize,
+    },
+}
+
+impl CsvError {
+    /// The [`CsvError`] that caused `err`, if any.
+    pub fn from_polars(err: &PolarsError) -> Option<&CsvError> {
+        match err {
+            PolarsError::Io(err) => err.get_ref()?.downcast_ref(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn from_polars_mut(err: &mut PolarsError) -> Option<&mut CsvError> {
+        match err {
+            PolarsError::Io(err) => err.get_mut()?.downcast_mut(),
+            _ => None,
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        use CsvError::*;
+        match self {
+            SchemaMismatch { line, .. }
+            | RaggedRow { line, .. }
+            | UnterminatedQuote { line, .. }
+            | FieldTooLong { line, .. }
+            | InvalidUtf8 { line, .. } => *line,
+        }
+    }
+
+    pub fn offset(&self) -> usize {
+        use CsvError::*;
+        match self {
+            SchemaMismatch { offset, .. }
+            | RaggedRow { offset, .. }
+            | UnterminatedQuote { offset, .. }
+            | FieldTooLong { offset, .. }
+            | InvalidUtf8 { offset, .. } => *offset,
+        }
+    }
+
+    /// Set the line from the offset, `bytes` is the whole file.
+    pub(crate) fn resolve_line(&mut self, bytes: &[u8], eol_char: u8) {
+        use CsvError::*;
+        let offset = self.offset().min(bytes.len());
+        let n_lines = 1 + memchr::memchr_iter(eol_char, &bytes[..offset]).count();
+        match self {
+            SchemaMismatch { line, .. }
+            | RaggedRow { line, .. }
+            | UnterminatedQuote { line, .. }
+            | FieldTooLong { line, .. }
+            | InvalidUtf8 { line, .. } => *line = n_lines,
+        }
+    }
+}
+
+impl std::fmt::Display for CsvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let at_line = match self.line() {
+            0 => String::new(),
+            line => format!(" (line {line})"),
+        };
+        match self {
+            CsvError::SchemaMismatch {
+                offset,
+                column,
+                col,
+                dtype,
+                value,
+                reason,
+                ..
+            } => write!(
+                f,
+                "could not parse `{value}` as dtype `{dtype}` at column '{column}' \
+                (column number {})\n\n\
+                The current offset in the file is {offset} bytes{at_line}.\n\
+                \n\
+                You might want to try:\n\
+                - increasing `infer_schema_length` (e.g. `infer_schema_length=10000`),\n\
+                - specifying correct dtype with the `dtypes` argument\n\
+                - setting `ignore_errors` to `True`,\n\
+                - adding `{value}` to the `null_values` list.\n\n\
+                Original error: ```{reason}```",
+                col + 1,
+            ),
+            CsvError::RaggedRow {
+                offset,
+                expected,
+                found,
+                ..
+            } => write!(
+                f,
+                "found more fields than defined in 'Schema': expected {expected}, found {found} \
+                at byte offset {offset}{at_line}\n\n\
+                Consider setting 'truncate_ragged_lines={}'.",
+                polars_error::constants::TRUE
+            ),
+            CsvError::UnterminatedQuote { offset, .. } => write!(
+                f,
+                "quoted field at byte offset {offset}{at_line} is not terminated before the end \
+                of the file"
+            ),
+            CsvError::FieldTooLong {
+                offset,
+                column,
+                max_field_len,
+                ..
+            } => write!(
+                f,
+                "quoted field in column '{column}' at byte offset {offset}{at_line} exceeds \
+                `max_field_len` of {max_field_len} bytes without a closing quote"
+            ),
+            CsvError::InvalidUtf8 { offset, col, .. } => write!(
+                f,
+                "invalid utf-8 sequence in column number {} at byte offset {offset}{at_line}",
+                col + 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {}
+
+impl From<CsvError> for PolarsError {
+    fn from(err: CsvError) -> Self {
+        PolarsError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+}
+
+/// How much data was lost while reading with `ignore_errors` or a [`RaggedPolicy`].
+///
+/// Returned by [`CsvReader::finish_with_stats`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ReadStats {
+    /// Number of rows in the returned DataFrame.
+    pub rows_parsed: usize,
+    /// Rows that were dropped by [`RaggedPolicy::Skip`].
+    pub rows_skipped: usize,
+    /// Fields that could not be parsed and were read as nulls with `ignore_errors`, including
+    /// quoted fields that exceeded the maximum field length.
+    pub fields_coerced_to_null: usize,
+}
+
+/// Called with the number of bytes parsed so far and the total number of bytes of the
+/// (decompressed) file, see [`CsvReader::with_progress_callback`].
+pub type ProgressCallback = Arc<dyn Fn(usize, Option<usize>) + Send + Sync>;
+
 pub(super) enum NullValuesCompiled {
     /// A single value that's used for all columns
     AllColumnsSingle(String),
@@ -137,31 +407,56 @@ where
     projection: Option<Vec<usize>>,
     /// Optional column names to project/ select.
     columns: Option<Vec<String>>,
+    /// Optional regex that selects the columns by name
+    columns_regex: Option<String>,
+    /// Optional (old, new) pairs to rename columns of the file
+    column_renames: Option<Vec<(String, String)>>,
     separator: Option<u8>,
+    /// Detect the separator if none is set
+    auto_separator: bool,
     pub(crate) schema: Option<SchemaRef>,
     encoding: CsvEncoding,
     n_threads: Option<usize>,
+    /// Created with [`CsvReader::from_reader_stream`], always parsed on a single thread
+    from_stream: bool,
     path: Option<PathBuf>,
+    /// Files that are read after `reader` and concatenated to its output
+    paths: Vec<PathBuf>,
     schema_overwrite: Option<SchemaRef>,
     dtype_overwrite: Option<&'a [DataType]>,
+    categorical_columns: Option<Vec<String>>,
     sample_size: usize,
     chunk_size: usize,
+    chunk_bytes: Option<usize>,
+    expected_rows: Option<usize>,
     comment_prefix: Option<CommentPrefix>,
     null_values: Option<NullValues>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     skip_rows_after_header: usize,
+    skip_footer: usize,
     try_parse_dates: bool,
     row_index: Option<RowIndex>,
     /// Aggregates chunk afterwards to a single chunk.
     rechunk: bool,
     raise_if_empty: bool,
-    truncate_ragged_lines: bool,
+    ragged_lines: RaggedPolicy,
+    skip_blank_lines: bool,
+    max_field_len: Option<usize>,
+    numeric_coercion: CoercionMode,
     missing_is_null: bool,
+    bool_values: Option<BoolValues>,
+    inference_null_threshold: Option<f64>,
+    thousands_separator: Option<u8>,
+    decimal_comma: bool,
+    truncate_whitespace: bool,
     low_memory: bool,
     has_header: bool,
+    dedup_column_names: bool,
     ignore_errors: bool,
     eol_char: u8,
+    progress_callback: Option<ProgressCallback>,
 }
 
 impl<'a, R> CsvReader<'a, R>
@@ -174,6 +469,14 @@ where
         self
     }
 
+    /// Skip the last `n` lines of the file, e.g. summary lines appended to a report export.
+    /// The footer is removed before any other row selection, so `with_n_rows` limits the rows
+    /// that remain after the footer is skipped.
+    pub fn with_skip_footer(mut self, n: usize) -> Self {
+        self.skip_footer = n;
+        self
+    }
+
     /// Add a row index column.
     pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
         self.row_index = row_index;
@@ -186,14 +489,37 @@ where
         self
     }
 
+    /// Set the target size in bytes of the parts of the file that are parsed in parallel.
+    /// By default the file is split in one part per thread; smaller parts balance the load
+    /// better between the threads, at the cost of more scheduling. Parts always end at a record
+    /// boundary, so they can be somewhat larger.
+    ///
+    /// Only used by [`finish`](SerReader::finish), not by the batched readers, and ignored if
+    /// the file is read by a single thread.
+    pub fn with_chunk_bytes(mut self, bytes: Option<usize>) -> Self {
+        self.chunk_bytes = bytes;
+        self
+    }
+
+    /// Set the number of rows the file is expected to have. The column buffers are then
+    /// preallocated for that many rows instead of for an estimate from a sample of the lines,
+    /// see [`sample_size`](Self::sample_size). A wrong count is no error, the buffers grow as
+    /// needed, but a much too large count allocates memory that isn't used.
+    ///
+    /// Only used by [`finish`](SerReader::finish), not by the batched readers.
+    pub fn with_expected_rows(mut self, n: Option<usize>) -> Self {
+        self.expected_rows = n;
+        self
+    }
+
     /// Set  [`CsvEncoding`]
     pub fn with_encoding(mut self, enc: CsvEncoding) -> Self {
         self.encoding = enc;
         self
     }
 
-    /// Try to stop parsing when `n` rows are parsed. During multithreaded parsing the upper bound `n` cannot
-    /// be guaranteed.
+    /// Stop parsing when `n` rows are parsed. Multithreaded parsing may parse a few rows more, but
+    /// those are dropped: the result holds exactly the first `n` rows of the file.
     pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
         self.n_rows = num_rows;
         self
@@ -220,6 +546,17 @@ where
         self
     }
 
+    /// Read the header from the physical line `idx` (0-based), e.g. `1` for a file with a title
+    /// line before the header. The lines before it are skipped as they are, without looking at
+    /// quotes or separators, and are never data.
+    ///
+    /// This is [`with_skip_rows(idx)`](Self::with_skip_rows) together with
+    /// [`has_header(true)`](Self::has_header); rows after the header are skipped with
+    /// [`with_skip_rows_after_header`](Self::with_skip_rows_after_header).
+    pub fn with_header_row(self, idx: usize) -> Self {
+        self.with_skip_rows(idx).has_header(true)
+    }
+
     /// Rechunk the DataFrame to contiguous memory after the CSV is parsed.
     pub fn with_rechunk(mut self, rechunk: bool) -> Self {
         self.rechunk = rechunk;
@@ -232,12 +569,29 @@ where
         self
     }
 
+    /// Rename duplicate column names of the header to `name_duplicated_0`, `name_duplicated_1`,
+    /// etc., in the order they appear in the file. This is the default; if set to `false` a
+    /// header with duplicate names raises an error instead.
+    pub fn with_dedup_column_names(mut self, toggle: bool) -> Self {
+        self.dedup_column_names = toggle;
+        self
+    }
+
     /// Set the CSV file's column separator as a byte character
     pub fn with_separator(mut self, separator: u8) -> Self {
         self.separator = Some(separator);
         self
     }
 
+    /// Detect the separator if none is set with [`with_separator`](Self::with_separator).
+    /// The first 20 lines are split on each of `,`, `;`, `\t` and `|`, and the one that splits
+    /// them all in the same number of fields (at least two) is used. If no candidate does, `,` is
+    /// used; if more than one does, reading fails with an error naming them instead of guessing.
+    pub fn with_auto_separator(mut self, toggle: bool) -> Self {
+        self.auto_separator = toggle;
+        self
+    }
+
     /// Set the comment prefix for this instance. Lines starting with this prefix will be ignored.
     pub fn with_comment_prefix(mut self, comment_prefix: Option<&str>) -> Self {
         self.comment_prefix = comment_prefix.map(|s| {
@@ -250,6 +604,13 @@ where
         self
     }
 
+    /// Set the comment character for this instance. Lines whose first non-whitespace byte is
+    /// this character will be ignored. This is a shorthand for a single byte comment prefix.
+    pub fn with_comment_char(mut self, comment_char: Option<u8>) -> Self {
+        self.comment_prefix = comment_char.map(CommentPrefix::new_single);
+        self
+    }
+
     /// Sets the comment prefix from `CsvParserOptions` for internal initialization.
     pub fn _with_comment_prefix(mut self, comment_prefix: Option<CommentPrefix>) -> Self {
         self.comment_prefix = comment_prefix;
@@ -268,12 +629,53 @@ where
         self
     }
 
+    /// Set the tokens that are parsed as `true` and `false`, replacing the default `true`/`false`.
+    /// These are used both to infer boolean columns and to parse them; any other value in a
+    /// boolean column is read as null.
+    pub fn with_bool_values(mut self, truthy: Vec<String>, falsy: Vec<String>) -> Self {
+        self.bool_values = Some(BoolValues { truthy, falsy });
+        self
+    }
+
+    /// Infer a column as `String` if less than the fraction `frac` of the rows used for inference
+    /// have a value in it. A sparse column would otherwise get the dtype of its few values, and
+    /// fail to parse the rows that weren't used for inference. Must be in `[0, 1]`.
+    pub fn with_inference_null_threshold(mut self, frac: f64) -> Self {
+        self.inference_null_threshold = Some(frac);
+        self
+    }
+
     /// Treat missing fields as null.
     pub fn with_missing_is_null(mut self, missing_is_null: bool) -> Self {
         self.missing_is_null = missing_is_null;
         self
     }
 
+    /// Strip leading and trailing ascii whitespace from unquoted fields before they are parsed,
+    /// e.g. ` 5.1` is read as `5.1`. Whitespace inside quoted fields is kept. Null values are
+    /// compared against the trimmed field, so a field of only whitespace is read as missing.
+    pub fn with_truncate_whitespace(mut self, toggle: bool) -> Self {
+        self.truncate_whitespace = toggle;
+        self
+    }
+
+    /// Parse floats with a decimal comma, e.g. `3,5`, as is common in European exports. This
+    /// cannot be combined with a `,` separator; use e.g. `;` as separator instead.
+    pub fn with_decimal_comma(mut self, toggle: bool) -> Self {
+        self.decimal_comma = toggle;
+        self
+    }
+
+    /// Set the thousands separator of numbers, e.g. `b','` to parse `1,234,567.89`. The separator
+    /// is removed from numeric fields before they are parsed; string fields are kept as is. Only
+    /// a valid digit grouping is accepted, a field like `1,2,3` is not a number.
+    /// Together with [`with_decimal_comma`](Self::with_decimal_comma) numbers like
+    /// `1.234.567,89` can be read. The separator cannot be the decimal separator.
+    pub fn with_thousands_separator(mut self, sep: Option<u8>) -> Self {
+        self.thousands_separator = sep;
+        self
+    }
+
     /// Overwrite the schema with the dtypes in this given Schema. The given schema may be a subset
     /// of the total schema.
     pub fn with_dtypes(mut self, schema: Option<SchemaRef>) -> Self {
@@ -288,6 +690,16 @@ where
         self
     }
 
+    /// Read the columns with these names as `Categorical`. The strings are interned while they
+    /// are parsed, which saves memory for columns with few distinct values, like country codes.
+    /// This takes precedence over the dtypes of [`with_dtypes`](Self::with_dtypes) and
+    /// [`with_schema`](Self::with_schema).
+    #[cfg(feature = "dtype-categorical")]
+    pub fn with_categorical_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.categorical_columns = columns;
+        self
+    }
+
     /// Set the CSV reader to infer the schema of the file
     ///
     /// # Arguments
@@ -306,17 +718,50 @@ where
         self
     }
 
-    /// Columns to select/ project
+    /// Project the columns with an index in `range`, e.g. `2..10` for the 3rd up to and
+    /// including the 10th column. This is [`with_projection`](Self::with_projection) with the
+    /// indices of the range, a range that ends past the last column is an error when reading.
+    pub fn with_projection_range(self, range: Option<std::ops::Range<usize>>) -> Self {
+        self.with_projection(range.map(|range| range.collect()))
+    }
+
+    /// Columns to select/ project. The names are resolved to indices against the header, so the
+    /// fields of the other columns are skipped without being parsed. This cannot be combined
+    /// with [`with_projection`](Self::with_projection).
     pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
         self.columns = columns;
         self
     }
 
+    /// Select the columns whose name matches `pattern`, e.g. `^sensor_\d+$`, in the order of the
+    /// file. The names are matched after [`with_column_renames`](Self::with_column_renames)
+    /// and the selection is resolved to indices like [`with_columns`](Self::with_columns), with
+    /// which it cannot be combined. An invalid pattern, or one that matches no column, is an
+    /// error when reading.
+    pub fn with_columns_regex(mut self, pattern: Option<String>) -> Self {
+        self.columns_regex = pattern;
+        self
+    }
+
+    /// Rename columns of the file, given as `(old, new)` pairs that are applied in order.
+    ///
+    /// The renames are applied to the schema before the file is parsed, so the resulting
+    /// DataFrame has the new names. [`with_columns`](Self::with_columns) may refer to either
+    /// the old or the new names, other options that take column names (e.g. per column null
+    /// values) expect the new names. Dtypes given by [`with_dtypes`](Self::with_dtypes) refer to
+    /// the names in the file. Renaming to the name of another column raises an error.
+    pub fn with_column_renames(mut self, renames: Option<Vec<(String, String)>>) -> Self {
+        self.column_renames = renames;
+        self
+    }
+
     /// Set the number of threads used in CSV reading. The default uses the number of cores of
     /// your cpu.
     ///
     /// Note that this only works if this is initialized with `CsvReader::from_path`.
     /// Note that the number of cores is the maximum allowed number of threads.
+    ///
+    /// The rows are always returned in the order of the file, whatever the number of threads.
     pub fn with_n_threads(mut self, n: Option<usize>) -> Self {
         self.n_threads = n;
         self
@@ -343,7 +788,13 @@ where
         self
     }
 
-    /// Reduce memory consumption at the expense of performance
+    /// Reduce memory consumption at the expense of performance.
+    ///
+    /// In low memory mode the column buffers are not pre-allocated for all rows a thread is
+    /// expected to read, but per chunk, where chunks of wide files hold proportionally fewer
+    /// rows. The resulting chunks are rechunked sequentially instead of in parallel. This keeps
+    /// peak memory closer to the size of the output at the cost of more reallocations and a
+    /// slower final rechunk.
     pub fn low_memory(mut self, toggle: bool) -> Self {
         self.low_memory = toggle;
         self
@@ -355,6 +806,14 @@ where
         self
     }
 
+    /// Set the `char` used to escape a quote char, separator or newline inside a quoted field.
+    /// The byte following the escape char is taken literally. The default is `[None]`, in
+    /// which case only doubled quotes are recognized as escapes.
+    pub fn with_escape_char(mut self, escape_char: Option<u8>) -> Self {
+        self.escape_char = escape_char;
+        self
+    }
+
     /// Automatically try to parse dates/ datetimes and time. If parsing fails, columns remain of dtype `[DataType::String]`.
     pub fn with_try_parse_dates(mut self, toggle: bool) -> Self {
         self.try_parse_dates = toggle;
@@ -367,8 +826,47 @@ where
     }
 
     /// Truncate lines that are longer than the schema.
+    /// This is a shorthand for `with_ragged_lines(RaggedPolicy::Truncate)`.
     pub fn truncate_ragged_lines(mut self, toggle: bool) -> Self {
-        self.truncate_ragged_lines = toggle;
+        self.ragged_lines = if toggle {
+            RaggedPolicy::Truncate
+        } else {
+            RaggedPolicy::Error
+        };
+        self
+    }
+
+    /// Set how rows with a different number of fields than the schema are handled.
+    pub fn with_ragged_lines(mut self, policy: RaggedPolicy) -> Self {
+        self.ragged_lines = policy;
+        self
+    }
+
+    /// Skip lines that are empty or contain only whitespace, e.g. blank lines between sections
+    /// of a hand-maintained file. Skipped lines don't count towards
+    /// [`with_n_rows`](Self::with_n_rows). A line of empty fields like `,,` is a row and is kept.
+    /// As a blank line is the only way to write a missing value in a file with a single column,
+    /// those aren't skipped. Defaults to `true`.
+    pub fn with_skip_blank_lines(mut self, toggle: bool) -> Self {
+        self.skip_blank_lines = toggle;
+        self
+    }
+
+    /// Set how a field that can't be parsed as the dtype of its numeric column is handled, see
+    /// [`CoercionMode`]. Like with [`with_ignore_errors`](Self::with_ignore_errors) the nulls
+    /// are counted by [`finish_with_stats`](Self::finish_with_stats) and reported by
+    /// [`finish_with_error_report`](Self::finish_with_error_report).
+    pub fn with_numeric_coercion(mut self, mode: CoercionMode) -> Self {
+        self.numeric_coercion = mode;
+        self
+    }
+
+    /// Limit the length in bytes of a quoted field. A missing closing quote in a corrupt file
+    /// otherwise turns the rest of the file into a single field. A field that is still open after
+    /// `n` bytes is an error, or with [`with_ignore_errors`](Self::with_ignore_errors) it is
+    /// read as a null, like the rest of its row, and reading resumes at the next line ending.
+    pub fn with_max_field_len(mut self, n: Option<usize>) -> Self {
+        self.max_field_len = n;
         self
     }
 }
@@ -380,6 +878,49 @@ impl<'a> CsvReader<'a, File> {
         let f = polars_utils::open_file(&path)?;
         Ok(Self::new(f).with_path(Some(path)))
     }
+
+    /// Read multiple files that share a schema and concatenate them vertically.
+    ///
+    /// Every file is parsed with the same options. The schema is inferred from the first file
+    /// and reused for the others; a file that infers different column names or dtypes raises an
+    /// error.
+    /// `with_n_rows` limits the total number of rows over all files and a row index continues
+    /// counting across files.
+    pub fn from_paths<P: AsRef<Path>>(paths: &[P]) -> PolarsResult<Self> {
+        let mut paths = paths.iter().map(|p| resolve_homedir(p.as_ref()));
+        let first = paths
+            .next()
+            .ok_or_else(|| polars_err!(ComputeError: "expected at least one path"))?;
+        let f = polars_utils::open_file(&first)?;
+        let mut reader = Self::new(f).with_path(Some(first));
+        reader.paths = paths.collect();
+        Ok(reader)
+    }
+}
+
+impl<'a, B> CsvReader<'a, Cursor<B>>
+where
+    B: AsRef<[u8]> + Send + Sync + 'a,
+{
+    /// Read CSV data that is already in memory, e.g. a `&[u8]`, `Vec<u8>` or `String`.
+    /// This is a shorthand for `CsvReader::new(Cursor::new(bytes))`.
+    pub fn from_bytes(bytes: B) -> Self {
+        Self::new(Cursor::new(bytes))
+    }
+}
+
+impl<'a> CsvReader<'a, Cursor<Vec<u8>>> {
+    /// Read CSV from a source that can only be read front to back, like stdin, a pipe or a
+    /// socket. Without `Seek` the input can't be memory mapped or split into chunks up front, so
+    /// the source is buffered in memory to its end and parsed on a single thread;
+    /// [`with_n_threads`](Self::with_n_threads) is ignored.
+    pub fn from_reader_stream<R: Read>(mut reader: R) -> PolarsResult<Self> {
+        let mut bytes = Vec::with_capacity(1024 * 128);
+        reader.read_to_end(&mut bytes)?;
+        let mut csv_reader = Self::from_bytes(bytes);
+        csv_reader.from_stream = true;
+        Ok(csv_reader)
+    }
 }
 
 impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
@@ -391,40 +932,293 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
     where
         'a: 'b,
     {
+        let columns_regex = self
+            .columns_regex
+            .as_deref()
+            .map(Regex::new)
+            .transpose()
+            .map_err(|e| polars_err!(ComputeError: "invalid column regex: {}", e))?;
         let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        if self.auto_separator && self.separator.is_none() {
+            self.separator = Some(sniff_separator(
+                &reader_bytes,
+                self.skip_rows_before_header,
+                self.comment_prefix.as_ref(),
+                self.quote_char,
+                self.escape_char,
+                self.eol_char,
+            )?);
+        }
+        let parse_options = ParseOptions {
+            separator: self.separator.unwrap_or(b','),
+            comment_prefix: std::mem::take(&mut self.comment_prefix),
+            quote_char: self.quote_char,
+            escape_char: self.escape_char,
+            eol_char: self.eol_char,
+            encoding: self.encoding,
+            missing_is_null: self.missing_is_null,
+            bool_values: self.bool_values.clone(),
+            thousands_separator: self.thousands_separator,
+            decimal_comma: self.decimal_comma,
+            truncate_whitespace: self.truncate_whitespace,
+            ignore_errors: self.ignore_errors,
+            ragged_lines: self.ragged_lines,
+            skip_blank_lines: self.skip_blank_lines,
+            max_field_len: self.max_field_len,
+            numeric_coercion: self.numeric_coercion,
+        };
         CoreReader::new(
             reader_bytes,
             self.n_rows,
             self.skip_rows_before_header,
             std::mem::take(&mut self.projection),
             self.max_records,
-            self.separator,
+            parse_options,
             self.has_header,
-            self.ignore_errors,
+            self.dedup_column_names,
             self.schema.clone(),
             std::mem::take(&mut self.columns),
-            self.encoding,
-            self.n_threads,
+            columns_regex,
+            std::mem::take(&mut self.column_renames),
+            if self.from_stream {
+                Some(1)
+            } else {
+                self.n_threads
+            },
             schema,
             self.dtype_overwrite,
             self.sample_size,
             self.chunk_size,
+            self.chunk_bytes,
+            self.expected_rows,
             self.low_memory,
-            std::mem::take(&mut self.comment_prefix),
-            self.quote_char,
-            self.eol_char,
             std::mem::take(&mut self.null_values),
-            self.missing_is_null,
+            self.inference_null_threshold,
             std::mem::take(&mut self.predicate),
             to_cast,
             self.skip_rows_after_header,
+            self.skip_footer,
             std::mem::take(&mut self.row_index),
             self.try_parse_dates,
             self.raise_if_empty,
-            self.truncate_ragged_lines,
         )
     }
 
+    /// Create a reader over `reader` with the same options as `self`.
+    fn with_reader<R2: MmapBytesReader>(&self, reader: R2) -> CsvReader<'a, R2> {
+        CsvReader {
+            reader,
+            rechunk: self.rechunk,
+            n_rows: self.n_rows,
+            max_records: self.max_records,
+            skip_rows_before_header: self.skip_rows_before_header,
+            projection: self.projection.clone(),
+            separator: self.separator,
+            auto_separator: self.auto_separator,
+            has_header: self.has_header,
+            dedup_column_names: self.dedup_column_names,
+            ignore_errors: self.ignore_errors,
+            schema: self.schema.clone(),
+            columns: self.columns.clone(),
+            columns_regex: self.columns_regex.clone(),
+            column_renames: self.column_renames.clone(),
+            encoding: self.encoding,
+            n_threads: self.n_threads,
+            from_stream: self.from_stream,
+            path: None,
+            paths: vec![],
+            schema_overwrite: self.schema_overwrite.clone(),
+            dtype_overwrite: self.dtype_overwrite,
+            categorical_columns: self.categorical_columns.clone(),
+            sample_size: self.sample_size,
+            chunk_size: self.chunk_size,
+            chunk_bytes: self.chunk_bytes,
+            expected_rows: self.expected_rows,
+            low_memory: self.low_memory,
+            comment_prefix: self.comment_prefix.clone(),
+            eol_char: self.eol_char,
+            null_values: self.null_values.clone(),
+            missing_is_null: self.missing_is_null,
+            bool_values: self.bool_values.clone(),
+            inference_null_threshold: self.inference_null_threshold,
+            thousands_separator: self.thousands_separator,
+            decimal_comma: self.decimal_comma,
+            truncate_whitespace: self.truncate_whitespace,
+            predicate: self.predicate.clone(),
+            quote_char: self.quote_char,
+            escape_char: self.escape_char,
+            skip_rows_after_header: self.skip_rows_after_header,
+            skip_footer: self.skip_footer,
+            try_parse_dates: self.try_parse_dates,
+            row_index: self.row_index.clone(),
+            raise_if_empty: self.raise_if_empty,
+            ragged_lines: self.ragged_lines,
+            skip_blank_lines: self.skip_blank_lines,
+            max_field_len: self.max_field_len,
+            numeric_coercion: self.numeric_coercion,
+            progress_callback: self.progress_callback.clone(),
+        }
+    }
+
+    fn infer_schema_from_reader(
+        &mut self,
+        max_records: Option<usize>,
+        schema_overwrite: Option<&Schema>,
+    ) -> PolarsResult<Schema> {
+        #[allow(unused_mut)]
+        let mut reader_bytes = get_reader_bytes(&mut self.reader)?;
+        if self.auto_separator && self.separator.is_none() {
+            self.separator = Some(sniff_separator(
+                &reader_bytes,
+                self.skip_rows_before_header,
+                self.comment_prefix.as_ref(),
+                self.quote_char,
+                self.escape_char,
+                self.eol_char,
+            )?);
+        }
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        if let Some(b) = decompress(
+            &reader_bytes,
+            // the footer is counted from the end, so we need all rows
+            max_records.filter(|_| self.skip_footer == 0).map(|n| {
+                self.skip_rows_before_header
+                    + (self.has_header as usize)
+                    + self.skip_rows_after_header
+                    + n
+            }),
+            self.separator.unwrap_or(b','),
+            self.quote_char,
+            self.escape_char,
+            self.eol_char,
+        ) {
+            reader_bytes = ReaderBytes::Owned(b);
+        }
+        let (schema, _, _) = infer_file_schema(
+            &ReaderBytes::Borrowed(skip_footer_lines(
+                &reader_bytes,
+                self.skip_footer,
+                self.eol_char,
+            )),
+            self.separator.unwrap_or(b','),
+            max_records,
+            self.has_header,
+            self.dedup_column_names,
+            schema_overwrite,
+            &mut self.skip_rows_before_header,
+            self.skip_rows_after_header,
+            self.comment_prefix.as_ref(),
+            self.quote_char,
+            self.escape_char,
+            self.eol_char,
+            self.encoding,
+            self.null_values.as_ref(),
+            self.truncate_whitespace,
+            self.decimal_comma,
+            self.thousands_separator,
+            self.bool_values.as_ref(),
+            self.inference_null_threshold,
+            self.try_parse_dates,
+            self.raise_if_empty,
+            &mut self.n_threads,
+        )?;
+        Ok(schema)
+    }
+
+    /// Read `reader` followed by all `paths` and concatenate the results.
+    fn finish_paths(mut self) -> PolarsResult<DataFrame> {
+        self.apply_categorical_columns()?;
+        let paths = std::mem::take(&mut self.paths);
+        let rechunk = std::mem::replace(&mut self.rechunk, false);
+
+        let schema_overwrite = self.schema_overwrite.clone();
+        let infer_schema = self.schema.is_none();
+        if infer_schema {
+            let schema =
+                self.infer_schema_from_reader(self.max_records, schema_overwrite.as_deref())?;
+            self.schema = Some(Arc::new(schema));
+        }
+        let schema = self.schema.clone().unwrap();
+        let max_records = self.max_records;
+
+        let readers = paths
+            .iter()
+            .map(|path| {
+                let file = polars_utils::open_file(path)?;
+                Ok(self.with_reader(file).with_path(Some(path)))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let n_rows = self.n_rows;
+        let row_index_offset = self.row_index.as_ref().map(|ri| ri.offset);
+
+        let mut df = self.finish()?;
+        for (path, mut reader) in paths.iter().zip(readers) {
+            if let Some(n_rows) = n_rows {
+                if df.height() >= n_rows {
+                    break;
+                }
+                reader.n_rows = Some(n_rows - df.height());
+            }
+            if let (Some(ri), Some(offset)) = (&mut reader.row_index, row_index_offset) {
+                ri.offset = offset + df.height() as IdxSize;
+            }
+            if infer_schema {
+                // the schema of the first file is used to parse this one, so it must infer the
+                // same dtypes
+                let file_schema =
+                    reader.infer_schema_from_reader(max_records, schema_overwrite.as_deref())?;
+                polars_ensure!(
+                    file_schema == *schema,
+                    SchemaMismatch: "schema of CSV file {:?} does not match the first file: \
+                    expected {:?}, got {:?}; set the dtypes with `with_dtypes` if they differ",
+                    path, schema, file_schema,
+                );
+            } else if reader.has_header {
+                let file_schema = reader.infer_schema_from_reader(Some(1), None)?;
+                polars_ensure!(
+                    file_schema.iter_names().eq(schema.iter_names()),
+                    SchemaMismatch: "columns of CSV file {:?} do not match the first file: \
+                    expected {:?}, got {:?}",
+                    path,
+                    schema.iter_names().collect::<Vec<_>>(),
+                    file_schema.iter_names().collect::<Vec<_>>(),
+                );
+            }
+            df.vstack_mut(&reader.finish()?)?;
+        }
+
+        if rechunk && df.n_chunks() > 1 {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+
+    /// Turn the columns of [`with_categorical_columns`](Self::with_categorical_columns) into dtype
+    /// overwrites, so that they are parsed into a categorical buffer.
+    fn apply_categorical_columns(&mut self) -> PolarsResult<()> {
+        #[cfg(feature = "dtype-categorical")]
+        if let Some(columns) = self.categorical_columns.take() {
+            let dtype = DataType::Categorical(None, Default::default());
+            if let Some(schema) = &mut self.schema {
+                let schema = Arc::make_mut(schema);
+                for name in &columns {
+                    polars_ensure!(schema.contains(name), ColumnNotFound: "{}", name);
+                    schema.set_dtype(name, dtype.clone());
+                }
+            }
+            let mut schema_overwrite = self
+                .schema_overwrite
+                .as_deref()
+                .cloned()
+                .unwrap_or_default();
+            for name in columns {
+                schema_overwrite.with_column(name.into(), dtype.clone());
+            }
+            self.schema_overwrite = Some(Arc::new(schema_overwrite));
+        }
+        Ok(())
+    }
+
     fn prepare_schema_overwrite(
         &self,
         overwriting_schema: &Schema,
@@ -478,7 +1272,10 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
         }
     }
 
+    /// Read the file in batches of roughly `chunk_size` rows with `next_batches`, which returns
+    /// `None` once the file is exhausted. All batches share the same schema.
     pub fn batched_borrowed_mmap(&'a mut self) -> PolarsResult<BatchedCsvReaderMmap<'a>> {
+        self.apply_categorical_columns()?;
         if let Some(schema) = self.schema_overwrite.as_deref() {
             let (schema, to_cast, has_cat) = self.prepare_schema_overwrite(schema)?;
             let schema = Arc::new(schema);
@@ -490,7 +1287,11 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
             csv_reader.batched_mmap(false)
         }
     }
+
+    /// Like [`CsvReader::batched_borrowed_mmap`], but loads the file with read calls instead of
+    /// memory mapping it. The reader must be a [`std::fs::File`].
     pub fn batched_borrowed_read(&'a mut self) -> PolarsResult<BatchedCsvReaderRead<'a>> {
+        self.apply_categorical_columns()?;
         if let Some(schema) = self.schema_overwrite.as_deref() {
             let (schema, to_cast, has_cat) = self.prepare_schema_overwrite(schema)?;
             let schema = Arc::new(schema);
@@ -502,121 +1303,85 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
             csv_reader.batched_read(false)
         }
     }
-}
 
-impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
-    pub fn batched_mmap(
-        mut self,
-        schema: Option<SchemaRef>,
-    ) -> PolarsResult<OwnedBatchedCsvReaderMmap> {
-        match schema {
-            Some(schema) => Ok(to_batched_owned_mmap(self, schema)),
+    /// Infer the schema of the file without parsing it. Only the rows used for schema inference
+    /// (see [`infer_schema`](Self::infer_schema)) are read.
+    ///
+    /// The dtypes set with [`with_dtypes`](Self::with_dtypes) and
+    /// [`with_dtypes_slice`](Self::with_dtypes_slice) are applied, projections, renames and the
+    /// row index are not. The reader is left unchanged and can be finished afterwards.
+    pub fn schema(&mut self) -> PolarsResult<Schema> {
+        self.apply_categorical_columns()?;
+        let mut schema = match &self.schema {
+            Some(schema) => schema.as_ref().clone(),
             None => {
-                let reader_bytes = get_reader_bytes(&mut self.reader)?;
-
-                let (inferred_schema, _, _) = infer_file_schema(
-                    &reader_bytes,
-                    self.separator.unwrap_or(b','),
-                    self.max_records,
-                    self.has_header,
-                    None,
-                    &mut self.skip_rows_before_header,
-                    self.skip_rows_after_header,
-                    self.comment_prefix.as_ref(),
-                    self.quote_char,
-                    self.eol_char,
-                    self.null_values.as_ref(),
-                    self.try_parse_dates,
-                    self.raise_if_empty,
-                    &mut self.n_threads,
-                )?;
-                let schema = Arc::new(inferred_schema);
-                Ok(to_batched_owned_mmap(self, schema))
+                // inference moves the start past leading comment lines, `finish` does that again.
+                let skip_rows = self.skip_rows_before_header;
+                let schema_overwrite = self.schema_overwrite.clone();
+                let schema =
+                    self.infer_schema_from_reader(self.max_records, schema_overwrite.as_deref());
+                self.skip_rows_before_header = skip_rows;
+                schema?
             },
+        };
+        if let Some(dtypes) = self.dtype_overwrite {
+            for (index, dt) in dtypes.iter().enumerate() {
+                schema.set_dtype_at_index(index, dt.clone());
+            }
         }
+        Ok(schema)
     }
-    pub fn batched_read(
-        mut self,
-        schema: Option<SchemaRef>,
-    ) -> PolarsResult<OwnedBatchedCsvReader> {
-        match schema {
-            Some(schema) => Ok(to_batched_owned_read(self, schema)),
-            None => {
-                let reader_bytes = get_reader_bytes(&mut self.reader)?;
-
-                let (inferred_schema, _, _) = infer_file_schema(
-                    &reader_bytes,
-                    self.separator.unwrap_or(b','),
-                    self.max_records,
-                    self.has_header,
-                    None,
-                    &mut self.skip_rows_before_header,
-                    self.skip_rows_after_header,
-                    self.comment_prefix.as_ref(),
-                    self.quote_char,
-                    self.eol_char,
-                    self.null_values.as_ref(),
-                    self.try_parse_dates,
-                    self.raise_if_empty,
-                    &mut self.n_threads,
-                )?;
-                let schema = Arc::new(inferred_schema);
-                Ok(to_batched_owned_read(self, schema))
-            },
-        }
+
+    /// Set a callback that is periodically called with the number of bytes parsed so far and
+    /// the total number of bytes while reading the file with [`SerReader::finish`].
+    ///
+    /// The callback is called from the threads that parse the file, calls are coalesced so that
+    /// the reported counts increase monotonically. The last call reports all bytes as read.
+    pub fn with_progress_callback(mut self, callback: Option<ProgressCallback>) -> Self {
+        self.progress_callback = callback;
+        self
     }
-}
 
-impl<'a, R> SerReader<R> for CsvReader<'a, R>
-where
-    R: MmapBytesReader + 'a,
-{
-    /// Create a new CsvReader from a file/ stream
-    fn new(reader: R) -> Self {
-        CsvReader {
-            reader,
-            rechunk: true,
-            n_rows: None,
-            max_records: Some(128),
-            skip_rows_before_header: 0,
-            projection: None,
-            separator: None,
-            has_header: true,
-            ignore_errors: false,
-            schema: None,
-            columns: None,
-            encoding: CsvEncoding::Utf8,
-            n_threads: None,
-            path: None,
-            schema_overwrite: None,
-            dtype_overwrite: None,
-            sample_size: 1024,
-            chunk_size: 1 << 18,
-            low_memory: false,
-            comment_prefix: None,
-            eol_char: b'\n',
-            null_values: None,
-            missing_is_null: true,
-            predicate: None,
-            quote_char: Some(b'"'),
-            skip_rows_after_header: 0,
-            try_parse_dates: false,
-            row_index: None,
-            raise_if_empty: true,
-            truncate_ragged_lines: false,
-        }
+    /// Read the file like [`SerReader::finish`], but also return the fields that could not be
+    /// parsed, sorted by their position in the file.
+    ///
+    /// Errors are only collected if `ignore_errors` is set; the offending fields are still
+    /// read as nulls. Otherwise the first error is raised as usual and the report is empty.
+    pub fn finish_with_error_report(self) -> PolarsResult<(DataFrame, Vec<CsvParseError>)> {
+        polars_ensure!(
+            self.paths.is_empty(),
+            InvalidOperation: "error reports are not supported when reading multiple files"
+        );
+        self.finish_impl(true, false)
+            .map(|(df, errors, _)| (df, errors))
     }
 
-    /// Read the file and create the DataFrame.
-    fn finish(mut self) -> PolarsResult<DataFrame> {
+    /// Read the file like [`SerReader::finish`], but also count how much data was lost while
+    /// parsing, see [`ReadStats`].
+    pub fn finish_with_stats(self) -> PolarsResult<(DataFrame, ReadStats)> {
+        polars_ensure!(
+            self.paths.is_empty(),
+            InvalidOperation: "read stats are not supported when reading multiple files"
+        );
+        self.finish_impl(false, true)
+            .map(|(df, _, stats)| (df, stats))
+    }
+
+    fn finish_impl(
+        mut self,
+        collect_errors: bool,
+        collect_stats: bool,
+    ) -> PolarsResult<(DataFrame, Vec<CsvParseError>, ReadStats)> {
+        self.apply_categorical_columns()?;
         let rechunk = self.rechunk;
         let schema_overwrite = self.schema_overwrite.clone();
         let low_memory = self.low_memory;
+        let progress_callback = self.progress_callback.clone();
 
         #[cfg(feature = "dtype-categorical")]
         let mut _cat_lock = None;
 
-        let mut df = if let Some(schema) = schema_overwrite.as_deref() {
+        let (mut df, errors, mut stats) = if let Some(schema) = schema_overwrite.as_deref() {
             let (schema, to_cast, _has_cat) = self.prepare_schema_overwrite(schema)?;
 
             #[cfg(feature = "dtype-categorical")]
@@ -625,7 +1390,21 @@ where
             }
 
             let mut csv_reader = self.core_reader(Some(Arc::new(schema)), to_cast)?;
-            csv_reader.as_df()?
+            if collect_errors {
+                csv_reader.collect_parse_errors();
+            }
+            if collect_stats {
+                csv_reader.collect_read_stats();
+            }
+            if let Some(callback) = progress_callback {
+                csv_reader.set_progress_callback(callback);
+            }
+            let df = csv_reader.as_df()?;
+            (
+                df,
+                csv_reader.take_parse_errors(),
+                csv_reader.take_read_stats(),
+            )
         } else {
             #[cfg(feature = "dtype-categorical")]
             {
@@ -643,7 +1422,21 @@ where
                 }
             }
             let mut csv_reader = self.core_reader(self.schema.clone(), vec![])?;
-            csv_reader.as_df()?
+            if collect_errors {
+                csv_reader.collect_parse_errors();
+            }
+            if collect_stats {
+                csv_reader.collect_read_stats();
+            }
+            if let Some(callback) = progress_callback {
+                csv_reader.set_progress_callback(callback);
+            }
+            let df = csv_reader.as_df()?;
+            (
+                df,
+                csv_reader.take_parse_errors(),
+                csv_reader.take_read_stats(),
+            )
         };
 
         // Important that this rechunk is never done in parallel.
@@ -675,7 +1468,111 @@ where
             };
             df = parse_dates(df, &fixed_schema)
         }
-        Ok(df)
+        stats.rows_parsed = df.height();
+        Ok((df, errors, stats))
+    }
+}
+
+impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
+    /// Owned version of [`CsvReader::batched_borrowed_mmap`]. If no `schema` is given it is
+    /// inferred once and reused for all batches.
+    pub fn batched_mmap(
+        mut self,
+        schema: Option<SchemaRef>,
+    ) -> PolarsResult<OwnedBatchedCsvReaderMmap> {
+        match schema {
+            Some(schema) => Ok(to_batched_owned_mmap(self, schema)),
+            None => {
+                let inferred_schema = self.infer_schema_from_reader(self.max_records, None)?;
+                let schema = Arc::new(inferred_schema);
+                Ok(to_batched_owned_mmap(self, schema))
+            },
+        }
+    }
+
+    /// Owned version of [`CsvReader::batched_borrowed_read`]. If no `schema` is given it is
+    /// inferred once and reused for all batches.
+    pub fn batched_read(
+        mut self,
+        schema: Option<SchemaRef>,
+    ) -> PolarsResult<OwnedBatchedCsvReader> {
+        match schema {
+            Some(schema) => Ok(to_batched_owned_read(self, schema)),
+            None => {
+                let inferred_schema = self.infer_schema_from_reader(self.max_records, None)?;
+                let schema = Arc::new(inferred_schema);
+                Ok(to_batched_owned_read(self, schema))
+            },
+        }
+    }
+}
+
+impl<'a, R> SerReader<R> for CsvReader<'a, R>
+where
+    R: MmapBytesReader + 'a,
+{
+    /// Create a new CsvReader from a file/ stream
+    fn new(reader: R) -> Self {
+        CsvReader {
+            reader,
+            rechunk: true,
+            n_rows: None,
+            max_records: Some(128),
+            skip_rows_before_header: 0,
+            projection: None,
+            separator: None,
+            auto_separator: false,
+            has_header: true,
+            dedup_column_names: true,
+            ignore_errors: false,
+            schema: None,
+            columns: None,
+            columns_regex: None,
+            column_renames: None,
+            encoding: CsvEncoding::Utf8,
+            n_threads: None,
+            from_stream: false,
+            path: None,
+            paths: vec![],
+            schema_overwrite: None,
+            dtype_overwrite: None,
+            categorical_columns: None,
+            sample_size: 1024,
+            chunk_size: 1 << 18,
+            chunk_bytes: None,
+            expected_rows: None,
+            low_memory: false,
+            comment_prefix: None,
+            eol_char: b'\n',
+            null_values: None,
+            missing_is_null: true,
+            bool_values: None,
+            inference_null_threshold: None,
+            thousands_separator: None,
+            decimal_comma: false,
+            truncate_whitespace: false,
+            predicate: None,
+            quote_char: Some(b'"'),
+            escape_char: None,
+            skip_rows_after_header: 0,
+            skip_footer: 0,
+            try_parse_dates: false,
+            row_index: None,
+            raise_if_empty: true,
+            ragged_lines: RaggedPolicy::Error,
+            skip_blank_lines: true,
+            max_field_len: None,
+            numeric_coercion: CoercionMode::Strict,
+            progress_callback: None,
+        }
+    }
+
+    /// Read the file and create the DataFrame.
+    fn finish(self) -> PolarsResult<DataFrame> {
+        if !self.paths.is_empty() {
+            return self.finish_paths();
+        }
+        self.finish_impl(false, false).map(|(df, _, _)| df)
     }
 }
 
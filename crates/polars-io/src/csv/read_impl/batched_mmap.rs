@@ -15,6 +15,7 @@ pub(crate) fn get_file_chunks_iterator(
     expected_fields: usize,
     separator: u8,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 ) {
     for _ in 0..n_chunks {
@@ -24,17 +25,27 @@ pub(crate) fn get_file_chunks_iterator(
             break;
         }
 
-        let end_pos = match next_line_position(
-            &bytes[search_pos..],
-            Some(expected_fields),
-            separator,
-            quote_char,
-            eol_char,
-        ) {
-            Some(pos) => search_pos + pos,
-            None => {
-                break;
-            },
+        let end_pos = match quote_char {
+            Some(quote_char) => next_record_position(
+                &bytes[*last_pos..],
+                chunk_size,
+                quote_char,
+                escape_char,
+                eol_char,
+            )
+            .map(|pos| *last_pos + pos),
+            None => next_line_position(
+                &bytes[search_pos..],
+                Some(expected_fields),
+                separator,
+                quote_char,
+                escape_char,
+                eol_char,
+            )
+            .map(|pos| search_pos + pos),
+        };
+        let Some(end_pos) = end_pos else {
+            break;
         };
         offsets.push_back((*last_pos, end_pos));
         *last_pos = end_pos;
@@ -51,6 +62,7 @@ struct ChunkOffsetIter<'a> {
     expected_fields: usize,
     separator: u8,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 }
 
@@ -70,6 +82,7 @@ impl<'a> Iterator for ChunkOffsetIter<'a> {
                         Some(self.expected_fields),
                         self.separator,
                         self.quote_char,
+                        self.escape_char,
                         self.eol_char,
                     )
                     .unwrap_or(1);
@@ -86,6 +99,7 @@ impl<'a> Iterator for ChunkOffsetIter<'a> {
                     self.expected_fields,
                     self.separator,
                     self.quote_char,
+                    self.escape_char,
                     self.eol_char,
                 );
                 match self.offsets.pop_front() {
@@ -107,8 +121,11 @@ impl<'a> CoreReader<'a> {
     pub fn batched_mmap(mut self, _has_cat: bool) -> PolarsResult<BatchedCsvReaderMmap<'a>> {
         let reader_bytes = self.reader_bytes.take().unwrap();
         let bytes = reader_bytes.as_ref();
-        let (bytes, starting_point_offset) =
-            self.find_starting_point(bytes, self.quote_char, self.eol_char)?;
+        let (bytes, starting_point_offset) = self.find_starting_point(
+            bytes,
+            self.parse_options.quote_char,
+            self.parse_options.eol_char,
+        )?;
 
         // this is arbitrarily chosen.
         // we don't want this to depend on the thread pool size
@@ -124,9 +141,10 @@ impl<'a> CoreReader<'a> {
             n_chunks: offset_batch_size,
             rows_per_batch: self.chunk_size,
             expected_fields: self.schema.len(),
-            separator: self.separator,
-            quote_char: self.quote_char,
-            eol_char: self.eol_char,
+            separator: self.parse_options.separator,
+            quote_char: self.parse_options.quote_char,
+            escape_char: self.parse_options.escape_char,
+            eol_char: self.parse_options.eol_char,
         };
 
         let projection = self.get_projection()?;
@@ -150,17 +168,10 @@ impl<'a> CoreReader<'a> {
             projection,
             starting_point_offset,
             row_index: self.row_index,
-            comment_prefix: self.comment_prefix,
-            quote_char: self.quote_char,
-            eol_char: self.eol_char,
+            parse_options: self.parse_options,
             null_values: self.null_values,
-            missing_is_null: self.missing_is_null,
             to_cast: self.to_cast,
-            ignore_errors: self.ignore_errors,
-            truncate_ragged_lines: self.truncate_ragged_lines,
             n_rows: self.n_rows,
-            encoding: self.encoding,
-            separator: self.separator,
             schema: self.schema,
             rows_read: 0,
             _cat_lock,
@@ -176,17 +187,10 @@ pub struct BatchedCsvReaderMmap<'a> {
     projection: Vec<usize>,
     starting_point_offset: Option<usize>,
     row_index: Option<RowIndex>,
-    comment_prefix: Option<CommentPrefix>,
-    quote_char: Option<u8>,
-    eol_char: u8,
+    parse_options: ParseOptions,
     null_values: Option<NullValuesCompiled>,
-    missing_is_null: bool,
-    truncate_ragged_lines: bool,
     to_cast: Vec<Field>,
-    ignore_errors: bool,
     n_rows: Option<usize>,
-    encoding: CsvEncoding,
-    separator: u8,
     schema: SchemaRef,
     rows_read: IdxSize,
     #[cfg(feature = "dtype-categorical")]
@@ -201,7 +205,8 @@ impl<'a> BatchedCsvReaderMmap<'a> {
             return Ok(None);
         }
         if let Some(n_rows) = self.n_rows {
-            if self.rows_read >= n_rows as IdxSize {
+            // compared as `usize`, a limit above `IdxSize::MAX` must not wrap around
+            if self.rows_read as usize >= n_rows {
                 return Ok(None);
             }
         }
@@ -227,25 +232,25 @@ impl<'a> BatchedCsvReaderMmap<'a> {
                 .map(|(bytes_offset_thread, stop_at_nbytes)| {
                     let mut df = read_chunk(
                         bytes,
-                        self.separator,
+                        &self.parse_options,
                         self.schema.as_ref(),
-                        self.ignore_errors,
                         &self.projection,
                         bytes_offset_thread,
-                        self.quote_char,
-                        self.eol_char,
-                        self.comment_prefix.as_ref(),
                         self.chunk_size,
-                        self.encoding,
                         self.null_values.as_ref(),
-                        self.missing_is_null,
-                        self.truncate_ragged_lines,
                         self.chunk_size,
                         stop_at_nbytes,
                         self.starting_point_offset,
+                        None,
+                        None,
                     )?;
 
-                    cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
+                    cast_columns(
+                        &mut df,
+                        &self.to_cast,
+                        false,
+                        self.parse_options.ignore_errors,
+                    )?;
 
                     if let Some(rc) = &self.row_index {
                         df.with_row_index_mut(&rc.name, Some(rc.offset));
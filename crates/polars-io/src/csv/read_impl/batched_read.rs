@@ -16,6 +16,7 @@ pub(crate) fn get_offsets(
     expected_fields: usize,
     separator: u8,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 ) {
     let mut start = 0;
@@ -26,17 +27,27 @@ pub(crate) fn get_offsets(
             break;
         }
 
-        let end_pos = match next_line_position(
-            &bytes[search_pos..],
-            Some(expected_fields),
-            separator,
-            quote_char,
-            eol_char,
-        ) {
-            Some(pos) => search_pos + pos,
-            None => {
-                break;
-            },
+        let end_pos = match quote_char {
+            Some(quote_char) => next_record_position(
+                &bytes[start..],
+                search_pos.saturating_sub(start),
+                quote_char,
+                escape_char,
+                eol_char,
+            )
+            .map(|pos| start + pos),
+            None => next_line_position(
+                &bytes[search_pos..],
+                Some(expected_fields),
+                separator,
+                quote_char,
+                escape_char,
+                eol_char,
+            )
+            .map(|pos| search_pos + pos),
+        };
+        let Some(end_pos) = end_pos else {
+            break;
         };
         offsets.push_back((start, end_pos));
         start = end_pos;
@@ -59,16 +70,19 @@ struct ChunkReader<'a> {
     expected_fields: usize,
     separator: u8,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 }
 
 impl<'a> ChunkReader<'a> {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         file: &'a File,
         rows_per_batch: usize,
         expected_fields: usize,
         separator: u8,
         quote_char: Option<u8>,
+        escape_char: Option<u8>,
         eol_char: u8,
         page_size: u64,
     ) -> Self {
@@ -87,6 +101,7 @@ impl<'a> ChunkReader<'a> {
             expected_fields,
             separator,
             quote_char,
+            escape_char,
             eol_char,
         }
     }
@@ -134,6 +149,7 @@ impl<'a> ChunkReader<'a> {
                     Some(self.expected_fields),
                     self.separator,
                     self.quote_char,
+                    self.escape_char,
                     self.eol_char,
                 );
 
@@ -181,6 +197,7 @@ impl<'a> ChunkReader<'a> {
             self.expected_fields,
             self.separator,
             self.quote_char,
+            self.escape_char,
             self.eol_char,
         );
         !self.offsets.is_empty()
@@ -190,13 +207,20 @@ impl<'a> ChunkReader<'a> {
 impl<'a> CoreReader<'a> {
     /// Create a batched csv reader that uses read calls to load data.
     pub fn batched_read(mut self, _has_cat: bool) -> PolarsResult<BatchedCsvReaderRead<'a>> {
+        polars_ensure!(
+            self.skip_footer == 0,
+            InvalidOperation: "skipping footer rows is not supported by the batched read reader"
+        );
         let reader_bytes = self.reader_bytes.take().unwrap();
 
         let ReaderBytes::Mapped(bytes, mut file) = &reader_bytes else {
             unreachable!()
         };
-        let (_, starting_point_offset) =
-            self.find_starting_point(bytes, self.quote_char, self.eol_char)?;
+        let (_, starting_point_offset) = self.find_starting_point(
+            bytes,
+            self.parse_options.quote_char,
+            self.parse_options.eol_char,
+        )?;
         if let Some(starting_point_offset) = starting_point_offset {
             file.seek(SeekFrom::Current(starting_point_offset as i64))
                 .unwrap();
@@ -206,9 +230,10 @@ impl<'a> CoreReader<'a> {
             file,
             self.chunk_size,
             self.schema.len(),
-            self.separator,
-            self.quote_char,
-            self.eol_char,
+            self.parse_options.separator,
+            self.parse_options.quote_char,
+            self.parse_options.escape_char,
+            self.parse_options.eol_char,
             4096,
         );
 
@@ -233,17 +258,10 @@ impl<'a> CoreReader<'a> {
             projection,
             starting_point_offset,
             row_index: self.row_index,
-            comment_prefix: self.comment_prefix,
-            quote_char: self.quote_char,
-            eol_char: self.eol_char,
+            parse_options: self.parse_options,
             null_values: self.null_values,
-            missing_is_null: self.missing_is_null,
             to_cast: self.to_cast,
-            ignore_errors: self.ignore_errors,
-            truncate_ragged_lines: self.truncate_ragged_lines,
             n_rows: self.n_rows,
-            encoding: self.encoding,
-            separator: self.separator,
             schema: self.schema,
             rows_read: 0,
             _cat_lock,
@@ -259,17 +277,10 @@ pub struct BatchedCsvReaderRead<'a> {
     projection: Vec<usize>,
     starting_point_offset: Option<usize>,
     row_index: Option<RowIndex>,
-    comment_prefix: Option<CommentPrefix>,
-    quote_char: Option<u8>,
-    eol_char: u8,
+    parse_options: ParseOptions,
     null_values: Option<NullValuesCompiled>,
-    missing_is_null: bool,
     to_cast: Vec<Field>,
-    ignore_errors: bool,
-    truncate_ragged_lines: bool,
     n_rows: Option<usize>,
-    encoding: CsvEncoding,
-    separator: u8,
     schema: SchemaRef,
     rows_read: IdxSize,
     #[cfg(feature = "dtype-categorical")]
@@ -284,7 +295,8 @@ impl<'a> BatchedCsvReaderRead<'a> {
             return Ok(None);
         }
         if let Some(n_rows) = self.n_rows {
-            if self.rows_read >= n_rows as IdxSize {
+            // compared as `usize`, a limit above `IdxSize::MAX` must not wrap around
+            if self.rows_read as usize >= n_rows {
                 return Ok(None);
             }
         }
@@ -324,25 +336,25 @@ impl<'a> BatchedCsvReaderRead<'a> {
                     let stop_at_n_bytes = chunk.len();
                     let mut df = read_chunk(
                         chunk,
-                        self.separator,
+                        &self.parse_options,
                         self.schema.as_ref(),
-                        self.ignore_errors,
                         &self.projection,
                         0,
-                        self.quote_char,
-                        self.eol_char,
-                        self.comment_prefix.as_ref(),
                         self.chunk_size,
-                        self.encoding,
                         self.null_values.as_ref(),
-                        self.missing_is_null,
-                        self.truncate_ragged_lines,
                         self.chunk_size,
                         stop_at_n_bytes,
                         self.starting_point_offset,
+                        None,
+                        None,
                     )?;
 
-                    cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
+                    cast_columns(
+                        &mut df,
+                        &self.to_cast,
+                        false,
+                        self.parse_options.ignore_errors,
+                    )?;
 
                     if let Some(rc) = &self.row_index {
                         df.with_row_index_mut(&rc.name, Some(rc.offset));
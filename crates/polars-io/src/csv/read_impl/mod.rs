@@ -3,6 +3,8 @@ mod batched_read;
 
 use std::fmt;
 use std::ops::Deref;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
 
 pub use batched_mmap::*;
 pub use batched_read::*;
@@ -14,12 +16,13 @@ use polars_core::POOL;
 use polars_time::prelude::*;
 use polars_utils::flatten;
 use rayon::prelude::*;
+use regex::Regex;
 
 use crate::csv::buffer::*;
 use crate::csv::parser::*;
-use crate::csv::read::{CommentPrefix, NullValuesCompiled};
+use crate::csv::read::NullValuesCompiled;
 use crate::csv::utils::*;
-use crate::csv::{CsvEncoding, NullValues};
+use crate::csv::{CsvError, CsvParseError, NullValues, ProgressCallback, ReadStats};
 use crate::mmap::ReaderBytes;
 use crate::predicates::PhysicalIoExpr;
 use crate::utils::update_row_counts;
@@ -85,6 +88,40 @@ pub(crate) fn cast_columns(
     Ok(())
 }
 
+/// Number of values (rows * columns) a chunk is allowed to pre-allocate in low memory mode.
+const LOW_MEMORY_VALUES_PER_CHUNK: usize = 1 << 20;
+
+/// Reports the number of parsed bytes to a [`ProgressCallback`].
+struct Progress {
+    callback: ProgressCallback,
+    total: Option<usize>,
+    read: AtomicUsize,
+    // The last reported count. The threads only report if the lock is free, so concurrent
+    // updates are coalesced and the reported counts increase monotonically.
+    reported: Mutex<usize>,
+}
+
+impl Progress {
+    fn add(&self, n_bytes: usize) {
+        self.read.fetch_add(n_bytes, Ordering::Relaxed);
+        if let Ok(mut reported) = self.reported.try_lock() {
+            let read = self.read.load(Ordering::Relaxed);
+            if read > *reported {
+                *reported = read;
+                (self.callback)(read, self.total)
+            }
+        }
+    }
+
+    fn finish(&self, n_bytes: usize) {
+        let mut reported = self.reported.lock().unwrap();
+        if n_bytes > *reported {
+            *reported = n_bytes;
+            (self.callback)(n_bytes, self.total)
+        }
+    }
+}
+
 /// CSV file reader
 pub(crate) struct CoreReader<'a> {
     reader_bytes: Option<ReaderBytes<'a>>,
@@ -94,27 +131,28 @@ pub(crate) struct CoreReader<'a> {
     projection: Option<Vec<usize>>,
     /// Current line number, used in error reporting
     line_number: usize,
-    ignore_errors: bool,
+    parse_options: ParseOptions,
     skip_rows_before_header: usize,
     // after the header, we need to take embedded lines into account
     skip_rows_after_header: usize,
+    skip_footer: usize,
     n_rows: Option<usize>,
-    encoding: CsvEncoding,
     n_threads: Option<usize>,
     has_header: bool,
-    separator: u8,
     sample_size: usize,
     chunk_size: usize,
+    chunk_bytes: Option<usize>,
+    expected_rows: Option<usize>,
     low_memory: bool,
-    comment_prefix: Option<CommentPrefix>,
-    quote_char: Option<u8>,
-    eol_char: u8,
     null_values: Option<NullValuesCompiled>,
-    missing_is_null: bool,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     to_cast: Vec<Field>,
     row_index: Option<RowIndex>,
-    truncate_ragged_lines: bool,
+    /// Fields that failed to parse, only collected if `ignore_errors` is set.
+    error_report: Option<Mutex<Vec<CsvParseError>>>,
+    /// Rows and fields lost while parsing, only counted if requested.
+    counters: Option<ReadCounters>,
+    progress: Option<Progress>,
 }
 
 impl<'a> fmt::Debug for CoreReader<'a> {
@@ -135,30 +173,30 @@ impl<'a> CoreReader<'a> {
         mut skip_rows: usize,
         mut projection: Option<Vec<usize>>,
         max_records: Option<usize>,
-        separator: Option<u8>,
+        parse_options: ParseOptions,
         has_header: bool,
-        ignore_errors: bool,
+        dedup_column_names: bool,
         schema: Option<SchemaRef>,
         columns: Option<Vec<String>>,
-        encoding: CsvEncoding,
+        columns_regex: Option<Regex>,
+        column_renames: Option<Vec<(String, String)>>,
         mut n_threads: Option<usize>,
         schema_overwrite: Option<SchemaRef>,
         dtype_overwrite: Option<&'a [DataType]>,
         sample_size: usize,
         chunk_size: usize,
+        chunk_bytes: Option<usize>,
+        expected_rows: Option<usize>,
         low_memory: bool,
-        comment_prefix: Option<CommentPrefix>,
-        quote_char: Option<u8>,
-        eol_char: u8,
         null_values: Option<NullValues>,
-        missing_is_null: bool,
+        inference_null_threshold: Option<f64>,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
         to_cast: Vec<Field>,
         skip_rows_after_header: usize,
+        skip_footer: usize,
         row_index: Option<RowIndex>,
         try_parse_dates: bool,
         raise_if_empty: bool,
-        truncate_ragged_lines: bool,
     ) -> PolarsResult<CoreReader<'a>> {
         #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
         let mut reader_bytes = reader_bytes;
@@ -171,8 +209,29 @@ impl<'a> CoreReader<'a> {
             );
         }
 
-        // check if schema should be inferred
-        let separator = separator.unwrap_or(b',');
+        let ParseOptions {
+            separator,
+            quote_char,
+            escape_char,
+            eol_char,
+            encoding,
+            thousands_separator,
+            decimal_comma,
+            truncate_whitespace,
+            ..
+        } = parse_options;
+        polars_ensure!(
+            !(decimal_comma && separator == b','),
+            InvalidOperation: "'decimal_comma' cannot be combined with a ',' separator"
+        );
+        polars_ensure!(
+            thousands_separator != Some(if decimal_comma { b',' } else { b'.' }),
+            InvalidOperation: "the thousands separator cannot be the decimal separator"
+        );
+        polars_ensure!(
+            chunk_bytes != Some(0),
+            InvalidOperation: "'chunk_bytes' should be larger than 0"
+        );
 
         let mut schema = match schema {
             Some(schema) => schema,
@@ -183,28 +242,48 @@ impl<'a> CoreReader<'a> {
                     // again after decompression.
                     #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
                     {
-                        let total_n_rows = n_rows.map(|n| {
-                            skip_rows + (has_header as usize) + skip_rows_after_header + n
+                        // the footer is counted from the end, so we need all rows
+                        // `n_rows` may be far above the number of rows in the file
+                        let total_n_rows = n_rows.filter(|_| skip_footer == 0).map(|n| {
+                            (skip_rows + (has_header as usize) + skip_rows_after_header)
+                                .saturating_add(n)
                         });
-                        if let Some(b) =
-                            decompress(&reader_bytes, total_n_rows, separator, quote_char, eol_char)
-                        {
+                        if let Some(b) = decompress(
+                            &reader_bytes,
+                            total_n_rows,
+                            separator,
+                            quote_char,
+                            escape_char,
+                            eol_char,
+                        ) {
                             reader_bytes = ReaderBytes::Owned(b);
                         }
                     }
 
                     let (inferred_schema, _, _) = infer_file_schema(
-                        &reader_bytes,
+                        &ReaderBytes::Borrowed(skip_footer_lines(
+                            &reader_bytes,
+                            skip_footer,
+                            eol_char,
+                        )),
                         separator,
                         max_records,
                         has_header,
+                        dedup_column_names,
                         schema_overwrite.as_deref(),
                         &mut skip_rows,
                         skip_rows_after_header,
-                        comment_prefix.as_ref(),
+                        parse_options.comment_prefix.as_ref(),
                         quote_char,
+                        escape_char,
                         eol_char,
+                        encoding,
                         null_values.as_ref(),
+                        truncate_whitespace,
+                        decimal_comma,
+                        thousands_separator,
+                        parse_options.bool_values.as_ref(),
+                        inference_null_threshold,
                         try_parse_dates,
                         raise_if_empty,
                         &mut n_threads,
@@ -220,22 +299,73 @@ impl<'a> CoreReader<'a> {
             }
         }
 
+        let column_renames = column_renames.unwrap_or_default();
+        if !column_renames.is_empty() {
+            let s = Arc::make_mut(&mut schema);
+            for (old, new) in &column_renames {
+                polars_ensure!(
+                    old == new || !s.contains(new),
+                    Duplicate: "cannot rename column '{}' to '{}': a column with that name already exists",
+                    old, new
+                );
+                s.rename(old, new.into()).ok_or_else(
+                    || polars_err!(ColumnNotFound: "cannot rename column '{}': not found in CSV file", old),
+                )?;
+            }
+        }
+
         // create a null value for every column
         let mut null_values = null_values.map(|nv| nv.compile(&schema)).transpose()?;
 
+        let columns = match columns_regex {
+            Some(re) => {
+                polars_ensure!(
+                    columns.is_none(),
+                    InvalidOperation: "cannot combine a column selection by name and by regex"
+                );
+                let cols = schema
+                    .iter_names()
+                    .filter(|name| re.is_match(name.as_str()))
+                    .map(|name| name.to_string())
+                    .collect::<Vec<_>>();
+                polars_ensure!(
+                    !cols.is_empty(),
+                    ColumnNotFound: "no column in the CSV file matches the regex '{}'", re
+                );
+                Some(cols)
+            },
+            None => columns,
+        };
+
         if let Some(cols) = columns {
+            polars_ensure!(
+                projection.is_none(),
+                InvalidOperation: "cannot combine a column projection by name and by index"
+            );
             let mut prj = Vec::with_capacity(cols.len());
             for col in cols {
-                let i = schema.try_index_of(&col)?;
+                // the column may be selected by its name in the file
+                let i = match schema.index_of(&col) {
+                    Some(i) => i,
+                    None => match column_renames.iter().find(|(old, _)| *old == col) {
+                        Some((_, new)) => schema.try_index_of(new)?,
+                        None => schema.try_index_of(&col)?,
+                    },
+                };
                 prj.push(i);
             }
 
-            // update null values with projection
-            if let Some(nv) = null_values.as_mut() {
+            projection = Some(prj);
+        }
+
+        // update null values with projection, `parse_lines` visits the projected fields in
+        // sorted order.
+        if let (Some(nv), Some(prj)) = (null_values.as_mut(), projection.as_ref()) {
+            let mut prj = prj.clone();
+            prj.sort_unstable();
+            if prj.last().map_or(true, |&i| i < schema.len()) {
                 nv.apply_projection(&prj);
             }
-
-            projection = Some(prj);
         }
 
         Ok(CoreReader {
@@ -243,29 +373,73 @@ impl<'a> CoreReader<'a> {
             schema,
             projection,
             line_number: usize::from(has_header),
-            ignore_errors,
+            parse_options,
             skip_rows_before_header: skip_rows,
             skip_rows_after_header,
+            skip_footer,
             n_rows,
-            encoding,
             n_threads,
             has_header,
-            separator,
             sample_size,
             chunk_size,
+            chunk_bytes,
+            expected_rows,
             low_memory,
-            comment_prefix,
-            quote_char,
-            eol_char,
             null_values,
-            missing_is_null,
             predicate,
             to_cast,
             row_index,
-            truncate_ragged_lines,
+            error_report: None,
+            counters: None,
+            progress: None,
         })
     }
 
+    /// Collect the fields that fail to parse instead of silently turning them into nulls.
+    /// Only has an effect if `ignore_errors` is set.
+    pub(crate) fn collect_parse_errors(&mut self) {
+        if self.parse_options.ignore_errors {
+            self.error_report = Some(Mutex::default());
+        }
+    }
+
+    /// Count the rows and fields that are lost while parsing, see [`ReadStats`].
+    pub(crate) fn collect_read_stats(&mut self) {
+        self.counters = Some(ReadCounters::default());
+    }
+
+    /// Take the counts collected by [`CoreReader::as_df`]. `rows_parsed` is left to the caller,
+    /// who knows the final height of the frame.
+    pub(crate) fn take_read_stats(&mut self) -> ReadStats {
+        self.counters
+            .take()
+            .map(|counters| ReadStats {
+                rows_parsed: 0,
+                rows_skipped: counters.rows_skipped.into_inner(),
+                fields_coerced_to_null: counters.fields_coerced_to_null.into_inner(),
+            })
+            .unwrap_or_default()
+    }
+
+    /// Call `callback` with the number of parsed bytes and the total number of bytes while
+    /// reading with [`CoreReader::as_df`].
+    pub(crate) fn set_progress_callback(&mut self, callback: ProgressCallback) {
+        self.progress = Some(Progress {
+            callback,
+            total: None,
+            read: AtomicUsize::new(0),
+            reported: Mutex::new(0),
+        });
+    }
+
+    /// Take the parse errors collected by [`CoreReader::as_df`], sorted by their position in the file.
+    pub(crate) fn take_parse_errors(&mut self) -> Vec<CsvParseError> {
+        self.error_report
+            .take()
+            .map(|errors| errors.into_inner().unwrap())
+            .unwrap_or_default()
+    }
+
     fn find_starting_point<'b>(
         &self,
         mut bytes: &'b [u8],
@@ -274,8 +448,12 @@ impl<'a> CoreReader<'a> {
     ) -> PolarsResult<(&'b [u8], Option<usize>)> {
         let starting_point_offset = bytes.as_ptr() as usize;
 
+        // skip 'n' trailing rows
+        bytes = skip_footer_lines(bytes, self.skip_footer, eol_char);
+
         // Skip all leading white space and the occasional utf8-bom
-        bytes = skip_whitespace_exclude(skip_bom(bytes), self.separator);
+        check_no_utf16_bom(bytes)?;
+        bytes = skip_whitespace_exclude(skip_bom(bytes), self.parse_options.separator);
         // \n\n can be a empty string row of a single column
         // in other cases we skip it.
         if self.schema.len() > 1 {
@@ -292,24 +470,39 @@ impl<'a> CoreReader<'a> {
         }
 
         // skip lines that are comments
-        while is_comment_line(bytes, self.comment_prefix.as_ref()) {
-            bytes = skip_this_line(bytes, quote_char, eol_char);
+        while is_comment_line(
+            bytes,
+            self.parse_options.comment_prefix.as_ref(),
+            self.parse_options.separator,
+        ) {
+            bytes = skip_this_line(bytes, quote_char, self.parse_options.escape_char, eol_char);
         }
 
         // skip header row
         if self.has_header {
-            bytes = skip_this_line(bytes, quote_char, eol_char);
+            bytes = skip_this_line(bytes, quote_char, self.parse_options.escape_char, eol_char);
         }
         // skip 'n' rows following the header
         if self.skip_rows_after_header > 0 {
             for _ in 0..self.skip_rows_after_header {
-                let pos = if is_comment_line(bytes, self.comment_prefix.as_ref()) {
+                let pos = if is_comment_line(
+                    bytes,
+                    self.parse_options.comment_prefix.as_ref(),
+                    self.parse_options.separator,
+                ) {
                     next_line_position_naive(bytes, eol_char)
                 } else {
                     // we don't pass expected fields
                     // as we want to skip all rows
                     // no matter the no. of fields
-                    next_line_position(bytes, None, self.separator, self.quote_char, eol_char)
+                    next_line_position(
+                        bytes,
+                        None,
+                        self.parse_options.separator,
+                        self.parse_options.quote_char,
+                        self.parse_options.escape_char,
+                        eol_char,
+                    )
                 }
                 .ok_or_else(|| polars_err!(NoData: "not enough lines to skip"))?;
 
@@ -344,10 +537,11 @@ impl<'a> CoreReader<'a> {
         if let Some((mean, std)) = get_line_stats(
             bytes,
             self.sample_size,
-            self.eol_char,
+            self.parse_options.eol_char,
             Some(self.schema.len()),
-            self.separator,
-            self.quote_char,
+            self.parse_options.separator,
+            self.parse_options.quote_char,
+            self.parse_options.escape_char,
         ) {
             if logging {
                 eprintln!("avg line length: {mean}\nstd. dev. line length: {std}");
@@ -367,16 +561,27 @@ impl<'a> CoreReader<'a> {
                 let n_bytes = (line_length_upper_bound * (n_rows as f32)) as usize;
 
                 if n_bytes < bytes.len() {
-                    if let Some(pos) = next_line_position(
-                        &bytes[n_bytes..],
-                        Some(self.schema.len()),
-                        self.separator,
-                        self.quote_char,
-                        self.eol_char,
-                    ) {
+                    let end_pos = match self.parse_options.quote_char {
+                        Some(quote_char) => next_record_position(
+                            bytes,
+                            n_bytes,
+                            quote_char,
+                            self.parse_options.escape_char,
+                            self.parse_options.eol_char,
+                        ),
+                        None => next_line_position(
+                            &bytes[n_bytes..],
+                            Some(self.schema.len()),
+                            self.parse_options.separator,
+                            self.parse_options.quote_char,
+                            self.parse_options.escape_char,
+                            self.parse_options.eol_char,
+                        )
+                        .map(|pos| n_bytes + pos),
+                    };
+                    if let Some(end_pos) = end_pos {
                         if set_upper_bound {
-                            (bytes, remaining_bytes) =
-                                (&bytes[..n_bytes + pos], Some(&bytes[n_bytes + pos..]))
+                            (bytes, remaining_bytes) = (&bytes[..end_pos], Some(&bytes[end_pos..]))
                         }
                     }
                 }
@@ -403,8 +608,11 @@ impl<'a> CoreReader<'a> {
         Option<&'a [u8]>,
     )> {
         // Make the variable mutable so that we can reassign the sliced file to this variable.
-        let (bytes, starting_point_offset) =
-            self.find_starting_point(bytes, self.quote_char, self.eol_char)?;
+        let (bytes, starting_point_offset) = self.find_starting_point(
+            bytes,
+            self.parse_options.quote_char,
+            self.parse_options.eol_char,
+        )?;
 
         let (bytes, total_rows, remaining_bytes) =
             self.estimate_rows_and_set_upper_bound(bytes, logging, true);
@@ -416,8 +624,26 @@ impl<'a> CoreReader<'a> {
             }
         }
 
-        let chunk_size = std::cmp::min(self.chunk_size, total_rows);
-        let n_file_chunks = *n_threads;
+        let mut chunk_size = std::cmp::min(self.chunk_size, total_rows);
+        if self.low_memory {
+            // bound the number of pre-allocated values per chunk, so that wide files
+            // are parsed in proportionally fewer rows at a time.
+            let n_cols = std::cmp::max(
+                self.projection
+                    .as_ref()
+                    .map_or(self.schema.len(), |p| p.len()),
+                1,
+            );
+            chunk_size = std::cmp::min(
+                chunk_size,
+                std::cmp::max(LOW_MEMORY_VALUES_PER_CHUNK / n_cols, 128),
+            );
+        }
+        // a single thread reads the file in one go
+        let n_file_chunks = match self.chunk_bytes {
+            Some(chunk_bytes) if *n_threads > 1 => std::cmp::max(bytes.len() / chunk_bytes, 1),
+            _ => *n_threads,
+        };
 
         // split the file by the nearest new line characters such that every thread processes
         // approximately the same number of rows.
@@ -426,9 +652,10 @@ impl<'a> CoreReader<'a> {
             bytes,
             n_file_chunks,
             Some(self.schema.len()),
-            self.separator,
-            self.quote_char,
-            self.eol_char,
+            self.parse_options.separator,
+            self.parse_options.quote_char,
+            self.parse_options.escape_char,
+            self.parse_options.eol_char,
         );
 
         if logging {
@@ -469,13 +696,28 @@ impl<'a> CoreReader<'a> {
         predicate: Option<&Arc<dyn PhysicalIoExpr>>,
     ) -> PolarsResult<DataFrame> {
         let logging = verbose();
+        let file_start = bytes.as_ptr() as usize;
         let (file_chunks, chunk_size, total_rows, starting_point_offset, bytes, remaining_bytes) =
             self.determine_file_chunks_and_statistics(&mut n_threads, bytes, logging)?;
         let projection = self.get_projection()?;
 
-        // An empty file with a schema should return an empty DataFrame with that schema
+        // An empty file with a schema should return an empty DataFrame with that schema,
+        // e.g. a file with only a header, but only with the projected columns
         if bytes.is_empty() {
-            let mut df = DataFrame::from(self.schema.as_ref());
+            let columns = projection
+                .iter()
+                .map(|&i| {
+                    let (name, dtype) = self.schema.get_at_index(i).unwrap();
+                    Series::new_empty(name, dtype)
+                })
+                .collect();
+            let mut df = unsafe { DataFrame::new_no_checks(columns) };
+            cast_columns(
+                &mut df,
+                &self.to_cast,
+                false,
+                self.parse_options.ignore_errors,
+            )?;
             if let Some(ref row_index) = self.row_index {
                 df.insert_column(0, Series::new_empty(&row_index.name, &IDX_DTYPE))?;
             }
@@ -491,7 +733,6 @@ impl<'a> CoreReader<'a> {
                     .into_par_iter()
                     .map(|(bytes_offset_thread, stop_at_nbytes)| {
                         let schema = self.schema.as_ref();
-                        let ignore_errors = self.ignore_errors;
                         let projection = &projection;
 
                         let mut read = bytes_offset_thread;
@@ -506,31 +747,32 @@ impl<'a> CoreReader<'a> {
                                 projection,
                                 chunk_size,
                                 schema,
-                                self.quote_char,
-                                self.encoding,
+                                self.parse_options.quote_char,
+                                self.parse_options.escape_char,
+                                self.parse_options.encoding,
                             )?;
 
                             let local_bytes = &bytes[read..stop_at_nbytes];
 
                             last_read = read;
                             let offset = read + starting_point_offset.unwrap();
-                            read += parse_lines(
+                            let n_bytes = parse_lines(
                                 local_bytes,
                                 offset,
-                                self.separator,
-                                self.comment_prefix.as_ref(),
-                                self.quote_char,
-                                self.eol_char,
-                                self.missing_is_null,
-                                ignore_errors,
-                                self.truncate_ragged_lines,
+                                &self.parse_options,
                                 self.null_values.as_ref(),
                                 projection,
                                 &mut buffers,
                                 chunk_size,
                                 self.schema.len(),
                                 &self.schema,
+                                self.error_report.as_ref(),
+                                self.counters.as_ref(),
                             )?;
+                            read += n_bytes;
+                            if let Some(progress) = &self.progress {
+                                progress.add(n_bytes);
+                            }
 
                             let columns = buffers
                                 .into_iter()
@@ -542,7 +784,12 @@ impl<'a> CoreReader<'a> {
                                 local_df.with_row_index_mut(&rc.name, Some(rc.offset));
                             };
 
-                            cast_columns(&mut local_df, &self.to_cast, false, self.ignore_errors)?;
+                            cast_columns(
+                                &mut local_df,
+                                &self.to_cast,
+                                false,
+                                self.parse_options.ignore_errors,
+                            )?;
                             let s = predicate.evaluate_io(&local_df)?;
                             let mask = s.bool()?;
                             local_df = local_df.filter(mask)?;
@@ -562,12 +809,18 @@ impl<'a> CoreReader<'a> {
             // let exponential growth solve the needed size. This leads to less memory overhead
             // in the later rechunk. Because we have large chunks they are easier reused for the
             // large final contiguous memory needed at the end.
-            let rows_per_thread = total_rows / n_threads;
-            let max_proxy = bytes.len() / n_threads / 2;
-            let capacity = if self.low_memory {
-                chunk_size
-            } else {
-                std::cmp::min(rows_per_thread, max_proxy)
+            // there may be more chunks than threads, see `chunk_bytes`
+            let n_file_chunks = std::cmp::max(file_chunks.len(), 1);
+            let rows_per_chunk = total_rows / n_file_chunks;
+            let max_proxy = bytes.len() / n_file_chunks / 2;
+            let expected_rows = self
+                .expected_rows
+                .map(|n| self.n_rows.map_or(n, |n_rows| std::cmp::min(n, n_rows)));
+            let capacity = match expected_rows {
+                _ if self.low_memory => chunk_size,
+                // the user knows better than the estimate
+                Some(n) => n.div_ceil(n_file_chunks),
+                None => std::cmp::min(rows_per_chunk, max_proxy),
             };
 
             let mut dfs = POOL.install(|| {
@@ -576,25 +829,28 @@ impl<'a> CoreReader<'a> {
                     .map(|(bytes_offset_thread, stop_at_nbytes)| {
                         let mut df = read_chunk(
                             bytes,
-                            self.separator,
+                            &self.parse_options,
                             self.schema.as_ref(),
-                            self.ignore_errors,
                             &projection,
                             bytes_offset_thread,
-                            self.quote_char,
-                            self.eol_char,
-                            self.comment_prefix.as_ref(),
                             capacity,
-                            self.encoding,
                             self.null_values.as_ref(),
-                            self.missing_is_null,
-                            self.truncate_ragged_lines,
                             usize::MAX,
                             stop_at_nbytes,
                             starting_point_offset,
+                            self.error_report.as_ref(),
+                            self.counters.as_ref(),
                         )?;
+                        if let Some(progress) = &self.progress {
+                            progress.add(stop_at_nbytes - bytes_offset_thread);
+                        }
 
-                        cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
+                        cast_columns(
+                            &mut df,
+                            &self.to_cast,
+                            false,
+                            self.parse_options.ignore_errors,
+                        )?;
                         if let Some(rc) = &self.row_index {
                             df.with_row_index_mut(&rc.name, Some(rc.offset));
                         }
@@ -609,30 +865,29 @@ impl<'a> CoreReader<'a> {
                     dfs.push({
                         let mut df = {
                             let remaining_rows = n_rows - rows_already_read;
+                            // `n_rows` may be far above the number of rows in the file, don't
+                            // preallocate for it
                             let mut buffers = init_buffers(
                                 &projection,
-                                remaining_rows,
+                                std::cmp::min(remaining_rows, chunk_size),
                                 self.schema.as_ref(),
-                                self.quote_char,
-                                self.encoding,
+                                self.parse_options.quote_char,
+                                self.parse_options.escape_char,
+                                self.parse_options.encoding,
                             )?;
 
                             parse_lines(
                                 remaining_bytes,
-                                0,
-                                self.separator,
-                                self.comment_prefix.as_ref(),
-                                self.quote_char,
-                                self.eol_char,
-                                self.missing_is_null,
-                                self.ignore_errors,
-                                self.truncate_ragged_lines,
+                                remaining_bytes.as_ptr() as usize - file_start,
+                                &self.parse_options,
                                 self.null_values.as_ref(),
                                 &projection,
                                 &mut buffers,
                                 remaining_rows - 1,
                                 self.schema.len(),
                                 self.schema.as_ref(),
+                                self.error_report.as_ref(),
+                                self.counters.as_ref(),
                             )?;
 
                             let columns = buffers
@@ -642,7 +897,12 @@ impl<'a> CoreReader<'a> {
                             unsafe { DataFrame::new_no_checks(columns) }
                         };
 
-                        cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
+                        cast_columns(
+                            &mut df,
+                            &self.to_cast,
+                            false,
+                            self.parse_options.ignore_errors,
+                        )?;
                         if let Some(rc) = &self.row_index {
                             df.with_row_index_mut(&rc.name, Some(rc.offset));
                         }
@@ -664,8 +924,38 @@ impl<'a> CoreReader<'a> {
         let n_threads = self.n_threads.unwrap_or_else(|| POOL.current_num_threads());
 
         let reader_bytes = self.reader_bytes.take().unwrap();
+        if let Some(progress) = self.progress.as_mut() {
+            progress.total = Some(reader_bytes.len());
+        }
 
-        let mut df = self.parse_csv(n_threads, &reader_bytes, predicate.as_ref())?;
+        let eol_char = self.parse_options.eol_char;
+        let mut df = self
+            .parse_csv(n_threads, &reader_bytes, predicate.as_ref())
+            .map_err(|mut e| {
+                if let Some(err) = CsvError::from_polars_mut(&mut e) {
+                    err.resolve_line(&reader_bytes, eol_char)
+                }
+                e
+            })?;
+        if let Some(progress) = &self.progress {
+            progress.finish(reader_bytes.len());
+        }
+
+        if let Some(error_report) = self.error_report.as_mut() {
+            let errors = error_report.get_mut().unwrap();
+            // the threads push in arbitrary order; sort by position and resolve the
+            // line numbers in a single pass over the file.
+            errors.sort_unstable_by_key(|e| e.offset);
+            let mut line = 1;
+            let mut pos = 0;
+            for e in errors.iter_mut() {
+                line +=
+                    memchr::memchr_iter(self.parse_options.eol_char, &reader_bytes[pos..e.offset])
+                        .count();
+                pos = e.offset;
+                e.line = line;
+            }
+        }
 
         // if multi-threaded the n_rows was probabilistically determined.
         // Let's slice to correct number of rows if possible.
@@ -681,25 +971,27 @@ impl<'a> CoreReader<'a> {
 #[allow(clippy::too_many_arguments)]
 fn read_chunk(
     bytes: &[u8],
-    separator: u8,
+    parse_options: &ParseOptions,
     schema: &Schema,
-    ignore_errors: bool,
     projection: &[usize],
     bytes_offset_thread: usize,
-    quote_char: Option<u8>,
-    eol_char: u8,
-    comment_prefix: Option<&CommentPrefix>,
     capacity: usize,
-    encoding: CsvEncoding,
     null_values: Option<&NullValuesCompiled>,
-    missing_is_null: bool,
-    truncate_ragged_lines: bool,
     chunk_size: usize,
     stop_at_nbytes: usize,
     starting_point_offset: Option<usize>,
+    error_report: Option<&Mutex<Vec<CsvParseError>>>,
+    counters: Option<&ReadCounters>,
 ) -> PolarsResult<DataFrame> {
     let mut read = bytes_offset_thread;
-    let mut buffers = init_buffers(projection, capacity, schema, quote_char, encoding)?;
+    let mut buffers = init_buffers(
+        projection,
+        capacity,
+        schema,
+        parse_options.quote_char,
+        parse_options.escape_char,
+        parse_options.encoding,
+    )?;
 
     let mut last_read = usize::MAX;
     loop {
@@ -713,19 +1005,15 @@ fn read_chunk(
         read += parse_lines(
             local_bytes,
             offset,
-            separator,
-            comment_prefix,
-            quote_char,
-            eol_char,
-            missing_is_null,
-            ignore_errors,
-            truncate_ragged_lines,
+            parse_options,
             null_values,
             projection,
             &mut buffers,
             chunk_size,
             schema.len(),
             schema,
+            error_report,
+            counters,
         )?;
     }
 
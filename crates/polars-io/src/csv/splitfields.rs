@@ -3,12 +3,20 @@ mod inner {
     /// An adapted version of std::iter::Split.
     /// This exists solely because we cannot split the lines naively as
     pub(crate) struct SplitFields<'a> {
-        v: &'a [u8],
+        pub v: &'a [u8],
         separator: u8,
         finished: bool,
         quote_char: u8,
         quoting: bool,
+        escape_char: u8,
+        escaping: bool,
         eol_char: u8,
+        max_field_len: usize,
+        /// Set if a quoted field exceeded `max_field_len` without a closing quote. The iterator
+        /// is finished then and `v` starts at the offending field.
+        pub field_too_long: bool,
+        /// Set if the last field starts with a quote that isn't closed before the end of `v`.
+        pub unterminated_quote: bool,
     }
 
     impl<'a> SplitFields<'a> {
@@ -16,6 +24,7 @@ mod inner {
             slice: &'a [u8],
             separator: u8,
             quote_char: Option<u8>,
+            escape_char: Option<u8>,
             eol_char: u8,
         ) -> Self {
             Self {
@@ -24,10 +33,22 @@ mod inner {
                 finished: false,
                 quote_char: quote_char.unwrap_or(b'"'),
                 quoting: quote_char.is_some(),
+                escape_char: escape_char.unwrap_or(b'\\'),
+                escaping: escape_char.is_some(),
                 eol_char,
+                max_field_len: usize::MAX,
+                field_too_long: false,
+                unterminated_quote: false,
             }
         }
 
+        /// Stop at a quoted field that is longer than `max_field_len` bytes without a closing
+        /// quote, see `field_too_long`.
+        pub(crate) fn with_max_field_len(mut self, max_field_len: Option<usize>) -> Self {
+            self.max_field_len = max_field_len.unwrap_or(usize::MAX);
+            self
+        }
+
         unsafe fn finish_eol(
             &mut self,
             need_escaping: bool,
@@ -75,17 +96,32 @@ mod inner {
 
                 // denotes if we are in a string field, started with a quote
                 let mut in_field = false;
+                // denotes if the previous byte was an escape char within a string field
+                let mut escaped = false;
 
                 let mut idx = 0u32;
                 let mut current_idx = 0u32;
                 // micro optimizations
                 #[allow(clippy::explicit_counter_loop)]
                 for &c in self.v.iter() {
+                    if escaped {
+                        // the escaped byte is taken literally
+                        escaped = false;
+                        current_idx += 1;
+                        continue;
+                    }
                     if c == self.quote_char {
                         // toggle between string field enclosure
                         //      if we encounter a starting '"' -> in_field = true;
                         //      if we encounter a closing '"' -> in_field = false;
                         in_field = !in_field;
+                    } else if self.escaping && in_field && c == self.escape_char {
+                        escaped = true;
+                    }
+                    if in_field && current_idx as usize >= self.max_field_len {
+                        self.finished = true;
+                        self.field_too_long = true;
+                        return None;
                     }
 
                     if !in_field && self.eof_oel(c) {
@@ -103,6 +139,7 @@ mod inner {
                 }
 
                 if idx == 0 {
+                    self.unterminated_quote = in_field;
                     return self.finish(needs_escaping);
                 }
 
@@ -163,7 +200,15 @@ mod inner {
         pub finished: bool,
         quote_char: u8,
         quoting: bool,
+        escape_char: u8,
+        escaping: bool,
         eol_char: u8,
+        max_field_len: usize,
+        /// Set if a quoted field exceeded `max_field_len` without a closing quote. The iterator
+        /// is finished then and `v` starts at the offending field.
+        pub field_too_long: bool,
+        /// Set if the last field starts with a quote that isn't closed before the end of `v`.
+        pub unterminated_quote: bool,
         simd_separator: SimdVec,
         simd_eol_char: SimdVec,
     }
@@ -173,6 +218,7 @@ mod inner {
             slice: &'a [u8],
             separator: u8,
             quote_char: Option<u8>,
+            escape_char: Option<u8>,
             eol_char: u8,
         ) -> Self {
             let simd_separator = SimdVec::splat(separator);
@@ -184,12 +230,24 @@ mod inner {
                 finished: false,
                 quote_char: quote_char.unwrap_or(b'"'),
                 quoting: quote_char.is_some(),
+                escape_char: escape_char.unwrap_or(b'\\'),
+                escaping: escape_char.is_some(),
                 eol_char,
+                max_field_len: usize::MAX,
+                field_too_long: false,
+                unterminated_quote: false,
                 simd_separator,
                 simd_eol_char,
             }
         }
 
+        /// Stop at a quoted field that is longer than `max_field_len` bytes without a closing
+        /// quote, see `field_too_long`.
+        pub(crate) fn with_max_field_len(mut self, max_field_len: Option<usize>) -> Self {
+            self.max_field_len = max_field_len.unwrap_or(usize::MAX);
+            self
+        }
+
         unsafe fn finish_eol(
             &mut self,
             need_escaping: bool,
@@ -237,17 +295,32 @@ mod inner {
 
                 // denotes if we are in a string field, started with a quote
                 let mut in_field = false;
+                // denotes if the previous byte was an escape char within a string field
+                let mut escaped = false;
 
                 let mut idx = 0u32;
                 let mut current_idx = 0u32;
                 // micro optimizations
                 #[allow(clippy::explicit_counter_loop)]
                 for &c in self.v.iter() {
+                    if escaped {
+                        // the escaped byte is taken literally
+                        escaped = false;
+                        current_idx += 1;
+                        continue;
+                    }
                     if c == self.quote_char {
                         // toggle between string field enclosure
                         //      if we encounter a starting '"' -> in_field = true;
                         //      if we encounter a closing '"' -> in_field = false;
                         in_field = !in_field;
+                    } else if self.escaping && in_field && c == self.escape_char {
+                        escaped = true;
+                    }
+                    if in_field && current_idx as usize >= self.max_field_len {
+                        self.finished = true;
+                        self.field_too_long = true;
+                        return None;
                     }
 
                     if !in_field && self.eof_oel(c) {
@@ -265,6 +338,7 @@ mod inner {
                 }
 
                 if idx == 0 {
+                    self.unterminated_quote = in_field;
                     return self.finish(needs_escaping);
                 }
 
@@ -5,21 +5,27 @@ use std::mem::MaybeUninit;
 
 use polars_core::config::verbose;
 use polars_core::prelude::*;
+use polars_core::POOL;
 #[cfg(feature = "polars-time")]
 use polars_time::chunkedarray::string::infer as date_infer;
 #[cfg(feature = "polars-time")]
 use polars_time::prelude::string::Pattern;
 use polars_utils::slice::GetSaferUnchecked;
+use rayon::prelude::*;
+use regex::Regex;
 
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
 use crate::csv::parser::next_line_position_naive;
-use crate::csv::parser::{next_line_position, skip_bom, skip_line_ending, SplitLines};
+use crate::csv::parser::{
+    check_no_utf16_bom, is_blank_line, is_valid_digit_grouping, next_line_position,
+    next_record_position, skip_bom, skip_line_ending, trim_whitespace, SplitLines,
+};
 use crate::csv::splitfields::SplitFields;
 use crate::csv::CsvEncoding;
 use crate::mmap::ReaderBytes;
 use crate::prelude::parser::is_comment_line;
-use crate::prelude::{CommentPrefix, NullValues};
-use crate::utils::{BOOLEAN_RE, FLOAT_RE, INTEGER_RE};
+use crate::prelude::{BoolValues, CommentPrefix, NullValues};
+use crate::utils::{BOOLEAN_RE, FLOAT_RE, FLOAT_RE_DECIMAL, INTEGER_RE};
 
 pub(crate) fn get_file_chunks(
     bytes: &[u8],
@@ -27,6 +33,7 @@ pub(crate) fn get_file_chunks(
     expected_fields: Option<usize>,
     separator: u8,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 ) -> Vec<(usize, usize)> {
     let mut last_pos = 0;
@@ -40,17 +47,27 @@ pub(crate) fn get_file_chunks(
             break;
         }
 
-        let end_pos = match next_line_position(
-            &bytes[search_pos..],
-            expected_fields,
-            separator,
-            quote_char,
-            eol_char,
-        ) {
-            Some(pos) => search_pos + pos,
-            None => {
-                break;
-            },
+        let end_pos = match quote_char {
+            Some(quote_char) => next_record_position(
+                &bytes[last_pos..],
+                chunk_size,
+                quote_char,
+                escape_char,
+                eol_char,
+            )
+            .map(|pos| last_pos + pos),
+            None => next_line_position(
+                &bytes[search_pos..],
+                expected_fields,
+                separator,
+                quote_char,
+                escape_char,
+                eol_char,
+            )
+            .map(|pos| search_pos + pos),
+        };
+        let Some(end_pos) = end_pos else {
+            break;
         };
         offsets.push((last_pos, end_pos));
         last_pos = end_pos;
@@ -60,7 +77,20 @@ pub(crate) fn get_file_chunks(
 }
 
 /// Infer the data type of a record
-fn infer_field_schema(string: &str, try_parse_dates: bool) -> DataType {
+fn infer_field_schema(
+    string: &str,
+    try_parse_dates: bool,
+    decimal_comma: bool,
+    thousands_separator: Option<u8>,
+    bool_values: Option<&BoolValues>,
+) -> DataType {
+    let float_re: &Regex = if decimal_comma {
+        &FLOAT_RE_DECIMAL
+    } else {
+        &FLOAT_RE
+    };
+    let number = thousands_separator.and_then(|sep| strip_thousands_separator(string, sep));
+    let number = number.as_deref().unwrap_or(string);
     // when quoting is enabled in the reader, these quotes aren't escaped, we default to
     // String for them
     if string.starts_with('"') {
@@ -89,11 +119,14 @@ fn infer_field_schema(string: &str, try_parse_dates: bool) -> DataType {
         }
     }
     // match regex in a particular order
-    else if BOOLEAN_RE.is_match(string) {
+    else if bool_values.map_or_else(
+        || BOOLEAN_RE.is_match(string),
+        |bv| bv.parse(string.as_bytes()).is_some(),
+    ) {
         DataType::Boolean
-    } else if FLOAT_RE.is_match(string) {
+    } else if float_re.is_match(number) {
         DataType::Float64
-    } else if INTEGER_RE.is_match(string) {
+    } else if INTEGER_RE.is_match(number) {
         DataType::Int64
     } else if try_parse_dates {
         #[cfg(feature = "polars-time")]
@@ -120,7 +153,25 @@ fn infer_field_schema(string: &str, try_parse_dates: bool) -> DataType {
     }
 }
 
+/// Remove the thousands separator from a number with a valid digit grouping, e.g. `1,234,567.8`.
+/// Returns `None` if `s` is not such a number.
+fn strip_thousands_separator(s: &str, sep: u8) -> Option<String> {
+    let valid = s.as_bytes().contains(&sep) && is_valid_digit_grouping(s.as_bytes(), sep);
+    valid.then(|| s.replace(sep as char, ""))
+}
+
 #[inline]
+/// Skip `n` physical lines, regardless of quotes, like the reader does before the header.
+fn skip_physical_lines(mut bytes: &[u8], n: usize, eol_char: u8) -> &[u8] {
+    for _ in 0..n {
+        match memchr::memchr(eol_char, bytes) {
+            Some(pos) => bytes = &bytes[pos + 1..],
+            None => return &bytes[bytes.len()..],
+        }
+    }
+    bytes
+}
+
 pub(crate) fn parse_bytes_with_encoding(
     bytes: &[u8],
     encoding: CsvEncoding,
@@ -130,15 +181,63 @@ pub(crate) fn parse_bytes_with_encoding(
             .map_err(|_| polars_err!(ComputeError: "invalid utf-8 sequence"))?
             .into(),
         CsvEncoding::LossyUtf8 => String::from_utf8_lossy(bytes),
+        CsvEncoding::Latin1 => decode_latin1(bytes),
     })
 }
 
+/// Transcode latin-1 bytes to utf8. Every byte maps to the unicode code point of the same value.
+#[inline]
+pub(crate) fn decode_latin1(bytes: &[u8]) -> Cow<str> {
+    if bytes.is_ascii() {
+        // SAFETY: ascii is valid utf8
+        unsafe { std::str::from_utf8_unchecked(bytes) }.into()
+    } else {
+        bytes.iter().map(|&b| b as char).collect::<String>().into()
+    }
+}
+
+/// Below this number of rows the schema is inferred on a single thread.
+const MIN_INFER_WINDOW_LEN: usize = 1024;
+
+/// The dtypes found in a window of the rows that are used to infer the schema.
+struct InferredWindow {
+    column_types: Vec<PlHashSet<DataType>>,
+    // the number of values per column, for the inference null threshold
+    value_counts: Vec<usize>,
+    records_count: usize,
+    many_escaped_eols: bool,
+}
+
+impl InferredWindow {
+    fn new(n_columns: usize) -> Self {
+        InferredWindow {
+            column_types: vec![PlHashSet::with_capacity(4); n_columns],
+            value_counts: vec![0; n_columns],
+            records_count: 0,
+            many_escaped_eols: false,
+        }
+    }
+
+    fn merge(mut self, other: Self) -> Self {
+        for (types, other) in self.column_types.iter_mut().zip(other.column_types) {
+            types.extend(other)
+        }
+        for (count, other) in self.value_counts.iter_mut().zip(other.value_counts) {
+            *count += other
+        }
+        self.records_count += other.records_count;
+        self.many_escaped_eols |= other.many_escaped_eols;
+        self
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn infer_file_schema_inner(
     reader_bytes: &ReaderBytes,
     separator: u8,
     max_read_rows: Option<usize>,
     has_header: bool,
+    dedup_column_names: bool,
     schema_overwrite: Option<&Schema>,
     // we take &mut because we maybe need to skip more rows dependent
     // on the schema inference
@@ -146,8 +245,15 @@ pub fn infer_file_schema_inner(
     skip_rows_after_header: usize,
     comment_prefix: Option<&CommentPrefix>,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
+    encoding: CsvEncoding,
     null_values: Option<&NullValues>,
+    truncate_whitespace: bool,
+    decimal_comma: bool,
+    thousands_separator: Option<u8>,
+    bool_values: Option<&BoolValues>,
+    inference_null_threshold: Option<f64>,
     try_parse_dates: bool,
     recursion_count: u8,
     raise_if_empty: bool,
@@ -157,14 +263,33 @@ pub fn infer_file_schema_inner(
     let start_ptr = reader_bytes.as_ptr() as usize;
 
     // We use lossy utf8 here because we don't want the schema inference to fail on utf8.
-    // It may later.
-    let encoding = CsvEncoding::LossyUtf8;
+    // It may later. Latin1 can't fail and must be decoded as the fields will be.
+    let encoding = match encoding {
+        CsvEncoding::Latin1 => CsvEncoding::Latin1,
+        CsvEncoding::Utf8 | CsvEncoding::LossyUtf8 => CsvEncoding::LossyUtf8,
+    };
 
+    if let Some(frac) = inference_null_threshold {
+        polars_ensure!(
+            (0.0..=1.0).contains(&frac),
+            ComputeError: "inference null threshold should be between 0.0 and 1.0, got {}", frac
+        );
+    }
+    check_no_utf16_bom(reader_bytes)?;
     let bytes = skip_line_ending(skip_bom(reader_bytes), eol_char);
     if raise_if_empty {
         polars_ensure!(!bytes.is_empty(), NoData: "empty CSV");
     };
-    let mut lines = SplitLines::new(bytes, quote_char.unwrap_or(b'"'), eol_char).skip(*skip_rows);
+    // the lines before the header are skipped as physical lines, a preamble may contain
+    // unbalanced quotes
+    let preamble_rows = *skip_rows;
+    let after_preamble = skip_physical_lines(bytes, preamble_rows, eol_char);
+    let mut lines = SplitLines::new(
+        after_preamble,
+        quote_char.unwrap_or(b'"'),
+        escape_char,
+        eol_char,
+    );
 
     // get or create header names
     // when has_header is false, creates default column names with column_ prefix
@@ -173,7 +298,7 @@ pub fn infer_file_schema_inner(
     let mut first_line = None;
 
     for (i, line) in (&mut lines).enumerate() {
-        if !is_comment_line(line, comment_prefix) {
+        if !is_comment_line(line, comment_prefix, separator) {
             first_line = Some(line);
             *skip_rows += i;
             break;
@@ -195,7 +320,8 @@ pub fn infer_file_schema_inner(
             }
         }
 
-        let byterecord = SplitFields::new(header_line, separator, quote_char, eol_char);
+        let byterecord =
+            SplitFields::new(header_line, separator, quote_char, escape_char, eol_char);
         if has_header {
             let headers = byterecord
                 .map(|(slice, needs_escaping)| {
@@ -212,11 +338,22 @@ pub fn infer_file_schema_inner(
             let mut final_headers = Vec::with_capacity(headers.len());
 
             let mut header_names = PlHashMap::with_capacity(headers.len());
+            let file_names = headers.iter().map(|s| s.as_ref()).collect::<PlHashSet<_>>();
 
             for name in &headers {
                 let count = header_names.entry(name.as_ref()).or_insert(0usize);
                 if *count != 0 {
-                    final_headers.push(format!("{}_duplicated_{}", name, *count - 1))
+                    polars_ensure!(
+                        dedup_column_names,
+                        Duplicate: "CSV header contains duplicate column name '{}'", name
+                    );
+                    // skip names that are taken by another column of the file
+                    let mut new_name = format!("{}_duplicated_{}", name, *count - 1);
+                    while file_names.contains(new_name.as_str()) {
+                        *count += 1;
+                        new_name = format!("{}_duplicated_{}", name, *count - 1);
+                    }
+                    final_headers.push(new_name)
                 } else {
                     final_headers.push(name.to_string())
                 }
@@ -241,13 +378,21 @@ pub fn infer_file_schema_inner(
             separator,
             max_read_rows,
             has_header,
+            dedup_column_names,
             schema_overwrite,
             skip_rows,
             skip_rows_after_header,
             comment_prefix,
             quote_char,
+            escape_char,
             eol_char,
+            encoding,
             null_values,
+            truncate_whitespace,
+            decimal_comma,
+            thousands_separator,
+            bool_values,
+            inference_null_threshold,
             try_parse_dates,
             recursion_count + 1,
             raise_if_empty,
@@ -260,24 +405,29 @@ pub fn infer_file_schema_inner(
     };
     if !has_header {
         // re-init lines so that the header is included in type inference.
-        lines = SplitLines::new(bytes, quote_char.unwrap_or(b'"'), eol_char).skip(*skip_rows);
+        lines = SplitLines::new(
+            after_preamble,
+            quote_char.unwrap_or(b'"'),
+            escape_char,
+            eol_char,
+        );
+        (&mut lines).take(*skip_rows - preamble_rows).for_each(drop);
     }
 
     let header_length = headers.len();
-    // keep track of inferred field types
-    let mut column_types: Vec<PlHashSet<DataType>> =
-        vec![PlHashSet::with_capacity(4); header_length];
-    // keep track of columns with nulls
-    let mut nulls: Vec<bool> = vec![false; header_length];
-
-    let mut rows_count = 0;
     let mut fields = Vec::with_capacity(header_length);
 
-    // needed to prevent ownership going into the iterator loop
-    let records_ref = &mut lines;
+    let infer_dtype = |s: &str| {
+        infer_field_schema(
+            s,
+            try_parse_dates,
+            decimal_comma,
+            thousands_separator,
+            bool_values,
+        )
+    };
 
-    let mut end_ptr = start_ptr;
-    for mut line in records_ref
+    let lines = lines
         .take(match max_read_rows {
             Some(max_read_rows) => {
                 if max_read_rows <= (usize::MAX - skip_rows_after_header) {
@@ -292,98 +442,148 @@ pub fn infer_file_schema_inner(
             None => usize::MAX,
         })
         .skip(skip_rows_after_header)
-    {
-        rows_count += 1;
-        // keep track so that we can determine the amount of bytes read
-        end_ptr = line.as_ptr() as usize + line.len();
+        .collect::<Vec<_>>();
+    let rows_count = lines.len();
+    // keep track so that we can determine the amount of bytes read
+    let end_ptr = lines
+        .last()
+        .map_or(start_ptr, |line| line.as_ptr() as usize + line.len());
 
-        if line.is_empty() {
-            continue;
-        }
+    let check_escaped_eols = n_threads.unwrap_or(2) > 1;
+    let infer_window = |lines: &[&[u8]]| -> PolarsResult<InferredWindow> {
+        let mut window = InferredWindow::new(header_length);
+        for mut line in lines.iter().copied() {
+            // blank lines don't tell anything about the dtypes, with a single column they are
+            // nulls
+            if line.is_empty() || (header_length > 1 && is_blank_line(line, separator, eol_char)) {
+                continue;
+            }
 
-        // line is a comment -> skip
-        if is_comment_line(line, comment_prefix) {
-            continue;
-        }
+            // line is a comment -> skip
+            if is_comment_line(line, comment_prefix, separator) {
+                continue;
+            }
 
-        let len = line.len();
-        if len > 1 {
-            // remove carriage return
-            let trailing_byte = line[len - 1];
-            if trailing_byte == b'\r' {
-                line = &line[..len - 1];
+            let len = line.len();
+            if len > 1 {
+                // remove carriage return
+                let trailing_byte = line[len - 1];
+                if trailing_byte == b'\r' {
+                    line = &line[..len - 1];
+                }
             }
-        }
 
-        let mut record = SplitFields::new(line, separator, quote_char, eol_char);
+            let mut record = SplitFields::new(line, separator, quote_char, escape_char, eol_char);
+            window.records_count += 1;
 
-        for i in 0..header_length {
-            if let Some((slice, needs_escaping)) = record.next() {
+            for i in 0..header_length {
+                let Some((mut slice, needs_escaping)) = record.next() else {
+                    continue;
+                };
+                if truncate_whitespace && !needs_escaping {
+                    slice = trim_whitespace(slice);
+                }
+                // an empty field is a null
                 if slice.is_empty() {
-                    unsafe { *nulls.get_unchecked_release_mut(i) = true };
+                    continue;
+                }
+                let slice_escaped = if needs_escaping && (slice.len() >= 2) {
+                    &slice[1..(slice.len() - 1)]
                 } else {
-                    let slice_escaped = if needs_escaping && (slice.len() >= 2) {
-                        &slice[1..(slice.len() - 1)]
-                    } else {
-                        slice
-                    };
-                    let s = parse_bytes_with_encoding(slice_escaped, encoding)?;
-                    let dtype = match &null_values {
-                        None => Some(infer_field_schema(&s, try_parse_dates)),
-                        Some(NullValues::AllColumns(names)) => {
-                            if !names.iter().any(|nv| nv == s.as_ref()) {
-                                Some(infer_field_schema(&s, try_parse_dates))
-                            } else {
-                                None
-                            }
-                        },
-                        Some(NullValues::AllColumnsSingle(name)) => {
-                            if s.as_ref() != name {
-                                Some(infer_field_schema(&s, try_parse_dates))
+                    slice
+                };
+                let s = parse_bytes_with_encoding(slice_escaped, encoding)?;
+                let dtype = match &null_values {
+                    None => Some(infer_dtype(&s)),
+                    Some(NullValues::AllColumns(names)) => {
+                        if !names.iter().any(|nv| nv == s.as_ref()) {
+                            Some(infer_dtype(&s))
+                        } else {
+                            None
+                        }
+                    },
+                    Some(NullValues::AllColumnsSingle(name)) => {
+                        if s.as_ref() != name {
+                            Some(infer_dtype(&s))
+                        } else {
+                            None
+                        }
+                    },
+                    Some(NullValues::Named(names)) => {
+                        // SAFETY:
+                        // we iterate over headers length.
+                        let current_name = unsafe { headers.get_unchecked_release(i) };
+                        let null_name = &names.iter().find(|name| &name.0 == current_name);
+
+                        if let Some(null_name) = null_name {
+                            if null_name.1 != s.as_ref() {
+                                Some(infer_dtype(&s))
                             } else {
                                 None
                             }
-                        },
-                        Some(NullValues::Named(names)) => {
-                            // SAFETY:
-                            // we iterate over headers length.
-                            let current_name = unsafe { headers.get_unchecked_release(i) };
-                            let null_name = &names.iter().find(|name| &name.0 == current_name);
-
-                            if let Some(null_name) = null_name {
-                                if null_name.1 != s.as_ref() {
-                                    Some(infer_field_schema(&s, try_parse_dates))
-                                } else {
-                                    None
-                                }
-                            } else {
-                                Some(infer_field_schema(&s, try_parse_dates))
-                            }
-                        },
-                    };
-                    if let Some(dtype) = dtype {
-                        if matches!(&dtype, DataType::String)
-                            && needs_escaping
-                            && n_threads.unwrap_or(2) > 1
-                        {
-                            // The parser will chunk the file.
-                            // However this will be increasingly unlikely to be correct if there are many
-                            // new line characters in an escaped field. So we set a (somewhat arbitrary)
-                            // upper bound to the number of escaped lines we accept.
-                            // On the chunking side we also have logic to make this more robust.
-                            if slice.iter().filter(|b| **b == eol_char).count() > 8 {
-                                if verbose() {
-                                    eprintln!("falling back to single core reading because of many escaped new line chars.")
-                                }
-                                *n_threads = Some(1);
-                            }
+                        } else {
+                            Some(infer_dtype(&s))
                         }
-                        unsafe { column_types.get_unchecked_release_mut(i).insert(dtype) };
+                    },
+                };
+                if let Some(dtype) = dtype {
+                    // The parser will chunk the file.
+                    // However this will be increasingly unlikely to be correct if there are many
+                    // new line characters in an escaped field. So we set a (somewhat arbitrary)
+                    // upper bound to the number of escaped lines we accept.
+                    // On the chunking side we also have logic to make this more robust.
+                    if check_escaped_eols
+                        && matches!(&dtype, DataType::String)
+                        && needs_escaping
+                        && slice.iter().filter(|b| **b == eol_char).count() > 8
+                    {
+                        window.many_escaped_eols = true;
                     }
+                    unsafe {
+                        window
+                            .column_types
+                            .get_unchecked_release_mut(i)
+                            .insert(dtype)
+                    };
+                    window.value_counts[i] += 1;
                 }
             }
         }
+        Ok(window)
+    };
+
+    // The sampled rows are inferred in windows in parallel. Merging the windows gives the same
+    // dtypes as inferring all rows at once, also when the dtype of a column changes deep into
+    // the sample.
+    let n_windows = n_threads
+        .unwrap_or_else(|| POOL.current_num_threads())
+        .max(1);
+    let window_len = rows_count.div_ceil(n_windows).max(MIN_INFER_WINDOW_LEN);
+    let inferred = if rows_count > window_len {
+        POOL.install(|| {
+            lines
+                .par_chunks(window_len)
+                .map(infer_window)
+                .collect::<PolarsResult<Vec<_>>>()
+        })?
+        .into_iter()
+        .reduce(InferredWindow::merge)
+        .unwrap()
+    } else {
+        infer_window(&lines)?
+    };
+    if inferred.many_escaped_eols {
+        if verbose() {
+            eprintln!("falling back to single core reading because of many escaped new line chars.")
+        }
+        *n_threads = Some(1);
     }
+    let InferredWindow {
+        column_types,
+        value_counts,
+        records_count,
+        ..
+    } = inferred;
 
     // build schema from inference results
     for i in 0..header_length {
@@ -406,6 +606,14 @@ pub fn infer_file_schema_inner(
             }
         }
 
+        // too few values to trust the inferred type
+        if inference_null_threshold.map_or(false, |frac| {
+            (value_counts[i] as f64) < frac * records_count as f64
+        }) {
+            fields.push(Field::new(field_name, DataType::String));
+            continue;
+        }
+
         // determine data type based on possible types
         // if there are incompatible types, use DataType::String
         match possibilities.len() {
@@ -444,13 +652,21 @@ pub fn infer_file_schema_inner(
             separator,
             max_read_rows,
             has_header,
+            dedup_column_names,
             schema_overwrite,
             skip_rows,
             skip_rows_after_header,
             comment_prefix,
             quote_char,
+            escape_char,
             eol_char,
+            encoding,
             null_values,
+            truncate_whitespace,
+            decimal_comma,
+            thousands_separator,
+            bool_values,
+            inference_null_threshold,
             try_parse_dates,
             recursion_count + 1,
             raise_if_empty,
@@ -476,6 +692,7 @@ pub fn infer_file_schema(
     separator: u8,
     max_read_rows: Option<usize>,
     has_header: bool,
+    dedup_column_names: bool,
     schema_overwrite: Option<&Schema>,
     // we take &mut because we maybe need to skip more rows dependent
     // on the schema inference
@@ -483,8 +700,15 @@ pub fn infer_file_schema(
     skip_rows_after_header: usize,
     comment_prefix: Option<&CommentPrefix>,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
+    encoding: CsvEncoding,
     null_values: Option<&NullValues>,
+    truncate_whitespace: bool,
+    decimal_comma: bool,
+    thousands_separator: Option<u8>,
+    bool_values: Option<&BoolValues>,
+    inference_null_threshold: Option<f64>,
     try_parse_dates: bool,
     raise_if_empty: bool,
     n_threads: &mut Option<usize>,
@@ -494,13 +718,21 @@ pub fn infer_file_schema(
         separator,
         max_read_rows,
         has_header,
+        dedup_column_names,
         schema_overwrite,
         skip_rows,
         skip_rows_after_header,
         comment_prefix,
         quote_char,
+        escape_char,
         eol_char,
+        encoding,
         null_values,
+        truncate_whitespace,
+        decimal_comma,
+        thousands_separator,
+        bool_values,
+        inference_null_threshold,
         try_parse_dates,
         0,
         raise_if_empty,
@@ -508,6 +740,82 @@ pub fn infer_file_schema(
     )
 }
 
+/// The separators that [`sniff_separator`] chooses from.
+const SEPARATOR_CANDIDATES: [u8; 4] = [b',', b';', b'\t', b'|'];
+/// The number of lines that [`sniff_separator`] looks at.
+const SNIFF_LINES: usize = 20;
+
+/// Detect the separator from the first lines after `skip_rows`, ignoring comments and blank
+/// lines. A candidate fits if it splits every line in the same number of fields, and at least
+/// in two. If no candidate fits the default `,` is returned; if several fit, the choice would
+/// be a guess and an error naming them is raised.
+pub(crate) fn sniff_separator(
+    bytes: &[u8],
+    skip_rows: usize,
+    comment_prefix: Option<&CommentPrefix>,
+    quote_char: Option<u8>,
+    escape_char: Option<u8>,
+    eol_char: u8,
+) -> PolarsResult<u8> {
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    if let Some(b) = decompress(
+        bytes,
+        Some(skip_rows + SNIFF_LINES),
+        b',',
+        quote_char,
+        escape_char,
+        eol_char,
+    ) {
+        return sniff_separator(
+            &b,
+            skip_rows,
+            comment_prefix,
+            quote_char,
+            escape_char,
+            eol_char,
+        );
+    }
+
+    let bytes = skip_line_ending(skip_bom(bytes), eol_char);
+    let bytes = skip_physical_lines(bytes, skip_rows, eol_char);
+
+    let fits = |separator: u8| {
+        // whether a line is a comment depends on the separator, a leading tab may be a field
+        let lines = SplitLines::new(bytes, quote_char.unwrap_or(b'"'), escape_char, eol_char)
+            .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+            .filter(|line| {
+                !is_comment_line(line, comment_prefix, separator)
+                    && !trim_whitespace(line).is_empty()
+            })
+            .take(SNIFF_LINES)
+            .collect::<Vec<_>>();
+        let n_fields = |line: &[u8]| {
+            SplitFields::new(line, separator, quote_char, escape_char, eol_char).count()
+        };
+        let first = lines.first().map_or(0, |line| n_fields(line));
+        first > 1 && lines[1..].iter().all(|line| n_fields(line) == first)
+    };
+    let fitting = SEPARATOR_CANDIDATES
+        .into_iter()
+        .filter(|separator| fits(*separator))
+        .collect::<Vec<_>>();
+
+    match fitting.as_slice() {
+        [] => Ok(b','),
+        [separator] => Ok(*separator),
+        _ => {
+            let names = fitting
+                .iter()
+                .map(|sep| format!("'{}'", (*sep as char).escape_default()))
+                .collect::<Vec<_>>();
+            polars_bail!(
+                ComputeError: "cannot detect the CSV separator, the first lines split consistently \
+                on each of {}; set it with `with_separator`", names.join(", ")
+            )
+        },
+    }
+}
+
 // magic numbers
 const GZIP: [u8; 2] = [31, 139];
 const ZLIB0: [u8; 2] = [0x78, 0x01];
@@ -530,6 +838,7 @@ fn decompress_impl<R: Read>(
     n_rows: Option<usize>,
     separator: u8,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 ) -> Option<Vec<u8>> {
     let chunk_size = 4096;
@@ -560,7 +869,8 @@ fn decompress_impl<R: Read>(
                     }
                     // now that we have enough, we compute the number of fields (also takes embedding into account)
                     expected_fields =
-                        SplitFields::new(&out, separator, quote_char, eol_char).count();
+                        SplitFields::new(&out, separator, quote_char, escape_char, eol_char)
+                            .count();
                     break;
                 }
             }
@@ -575,6 +885,7 @@ fn decompress_impl<R: Read>(
                     Some(expected_fields),
                     separator,
                     quote_char,
+                    escape_char,
                     eol_char,
                 ) {
                     Some(pos) => {
@@ -603,17 +914,39 @@ pub(crate) fn decompress(
     n_rows: Option<usize>,
     separator: u8,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 ) -> Option<Vec<u8>> {
     if bytes.starts_with(&GZIP) {
         let mut decoder = flate2::read::MultiGzDecoder::new(bytes);
-        decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
+        decompress_impl(
+            &mut decoder,
+            n_rows,
+            separator,
+            quote_char,
+            escape_char,
+            eol_char,
+        )
     } else if bytes.starts_with(&ZLIB0) || bytes.starts_with(&ZLIB1) || bytes.starts_with(&ZLIB2) {
         let mut decoder = flate2::read::ZlibDecoder::new(bytes);
-        decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
+        decompress_impl(
+            &mut decoder,
+            n_rows,
+            separator,
+            quote_char,
+            escape_char,
+            eol_char,
+        )
     } else if bytes.starts_with(&ZSTD) {
         let mut decoder = zstd::Decoder::new(bytes).ok()?;
-        decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
+        decompress_impl(
+            &mut decoder,
+            n_rows,
+            separator,
+            quote_char,
+            escape_char,
+            eol_char,
+        )
     } else {
         None
     }
@@ -629,13 +962,25 @@ pub(crate) fn decompress(
 ///     - Output buffer must have enough capacity to hold `bytes.len()`
 ///     - bytes ends with the quote character e.g.: `"`
 ///     - bytes length > 1.
-pub(super) unsafe fn escape_field(bytes: &[u8], quote: u8, buf: &mut [MaybeUninit<u8>]) -> usize {
+/// Unescape a quoted field into `buf`: the enclosing quotes are removed, doubled quotes are
+/// collapsed and, if an `escape` byte is given, the byte following it is taken literally.
+pub(super) unsafe fn escape_field(
+    bytes: &[u8],
+    quote: u8,
+    escape: Option<u8>,
+    buf: &mut [MaybeUninit<u8>],
+) -> usize {
     debug_assert!(bytes.len() > 1);
     let mut prev_quote = false;
+    let mut prev_escape = false;
 
     let mut count = 0;
     for c in bytes.get_unchecked(1..bytes.len() - 1) {
-        if *c == quote {
+        if prev_escape {
+            prev_escape = false;
+            buf.get_unchecked_mut(count).write(*c);
+            count += 1;
+        } else if *c == quote {
             if prev_quote {
                 prev_quote = false;
                 buf.get_unchecked_mut(count).write(*c);
@@ -643,6 +988,9 @@ pub(super) unsafe fn escape_field(bytes: &[u8], quote: u8, buf: &mut [MaybeUnini
             } else {
                 prev_quote = true;
             }
+        } else if Some(*c) == escape {
+            prev_quote = false;
+            prev_escape = true;
         } else {
             prev_quote = false;
             buf.get_unchecked_mut(count).write(*c);
@@ -684,10 +1032,90 @@ mod test {
         let bytes = s.as_bytes();
         // can be within -1 / +1 bounds.
         assert!(
-            (get_file_chunks(bytes, 10, Some(4), b',', None, b'\n').len() as i32 - 10).abs() <= 1
+            (get_file_chunks(bytes, 10, Some(4), b',', None, None, b'\n').len() as i32 - 10).abs()
+                <= 1
         );
         assert!(
-            (get_file_chunks(bytes, 8, Some(4), b',', None, b'\n').len() as i32 - 8).abs() <= 1
+            (get_file_chunks(bytes, 8, Some(4), b',', None, None, b'\n').len() as i32 - 8).abs()
+                <= 1
         );
     }
+
+    #[test]
+    fn test_get_file_chunks_multi_line_quoted() {
+        let rows = "1,a,x\n".repeat(10);
+        // the embedded lines have as many fields as a record
+        let csv = format!("{rows}2,\"4,c,z\n5,d,w\n6,e,v\n7,f,u\",q\n{rows}");
+        let bytes = csv.as_bytes();
+        let (open, close) = (csv.find('"').unwrap(), csv.rfind('"').unwrap());
+
+        for n_chunks in 2..8 {
+            let chunks = get_file_chunks(bytes, n_chunks, Some(3), b',', Some(b'"'), None, b'\n');
+            assert_eq!(chunks.first().unwrap().0, 0);
+            assert_eq!(chunks.last().unwrap().1, bytes.len());
+            for w in chunks.windows(2) {
+                assert_eq!(w[0].1, w[1].0);
+                assert!(w[0].1 <= open || w[0].1 > close);
+                assert_eq!(bytes[w[0].1 - 1], b'\n');
+            }
+        }
+    }
+    #[test]
+    #[cfg(feature = "decompress")]
+    fn test_decompress_zstd() {
+        use std::io::Cursor;
+
+        use crate::prelude::*;
+
+        let path = "../../examples/datasets/foods1.csv";
+        let s = std::fs::read_to_string(path).unwrap();
+        let compressed = zstd::encode_all(s.as_bytes(), 0).unwrap();
+        assert!(is_compressed(&compressed));
+
+        let out = decompress(&compressed, None, b',', Some(b'"'), None, b'\n').unwrap();
+        assert_eq!(out, s.as_bytes());
+
+        // the decompressed bytes are parsed by the multithreaded reader
+        let df = CsvReader::new(Cursor::new(compressed.clone()))
+            .with_n_threads(Some(4))
+            .finish()
+            .unwrap();
+        assert_eq!(df.shape(), (27, 4));
+
+        let df = CsvReader::new(Cursor::new(compressed))
+            .with_n_rows(Some(5))
+            .finish()
+            .unwrap();
+        assert_eq!(df.shape(), (5, 4));
+    }
+
+    #[test]
+    #[cfg(feature = "decompress")]
+    fn test_decompress_escape_char() {
+        use std::io::Cursor;
+
+        use crate::prelude::*;
+
+        // the escaped quotes don't close the fields, the rows must be counted with the escape char
+        let csv = "id,text\n1,\"a\\\",b\"\n2,\"c\\\"\nd\"\n3,e\n";
+        let compressed = zstd::encode_all(csv.as_bytes(), 0).unwrap();
+        for n_rows in [None, Some(2)] {
+            let df = CsvReader::new(Cursor::new(compressed.clone()))
+                .with_escape_char(Some(b'\\'))
+                .with_n_rows(n_rows)
+                .finish()
+                .unwrap();
+            let expected = ["a\",b", "c\"\nd", "e"];
+            let n = n_rows.unwrap_or(3);
+            assert_eq!(
+                df.column("text")
+                    .unwrap()
+                    .str()
+                    .unwrap()
+                    .into_no_null_iter()
+                    .collect::<Vec<_>>(),
+                &expected[..n]
+            );
+        }
+    }
 }
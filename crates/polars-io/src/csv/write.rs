@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::num::NonZeroUsize;
 
 use polars_core::POOL;
@@ -22,6 +23,18 @@ pub enum QuoteStyle {
     Never,
 }
 
+/// Compression codec of the csv output.
+#[derive(Copy, Clone, Eq, Hash, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CsvCompression {
+    /// Gzip, requires the `decompress` or `decompress-fast` feature.
+    Gzip,
+}
+
+/// Maps a column name to the name that is written in the header row, see
+/// [`CsvWriter::with_header_transform`].
+pub type HeaderTransform = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
 /// Write a DataFrame to csv.
 ///
 /// Don't use a `Buffered` writer, the `CsvWriter` internally already buffers writes.
@@ -32,8 +45,17 @@ pub struct CsvWriter<W: Write> {
     options: write_impl::SerializeOptions,
     header: bool,
     bom: bool,
+    // the BOM is only written once, also if `finish` is called again on an appending stream
+    has_written_bom: bool,
     batch_size: NonZeroUsize,
     n_threads: usize,
+    compression: Option<CsvCompression>,
+    columns: Option<Vec<String>>,
+    header_transform: Option<HeaderTransform>,
+    append: bool,
+    // the file that is appended to, its header is checked in `finish`
+    append_path: Option<PathBuf>,
+    validate_header: bool,
 }
 
 impl<W> SerWriter<W> for CsvWriter<W>
@@ -52,26 +74,100 @@ where
             options,
             header: true,
             bom: false,
+            has_written_bom: false,
             batch_size: NonZeroUsize::new(1024).unwrap(),
             n_threads: POOL.current_num_threads(),
+            compression: None,
+            columns: None,
+            header_transform: None,
+            append: false,
+            append_path: None,
+            validate_header: true,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        if self.bom {
-            write_impl::write_bom(&mut self.buffer)?;
+        let projected;
+        let df = match &self.columns {
+            Some(columns) => {
+                projected = df.select(columns)?;
+                &projected
+            },
+            None => &*df,
+        };
+        let names = header_names(df.get_column_names(), self.header_transform.as_ref());
+        if self.append && self.header {
+            self.validate_append_header(&names)?;
         }
-        let names = df.get_column_names();
-        if self.header {
-            write_impl::write_header(&mut self.buffer, &names, &self.options)?;
+        let header = (self.header && !self.append).then_some(names.as_slice());
+        let batch_size = self.batch_size.into();
+        let bom = self.bom && !self.has_written_bom && !self.append;
+        self.has_written_bom |= bom;
+        match self.compression {
+            None => write_csv(
+                &mut self.buffer,
+                df,
+                &self.options,
+                header,
+                bom,
+                batch_size,
+                self.n_threads,
+            ),
+            Some(CsvCompression::Gzip) => {
+                #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+                {
+                    let mut encoder = flate2::write::GzEncoder::new(
+                        &mut self.buffer,
+                        flate2::Compression::default(),
+                    );
+                    write_csv(
+                        &mut encoder,
+                        df,
+                        &self.options,
+                        header,
+                        bom,
+                        batch_size,
+                        self.n_threads,
+                    )?;
+                    encoder.finish()?;
+                    Ok(())
+                }
+                #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
+                {
+                    polars_bail!(
+                        ComputeError: "gzip compression of csv requires the 'decompress' feature"
+                    )
+                }
+            },
         }
-        write_impl::write(
-            &mut self.buffer,
-            df,
-            self.batch_size.into(),
-            &self.options,
-            self.n_threads,
-        )
+    }
+}
+
+fn write_csv<W: Write>(
+    buffer: &mut W,
+    df: &DataFrame,
+    options: &write_impl::SerializeOptions,
+    header: Option<&[Cow<str>]>,
+    bom: bool,
+    batch_size: usize,
+    n_threads: usize,
+) -> PolarsResult<()> {
+    if bom {
+        write_impl::write_bom(buffer)?;
+    }
+    if let Some(names) = header {
+        write_impl::write_header(buffer, names, options)?;
+    }
+    write_impl::write(buffer, df, batch_size, options, n_threads)
+}
+
+fn header_names<'a>(names: Vec<&'a str>, transform: Option<&HeaderTransform>) -> Vec<Cow<'a, str>> {
+    match transform {
+        Some(transform) => names
+            .into_iter()
+            .map(|name| transform(name).into())
+            .collect(),
+        None => names.into_iter().map(Cow::Borrowed).collect(),
     }
 }
 
@@ -127,6 +223,18 @@ where
         self
     }
 
+    /// Set the format of a single date, time or datetime column. This takes precedence over the
+    /// formats set with [`CsvWriter::with_date_format`], [`CsvWriter::with_time_format`] and
+    /// [`CsvWriter::with_datetime_format`].
+    pub fn with_column_date_format(mut self, column: String, format: String) -> Self {
+        let formats = &mut self.options.column_formats;
+        match formats.iter_mut().find(|(name, _)| *name == column) {
+            Some((_, fmt)) => *fmt = format,
+            None => formats.push((column, format)),
+        }
+        self
+    }
+
     /// Set the CSV file's float precision.
     pub fn with_float_precision(mut self, precision: Option<usize>) -> Self {
         if precision.is_some() {
@@ -135,6 +243,28 @@ where
         self
     }
 
+    /// Set whether to write floats in scientific notation.
+    ///
+    /// This can be combined with [`CsvWriter::with_float_precision`] to set the number of
+    /// decimals of the mantissa.
+    pub fn with_float_scientific(mut self, float_scientific: bool) -> Self {
+        self.options.float_scientific = float_scientific;
+        self
+    }
+
+    /// Set the representation of NaN, positive and negative infinity in float columns.
+    pub fn with_float_special_values(
+        mut self,
+        nan_value: String,
+        inf_value: String,
+        neg_inf_value: String,
+    ) -> Self {
+        self.options.nan_value = nan_value;
+        self.options.inf_value = inf_value;
+        self.options.neg_inf_value = neg_inf_value;
+        self
+    }
+
     /// Set the single byte character used for quoting.
     pub fn with_quote_char(mut self, char: u8) -> Self {
         self.options.quote_char = char;
@@ -147,6 +277,13 @@ where
         self
     }
 
+    /// Set the representation of `true` and `false` in boolean columns.
+    pub fn with_bool_representation(mut self, true_value: String, false_value: String) -> Self {
+        self.options.true_value = true_value;
+        self.options.false_value = false_value;
+        self
+    }
+
     /// Set the CSV file's line terminator.
     pub fn with_line_terminator(mut self, line_terminator: String) -> Self {
         self.options.line_terminator = line_terminator;
@@ -160,23 +297,117 @@ where
         self
     }
 
+    /// Set the compression of the output, the buffer is wrapped in an encoder in
+    /// [`CsvWriter::finish`].
+    ///
+    /// Compression is not supported by the [`BatchedWriter`].
+    pub fn with_compression(mut self, compression: Option<CsvCompression>) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Only write these columns, in the given order.
+    ///
+    /// Writing errors if one of the columns does not exist.
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Write the column names through `transform` in the header row, e.g. to uppercase them.
+    ///
+    /// The names are quoted after the transform if they contain the separator or a quote. The
+    /// names of the DataFrame are not changed.
+    pub fn with_header_transform(mut self, transform: Option<HeaderTransform>) -> Self {
+        self.header_transform = transform;
+        self
+    }
+
+    /// Append to existing data: neither the header nor the BOM are written.
+    pub fn with_append(mut self, toggle: bool) -> Self {
+        self.append = toggle;
+        self
+    }
+
+    /// Check that the header of the file that is appended to matches the names of the columns
+    /// that are written, see [`CsvWriter::to_path_append`]. This is on by default and requires
+    /// reading the header of the file.
+    pub fn with_header_validation(mut self, toggle: bool) -> Self {
+        self.validate_header = toggle;
+        self
+    }
+
+    fn validate_append_header(&self, names: &[Cow<str>]) -> PolarsResult<()> {
+        let Some(path) = self.append_path.as_ref().filter(|_| self.validate_header) else {
+            return Ok(());
+        };
+        let existing = CsvReader::from_path(path)?
+            .with_separator(self.options.separator)
+            .with_quote_char(Some(self.options.quote_char))
+            .infer_schema(Some(0))
+            .schema()?;
+        let existing = existing.get_names();
+        polars_ensure!(
+            existing.len() == names.len()
+                && existing.iter().zip(names).all(|(a, b)| *a == b.as_ref()),
+            SchemaMismatch: "cannot append columns {:?} to the csv file {:?} with header {:?}",
+            names, path, existing
+        );
+        Ok(())
+    }
+
     pub fn n_threads(mut self, n_threads: usize) -> Self {
         self.n_threads = n_threads;
         self
     }
 
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
-        let expects_bom = self.bom;
-        let expects_header = self.header;
+        polars_ensure!(
+            self.compression.is_none(),
+            InvalidOperation: "compression is not supported by the batched csv writer"
+        );
+        let schema = match &self.columns {
+            Some(columns) => columns
+                .iter()
+                .map(|name| schema.try_get_field(name))
+                .collect::<PolarsResult<Schema>>()?,
+            None => schema.clone(),
+        };
+        if self.append && self.header {
+            let names = header_names(schema.get_names(), self.header_transform.as_ref());
+            self.validate_append_header(&names)?;
+        }
+        let expects_bom = self.bom && !self.append;
+        let expects_header = self.header && !self.append;
         Ok(BatchedWriter {
             writer: self,
             has_written_bom: !expects_bom,
             has_written_header: !expects_header,
-            schema: schema.clone(),
+            schema,
         })
     }
 }
 
+impl CsvWriter<File> {
+    /// Open the file at `path` to append rows to it, it is created if it doesn't exist.
+    ///
+    /// If the file isn't empty, the header isn't written again and writing checks that the
+    /// header of the file matches the columns, see [`CsvWriter::with_header_validation`].
+    pub fn to_path_append<P: AsRef<Path>>(path: P) -> PolarsResult<Self> {
+        let path = resolve_homedir(path.as_ref());
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+        let append = file.metadata()?.len() > 0;
+        let mut writer = Self::new(file).with_append(append);
+        if append {
+            writer.append_path = Some(path);
+        }
+        Ok(writer)
+    }
+}
+
 pub struct BatchedWriter<W: Write> {
     writer: CsvWriter<W>,
     has_written_bom: bool,
@@ -187,9 +418,30 @@ pub struct BatchedWriter<W: Write> {
 impl<W: Write> BatchedWriter<W> {
     /// Write a batch to the csv writer.
     ///
+    /// Errors if the schema of the batch differs from the schema the writer was created with.
+    ///
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let projected;
+        let df = match &self.writer.columns {
+            Some(columns) => {
+                projected = df.select(columns)?;
+                &projected
+            },
+            None => df,
+        };
+        let same_schema = self.schema.len() == df.width()
+            && self
+                .schema
+                .iter()
+                .zip(df.get_columns())
+                .all(|((name, dtype), s)| name == s.name() && dtype == s.dtype());
+        polars_ensure!(
+            same_schema,
+            SchemaMismatch: "batch schema {:?} differs from the csv writer schema {:?}",
+            df.schema(), self.schema
+        );
         if !self.has_written_bom {
             self.has_written_bom = true;
             write_impl::write_bom(&mut self.writer.buffer)?;
@@ -197,7 +449,7 @@ impl<W: Write> BatchedWriter<W> {
 
         if !self.has_written_header {
             self.has_written_header = true;
-            let names = df.get_column_names();
+            let names = header_names(df.get_column_names(), self.writer.header_transform.as_ref());
             write_impl::write_header(&mut self.writer.buffer, &names, &self.writer.options)?;
         }
 
@@ -220,7 +472,10 @@ impl<W: Write> BatchedWriter<W> {
 
         if !self.has_written_header {
             self.has_written_header = true;
-            let names = self.schema.get_names();
+            let names = header_names(
+                self.schema.get_names(),
+                self.writer.header_transform.as_ref(),
+            );
             write_impl::write_header(&mut self.writer.buffer, &names, &self.writer.options)?;
         };
 
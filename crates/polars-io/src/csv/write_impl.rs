@@ -9,7 +9,7 @@ use arrow::legacy::time_zone::Tz;
 use arrow::temporal_conversions;
 #[cfg(feature = "timezones")]
 use chrono::TimeZone;
-use memchr::{memchr, memchr2};
+use memchr::{memchr, memchr3};
 use polars_core::prelude::*;
 use polars_core::series::SeriesIter;
 use polars_core::POOL;
@@ -41,7 +41,7 @@ fn fmt_and_escape_str(f: &mut Vec<u8>, v: &str, options: &SerializeOptions) -> s
     }
     let surround_with_quotes = match options.quote_style {
         QuoteStyle::Always | QuoteStyle::NonNumeric => true,
-        QuoteStyle::Necessary => memchr2(options.separator, b'\n', v.as_bytes()).is_some(),
+        QuoteStyle::Necessary => memchr3(options.separator, b'\n', b'\r', v.as_bytes()).is_some(),
         QuoteStyle::Never => false,
     };
 
@@ -58,6 +58,31 @@ fn fast_float_write<I: ryu::Float>(f: &mut Vec<u8>, val: I) {
     f.extend_from_slice(value.as_bytes())
 }
 
+fn write_float<I>(f: &mut Vec<u8>, val: I, options: &SerializeOptions) -> std::io::Result<()>
+where
+    I: ryu::Float + num_traits::Float + std::fmt::Display + std::fmt::LowerExp,
+{
+    if val.is_nan() {
+        return write!(f, "{}", &options.nan_value);
+    } else if val.is_infinite() {
+        let v = if val.is_sign_positive() {
+            &options.inf_value
+        } else {
+            &options.neg_inf_value
+        };
+        return write!(f, "{v}");
+    }
+    match (options.float_precision, options.float_scientific) {
+        (None, false) => {
+            fast_float_write(f, val);
+            Ok(())
+        },
+        (Some(precision), false) => write!(f, "{val:.precision$}"),
+        (None, true) => write!(f, "{val:e}"),
+        (Some(precision), true) => write!(f, "{val:.precision$e}"),
+    }
+}
+
 fn write_integer<I: itoa::Integer>(f: &mut Vec<u8>, val: I) {
     let mut buffer = itoa::Buffer::new();
     let value = buffer.format(val);
@@ -69,6 +94,7 @@ unsafe fn write_any_value(
     f: &mut Vec<u8>,
     value: AnyValue,
     options: &SerializeOptions,
+    column_formats: &[Option<&str>],
     datetime_formats: &[&str],
     time_zones: &[Option<Tz>],
     i: usize,
@@ -129,20 +155,8 @@ unsafe fn write_any_value(
                     write_integer(f, v);
                     Ok(())
                 },
-                AnyValue::Float32(v) => match &options.float_precision {
-                    None => {
-                        fast_float_write(f, v);
-                        Ok(())
-                    },
-                    Some(precision) => write!(f, "{v:.precision$}"),
-                },
-                AnyValue::Float64(v) => match &options.float_precision {
-                    None => {
-                        fast_float_write(f, v);
-                        Ok(())
-                    },
-                    Some(precision) => write!(f, "{v:.precision$}"),
-                },
+                AnyValue::Float32(v) => write_float(f, v, options),
+                AnyValue::Float64(v) => write_float(f, v, options),
                 _ => {
                     // And here we deal with the non-numeric types (excluding strings)
                     if !end_with_quote && matches!(options.quote_style, QuoteStyle::NonNumeric) {
@@ -152,11 +166,19 @@ unsafe fn write_any_value(
                     }
 
                     match value {
-                        AnyValue::Boolean(v) => write!(f, "{v}"),
+                        AnyValue::Boolean(v) => {
+                            let v = if v {
+                                &options.true_value
+                            } else {
+                                &options.false_value
+                            };
+                            write!(f, "{v}")
+                        },
                         #[cfg(feature = "dtype-date")]
                         AnyValue::Date(v) => {
                             let date = temporal_conversions::date32_to_date(v);
-                            match &options.date_format {
+                            let format = { *column_formats.get_unchecked(i) };
+                            match format.or(options.date_format.as_deref()) {
                                 None => write!(f, "{date}"),
                                 Some(fmt) => write!(f, "{}", date.format(fmt)),
                             }
@@ -204,7 +226,8 @@ unsafe fn write_any_value(
                         #[cfg(feature = "dtype-time")]
                         AnyValue::Time(v) => {
                             let date = temporal_conversions::time64ns_to_time(v);
-                            match &options.time_format {
+                            let format = { *column_formats.get_unchecked(i) };
+                            match format.or(options.time_format.as_deref()) {
                                 None => write!(f, "{date}"),
                                 Some(fmt) => write!(f, "{}", date.format(fmt)),
                             }
@@ -237,14 +260,29 @@ pub struct SerializeOptions {
     pub time_format: Option<String>,
     /// Used for [`DataType::Datetime`].
     pub datetime_format: Option<String>,
+    /// Formats of single temporal columns as `(column, format)`, these take precedence over
+    /// `date_format`, `time_format` and `datetime_format`.
+    pub column_formats: Vec<(String, String)>,
     /// Used for [`DataType::Float64`] and [`DataType::Float32`].
     pub float_precision: Option<usize>,
+    /// Write floats in scientific notation, e.g. `1.5e3`.
+    pub float_scientific: bool,
     /// Used as separator.
     pub separator: u8,
     /// Quoting character.
     pub quote_char: u8,
     /// Null value representation.
     pub null: String,
+    /// Representation of `true` in [`DataType::Boolean`] columns.
+    pub true_value: String,
+    /// Representation of `false` in [`DataType::Boolean`] columns.
+    pub false_value: String,
+    /// Representation of NaN in float columns.
+    pub nan_value: String,
+    /// Representation of positive infinity in float columns.
+    pub inf_value: String,
+    /// Representation of negative infinity in float columns.
+    pub neg_inf_value: String,
     /// String appended after every row.
     pub line_terminator: String,
     pub quote_style: QuoteStyle,
@@ -256,10 +294,17 @@ impl Default for SerializeOptions {
             date_format: None,
             time_format: None,
             datetime_format: None,
+            column_formats: vec![],
             float_precision: None,
+            float_scientific: false,
             separator: b',',
             quote_char: b'"',
             null: String::new(),
+            true_value: "true".into(),
+            false_value: "false".into(),
+            nan_value: "NaN".into(),
+            inf_value: "inf".into(),
+            neg_inf_value: "-inf".into(),
             line_terminator: "\n".into(),
             quote_style: Default::default(),
         }
@@ -309,24 +354,35 @@ pub(crate) fn write<W: Write>(
     );
     let separator = char::from(options.separator);
 
+    let column_formats = df
+        .get_columns()
+        .iter()
+        .map(|column| {
+            options
+                .column_formats
+                .iter()
+                .find(|(name, _)| name == column.name())
+                .map(|(_, format)| format.as_str())
+        })
+        .collect::<Vec<_>>();
+
     let (datetime_formats, time_zones): (Vec<&str>, Vec<Option<Tz>>) = df
         .get_columns()
         .iter()
-        .map(|column| match column.dtype() {
+        .zip(&column_formats)
+        .map(|(column, column_format)| match column.dtype() {
             DataType::Datetime(TimeUnit::Milliseconds, tz) => {
                 let (format, tz_parsed) = match tz {
                     #[cfg(feature = "timezones")]
                     Some(tz) => (
-                        options
-                            .datetime_format
-                            .as_deref()
+                        column_format
+                            .or(options.datetime_format.as_deref())
                             .unwrap_or("%FT%H:%M:%S.%3f%z"),
                         tz.parse::<Tz>().ok(),
                     ),
                     _ => (
-                        options
-                            .datetime_format
-                            .as_deref()
+                        column_format
+                            .or(options.datetime_format.as_deref())
                             .unwrap_or("%FT%H:%M:%S.%3f"),
                         None,
                     ),
@@ -337,16 +393,14 @@ pub(crate) fn write<W: Write>(
                 let (format, tz_parsed) = match tz {
                     #[cfg(feature = "timezones")]
                     Some(tz) => (
-                        options
-                            .datetime_format
-                            .as_deref()
+                        column_format
+                            .or(options.datetime_format.as_deref())
                             .unwrap_or("%FT%H:%M:%S.%6f%z"),
                         tz.parse::<Tz>().ok(),
                     ),
                     _ => (
-                        options
-                            .datetime_format
-                            .as_deref()
+                        column_format
+                            .or(options.datetime_format.as_deref())
                             .unwrap_or("%FT%H:%M:%S.%6f"),
                         None,
                     ),
@@ -357,16 +411,14 @@ pub(crate) fn write<W: Write>(
                 let (format, tz_parsed) = match tz {
                     #[cfg(feature = "timezones")]
                     Some(tz) => (
-                        options
-                            .datetime_format
-                            .as_deref()
+                        column_format
+                            .or(options.datetime_format.as_deref())
                             .unwrap_or("%FT%H:%M:%S.%9f%z"),
                         tz.parse::<Tz>().ok(),
                     ),
                     _ => (
-                        options
-                            .datetime_format
-                            .as_deref()
+                        column_format
+                            .or(options.datetime_format.as_deref())
                             .unwrap_or("%FT%H:%M:%S.%9f"),
                         None,
                     ),
@@ -429,6 +481,7 @@ pub(crate) fn write<W: Write>(
                                 &mut write_buffer,
                                 value,
                                 options,
+                                &column_formats,
                                 &datetime_formats,
                                 &time_zones,
                                 i,
@@ -477,16 +530,16 @@ pub(crate) fn write<W: Write>(
 }
 
 /// Writes a CSV header to `writer`.
-pub(crate) fn write_header<W: Write>(
+pub(crate) fn write_header<W: Write, S: AsRef<str>>(
     writer: &mut W,
-    names: &[&str],
+    names: &[S],
     options: &SerializeOptions,
 ) -> PolarsResult<()> {
     let mut escaped_names: Vec<String> = Vec::with_capacity(names.len());
     let mut nm: Vec<u8> = vec![];
 
     for name in names {
-        fmt_and_escape_str(&mut nm, name, options)?;
+        fmt_and_escape_str(&mut nm, name.as_ref(), options)?;
         unsafe {
             // SAFETY: we know headers will be valid UTF-8 at this point
             escaped_names.push(std::str::from_utf8_unchecked(&nm).to_string());
@@ -38,6 +38,7 @@ impl CsvExec {
             .with_encoding(CsvEncoding::LossyUtf8)
             ._with_comment_prefix(std::mem::take(&mut self.options.comment_prefix))
             .with_quote_char(self.options.quote_char)
+            .with_escape_char(self.options.escape_char)
             .with_end_of_line_char(self.options.eol_char)
             .with_encoding(self.options.encoding)
             .with_rechunk(self.file_options.rechunk)
@@ -24,6 +24,7 @@ pub struct LazyCsvReader<'a> {
     low_memory: bool,
     comment_prefix: Option<CommentPrefix>,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
     null_values: Option<NullValues>,
     missing_is_null: bool,
@@ -59,6 +60,7 @@ impl<'a> LazyCsvReader<'a> {
             low_memory: false,
             comment_prefix: None,
             quote_char: Some(b'"'),
+            escape_char: None,
             eol_char: b'\n',
             null_values: None,
             missing_is_null: true,
@@ -168,6 +170,14 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
+    /// Set the `char` used to escape a quote char, separator or newline inside a quoted field.
+    /// The default is `[None]`, in which case only doubled quotes are recognized as escapes.
+    #[must_use]
+    pub fn with_escape_char(mut self, escape_char: Option<u8>) -> Self {
+        self.escape_char = escape_char;
+        self
+    }
+
     /// Set the `char` used as end of line. The default is `b'\n'`.
     #[must_use]
     pub fn with_end_of_line_char(mut self, eol_char: u8) -> Self {
@@ -255,13 +265,21 @@ impl<'a> LazyCsvReader<'a> {
             self.separator,
             self.infer_schema_length,
             self.has_header,
+            true,
             // we set it to None and modify them after the schema is updated
             None,
             &mut skip_rows,
             self.skip_rows_after_header,
             self.comment_prefix.as_ref(),
             self.quote_char,
+            self.escape_char,
             self.eol_char,
+            self.encoding,
+            None,
+            false,
+            false,
+            None,
+            None,
             None,
             self.try_parse_dates,
             self.raise_if_empty,
@@ -295,6 +313,7 @@ impl LazyFileListReader for LazyCsvReader<'_> {
             self.low_memory,
             self.comment_prefix,
             self.quote_char,
+            self.escape_char,
             self.eol_char,
             self.null_values,
             self.infer_schema_length,
@@ -647,6 +647,27 @@ fn scan_predicate_on_set_null_values() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn scan_csv_escape_char() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_scan_csv_escape_char.csv");
+    std::fs::write(&path, "id,text\n1,\"a\\\",b\"\n2,\"c\\\"\nd\"\n3,e\n").unwrap();
+
+    let df = LazyCsvReader::new(&path)
+        .with_escape_char(Some(b'\\'))
+        .finish()?
+        .collect()?;
+    assert_eq!(df.shape(), (3, 2));
+    assert_eq!(
+        df.column("text")?
+            .str()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &["a\",b", "c\"\nd", "e"]
+    );
+    std::fs::remove_file(&path).unwrap();
+    Ok(())
+}
+
 #[test]
 fn scan_anonymous_fn() -> PolarsResult<()> {
     let function = Arc::new(|_scan_opts: AnonymousScanArgs| Ok(fruits_cars()));
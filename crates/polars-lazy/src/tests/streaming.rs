@@ -59,6 +59,24 @@ fn test_streaming_csv() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_streaming_csv_group_by_sink() -> PolarsResult<()> {
+    // read, aggregate and write without collecting the result
+    let q = get_csv_file()
+        .group_by([col("category")])
+        .agg([col("calories").sum(), col("fats_g").sum()]);
+    let expected = q.clone().collect()?.sort(["category"], false, false)?;
+
+    let path = std::env::temp_dir().join("polars_streaming_group_by_sink.csv");
+    q.sink_csv(path.clone(), Default::default())?;
+    let out = CsvReader::from_path(&path)?.finish()?;
+    std::fs::remove_file(&path)?;
+    // the header is written once
+    assert_eq!(out.get_column_names(), &["category", "calories", "fats_g"]);
+    assert_eq!(out.sort(["category"], false, false)?, expected);
+    Ok(())
+}
+
 #[test]
 fn test_streaming_glob() -> PolarsResult<()> {
     let q = get_csv_glob();
@@ -128,6 +146,138 @@ fn test_streaming_first_sum() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_streaming_string_key_sum_count() -> PolarsResult<()> {
+    let q = df![
+        "fruit" => ["apple", "banana", "apple", "cherry", "banana", "apple"],
+        "amount" => [Some(1i64), Some(2), Some(3), Some(4), Some(5), None],
+    ]?
+    .lazy()
+    .group_by([col("fruit")])
+    .agg([
+        col("amount").sum(),
+        col("amount").count().alias("amount_count"),
+    ])
+    .sort("fruit", Default::default());
+
+    assert_streaming_with_default(q.clone(), true, false);
+
+    let out = q.with_streaming(true).collect()?;
+    let expected = df![
+        "fruit" => ["apple", "banana", "cherry"],
+        "amount" => [4i64, 7, 4],
+        "amount_count" => [2 as IdxSize, 2, 1],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_streaming_sum_i32_overflow() -> PolarsResult<()> {
+    let q = df![
+        "key" => [1i32, 1, 2],
+        "value" => [i32::MAX, 1, 3],
+    ]?
+    .lazy()
+    .group_by([col("key")])
+    .agg([col("value").sum()])
+    .sort("key", Default::default());
+
+    assert!(optimization_checks::is_pipeline(
+        q.clone().with_streaming(true)
+    ));
+
+    // the sum doesn't fit in 32 bits, it must not become null
+    let out = q.with_streaming(true).collect()?;
+    let expected = df![
+        "key" => [1i32, 2],
+        "value" => [i32::MAX as i64 + 1, 3],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_streaming_arg_min_max() -> PolarsResult<()> {
+    let q = get_csv_file()
+        .group_by([col("category")])
+        .agg([
+            col("calories").arg_min().alias("calories_arg_min"),
+            col("calories").arg_max().alias("calories_arg_max"),
+        ])
+        .sort("category", Default::default());
+
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "approx_unique")]
+fn test_streaming_approx_n_unique() -> PolarsResult<()> {
+    // the counts are small, so the estimates are exact
+    let q = get_csv_file()
+        .group_by([col("category")])
+        .agg([
+            col("calories").approx_n_unique(),
+            col("sugars_g").approx_n_unique(),
+        ])
+        .sort("category", Default::default());
+
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "moment")]
+fn test_streaming_skew_kurtosis() -> PolarsResult<()> {
+    let q = get_csv_file()
+        .group_by([col("category")])
+        .agg([
+            col("calories").skew(true).alias("skew"),
+            col("calories").skew(false).alias("skew_unbiased"),
+            col("fats_g").kurtosis(true, true).alias("kurtosis"),
+            col("fats_g")
+                .kurtosis(false, false)
+                .alias("kurtosis_unbiased"),
+        ])
+        .sort("category", Default::default());
+    assert!(optimization_checks::is_pipeline(
+        q.clone().with_streaming(true)
+    ));
+
+    let out = q.clone().with_streaming(true).collect()?;
+    let expected = q.with_streaming(false).collect()?;
+    assert_eq!(out.get_column_names(), expected.get_column_names());
+    // the moments are accumulated in a different order than in memory
+    for name in ["skew", "skew_unbiased", "kurtosis", "kurtosis_unbiased"] {
+        let out = out.column(name)?.f64()?;
+        let expected = expected.column(name)?.f64()?;
+        for (a, b) in out.into_iter().zip(expected) {
+            let (a, b) = (a.unwrap(), b.unwrap());
+            assert!(
+                (a - b).abs() <= 1e-9 * b.abs().max(1.0),
+                "{name}: {a} != {b}"
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_streaming_bitwise() -> PolarsResult<()> {
+    let q = get_csv_file()
+        .group_by([col("category")])
+        .agg([
+            col("calories").bitwise_and().alias("calories_and"),
+            col("calories").bitwise_or().alias("calories_or"),
+            col("sugars_g").bitwise_or().alias("sugars_or"),
+        ])
+        .sort("category", Default::default());
+
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
 #[test]
 fn test_streaming_unique() -> PolarsResult<()> {
     let q = get_csv_file();
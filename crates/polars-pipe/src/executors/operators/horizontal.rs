@@ -0,0 +1,126 @@
+use polars_core::prelude::*;
+use polars_ops::prelude::{max_horizontal, mean_horizontal, min_horizontal, sum_horizontal};
+
+// Row-wise counterparts of the vertical aggregations: they reduce the columns of a chunk to a
+// single column, e.g. to go from a wide to a narrow table. Nulls are skipped, like the vertical
+// aggregations do: a row without any value sums to zero and has a null mean, min and max.
+// The output has the name of the first column.
+
+fn check_columns(name: &str, columns: &[Series]) -> PolarsResult<()> {
+    polars_ensure!(!columns.is_empty(), NoData: "`{}` needs at least one column", name);
+    let len = columns[0].len();
+    for s in columns {
+        polars_ensure!(
+            s.len() == len,
+            ShapeMismatch: "`{}` needs columns of equal length, got {} and {}", name, len, s.len()
+        );
+        polars_ensure!(
+            s.dtype().is_numeric(),
+            SchemaMismatch: "expected numeric columns for `{}`, got {}", name, s.dtype()
+        );
+    }
+    Ok(())
+}
+
+fn row_aggregate(
+    name: &str,
+    columns: &[Series],
+    agg: fn(&[Series]) -> PolarsResult<Option<Series>>,
+) -> PolarsResult<Series> {
+    check_columns(name, columns)?;
+    // the columns are checked to be non-empty
+    Ok(agg(columns)?.unwrap())
+}
+
+/// The sum of every row, in the supertype of the columns.
+// not used by the planner yet
+#[allow(dead_code)]
+pub(crate) fn row_sum(columns: &[Series]) -> PolarsResult<Series> {
+    row_aggregate("row_sum", columns, sum_horizontal)
+}
+
+/// The mean of the non-null values of every row, as `Float64`.
+#[allow(dead_code)]
+pub(crate) fn row_mean(columns: &[Series]) -> PolarsResult<Series> {
+    let out = row_aggregate("row_mean", columns, mean_horizontal)?;
+    // a single column is returned as is
+    match out.dtype() {
+        DataType::Float32 | DataType::Float64 => Ok(out),
+        _ => out.cast(&DataType::Float64),
+    }
+}
+
+/// The minimum of the non-null values of every row.
+#[allow(dead_code)]
+pub(crate) fn row_min(columns: &[Series]) -> PolarsResult<Series> {
+    row_aggregate("row_min", columns, min_horizontal)
+}
+
+/// The maximum of the non-null values of every row.
+#[allow(dead_code)]
+pub(crate) fn row_max(columns: &[Series]) -> PolarsResult<Series> {
+    row_aggregate("row_max", columns, max_horizontal)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn columns() -> Vec<Series> {
+        vec![
+            Series::new("a", &[Some(1i64), None, Some(3), None]),
+            Series::new("b", &[Some(4i64), Some(5), None, None]),
+            Series::new("c", &[None, Some(-6i64), Some(9), None]),
+        ]
+    }
+
+    #[test]
+    fn test_row_aggregations_with_nulls() {
+        let columns = columns();
+
+        let sum = row_sum(&columns).unwrap();
+        assert_eq!(sum.name(), "a");
+        assert_eq!(
+            Vec::from(sum.i64().unwrap()),
+            &[Some(5), Some(-1), Some(12), Some(0)]
+        );
+
+        let mean = row_mean(&columns).unwrap();
+        assert_eq!(
+            Vec::from(mean.f64().unwrap()),
+            &[Some(2.5), Some(-0.5), Some(6.0), None]
+        );
+
+        let min = row_min(&columns).unwrap();
+        assert_eq!(
+            Vec::from(min.i64().unwrap()),
+            &[Some(1), Some(-6), Some(3), None]
+        );
+
+        let max = row_max(&columns).unwrap();
+        assert_eq!(
+            Vec::from(max.i64().unwrap()),
+            &[Some(4), Some(5), Some(9), None]
+        );
+
+        // a single column
+        let mean = row_mean(&columns[..1]).unwrap();
+        assert_eq!(
+            Vec::from(mean.f64().unwrap()),
+            &[Some(1.0), None, Some(3.0), None]
+        );
+    }
+
+    #[test]
+    fn test_row_aggregations_invalid() {
+        assert!(row_sum(&[]).is_err());
+
+        let mut columns = columns();
+        columns.push(Series::new("d", &[1i64, 2]));
+        assert!(row_min(&columns).is_err());
+
+        columns.pop();
+        columns.push(Series::new("d", &["w", "x", "y", "z"]));
+        assert!(row_mean(&columns).is_err());
+    }
+}
@@ -1,5 +1,6 @@
 mod filter;
 mod function;
+mod horizontal;
 mod pass;
 mod placeholder;
 mod projection;
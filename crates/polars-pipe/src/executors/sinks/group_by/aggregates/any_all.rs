@@ -0,0 +1,265 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+
+/// Whether any value of a boolean group is `true`.
+///
+/// With `ignore_nulls` nulls are skipped, so a group without a `true` is `false`. Otherwise
+/// Kleene logic is used: a group without a `true` that contains a null is null, as it is
+/// unknown whether the null would have been `true`.
+pub(crate) struct AnyAgg {
+    any: bool,
+    has_null: bool,
+    ignore_nulls: bool,
+}
+
+impl AnyAgg {
+    pub(crate) fn new(ignore_nulls: bool) -> Self {
+        AnyAgg {
+            any: false,
+            has_null: false,
+            ignore_nulls,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.ignore_nulls)
+    }
+}
+
+impl AggregateFn for AnyAgg {
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
+        match unsafe { item.next().unwrap_unchecked_release() } {
+            AnyValue::Boolean(val) => self.any |= val,
+            _ => self.has_null = true,
+        }
+        Ok(())
+    }
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) -> PolarsResult<()> {
+        let ca = values.bool()?;
+        // the result is already known
+        if self.any {
+            return Ok(());
+        }
+        let ca = ca.slice(offset as i64, length as usize);
+        self.any = ca.any();
+        self.has_null |= ca.null_count() > 0;
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "any"
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::Boolean
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        self.any |= other.any;
+        self.has_null |= other.has_null;
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        if self.any || self.ignore_nulls || !self.has_null {
+            AnyValue::Boolean(self.any)
+        } else {
+            AnyValue::Null
+        }
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Whether all values of a boolean group are `true`.
+///
+/// With `ignore_nulls` nulls are skipped, so a group without a `false` is `true`. Otherwise
+/// Kleene logic is used: a group without a `false` that contains a null is null.
+pub(crate) struct AllAgg {
+    all: bool,
+    has_null: bool,
+    ignore_nulls: bool,
+}
+
+impl AllAgg {
+    pub(crate) fn new(ignore_nulls: bool) -> Self {
+        AllAgg {
+            all: true,
+            has_null: false,
+            ignore_nulls,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.ignore_nulls)
+    }
+}
+
+impl AggregateFn for AllAgg {
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
+        match unsafe { item.next().unwrap_unchecked_release() } {
+            AnyValue::Boolean(val) => self.all &= val,
+            _ => self.has_null = true,
+        }
+        Ok(())
+    }
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) -> PolarsResult<()> {
+        let ca = values.bool()?;
+        // the result is already known
+        if !self.all {
+            return Ok(());
+        }
+        let ca = ca.slice(offset as i64, length as usize);
+        self.all = ca.all();
+        self.has_null |= ca.null_count() > 0;
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "all"
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::Boolean
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        self.all &= other.all;
+        self.has_null |= other.has_null;
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        if !self.all || self.ignore_nulls || !self.has_null {
+            AnyValue::Boolean(self.all)
+        } else {
+            AnyValue::Null
+        }
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn any(values: &[Option<bool>], ignore_nulls: bool) -> AnyValue<'static> {
+        let s = Series::new("a", values);
+        let mid = values.len() as IdxSize / 2;
+        let mut agg = AnyAgg::new(ignore_nulls);
+        agg.pre_agg_ordered(0, 0, mid, &s).unwrap();
+        let mut other = agg.split();
+        other
+            .pre_agg_ordered(1, mid, values.len() as IdxSize - mid, &s)
+            .unwrap();
+        agg.combine(other.as_any()).unwrap();
+        agg.finalize()
+    }
+
+    fn all(values: &[Option<bool>], ignore_nulls: bool) -> AnyValue<'static> {
+        let s = Series::new("a", values);
+        let mid = values.len() as IdxSize / 2;
+        let mut agg = AllAgg::new(ignore_nulls);
+        agg.pre_agg_ordered(0, 0, mid, &s).unwrap();
+        let mut other = agg.split();
+        other
+            .pre_agg_ordered(1, mid, values.len() as IdxSize - mid, &s)
+            .unwrap();
+        agg.combine(other.as_any()).unwrap();
+        agg.finalize()
+    }
+
+    #[test]
+    fn test_any() {
+        for ignore_nulls in [true, false] {
+            assert_eq!(
+                any(&[Some(true), Some(true)], ignore_nulls),
+                AnyValue::Boolean(true)
+            );
+            assert_eq!(
+                any(&[Some(false), Some(false)], ignore_nulls),
+                AnyValue::Boolean(false)
+            );
+            assert_eq!(
+                any(&[Some(false), Some(true)], ignore_nulls),
+                AnyValue::Boolean(true)
+            );
+            assert_eq!(
+                any(&[None, Some(true)], ignore_nulls),
+                AnyValue::Boolean(true)
+            );
+        }
+        assert_eq!(any(&[None, Some(false)], true), AnyValue::Boolean(false));
+        assert_eq!(any(&[None, Some(false)], false), AnyValue::Null);
+        assert_eq!(any(&[None, None], true), AnyValue::Boolean(false));
+        assert_eq!(any(&[None, None], false), AnyValue::Null);
+    }
+
+    #[test]
+    fn test_all() {
+        for ignore_nulls in [true, false] {
+            assert_eq!(
+                all(&[Some(true), Some(true)], ignore_nulls),
+                AnyValue::Boolean(true)
+            );
+            assert_eq!(
+                all(&[Some(false), Some(false)], ignore_nulls),
+                AnyValue::Boolean(false)
+            );
+            assert_eq!(
+                all(&[Some(false), Some(true)], ignore_nulls),
+                AnyValue::Boolean(false)
+            );
+            assert_eq!(
+                all(&[None, Some(false)], ignore_nulls),
+                AnyValue::Boolean(false)
+            );
+        }
+        assert_eq!(all(&[None, Some(true)], true), AnyValue::Boolean(true));
+        assert_eq!(all(&[None, Some(true)], false), AnyValue::Null);
+        assert_eq!(all(&[None, None], true), AnyValue::Boolean(true));
+        assert_eq!(all(&[None, None], false), AnyValue::Null);
+    }
+
+    #[test]
+    fn test_any_all_dtype_mismatch() {
+        let s = Series::new("a", &[1i32, 2]);
+        assert!(AnyAgg::new(true).pre_agg_ordered(0, 0, 2, &s).is_err());
+        assert!(AllAgg::new(true).pre_agg_ordered(0, 0, 2, &s).is_err());
+    }
+}
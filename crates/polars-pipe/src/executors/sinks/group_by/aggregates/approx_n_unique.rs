@@ -0,0 +1,213 @@
+use std::any::Any;
+
+use polars_core::export::ahash::RandomState;
+use polars_core::prelude::*;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+use crate::executors::sinks::group_by::seeded_random_state;
+
+// The values are hashed the same in every process, so that serialized registers can be merged.
+const SEED: u64 = 0x9e37_79b9_7f4a_7c15;
+/// The precision of the `approx_n_unique` expression.
+pub(crate) const DEFAULT_PRECISION: u8 = 14;
+
+/// Estimates the number of distinct values of a group with a HyperLogLog.
+///
+/// Unlike [`NUniqueAgg`](super::n_unique::NUniqueAgg) the values aren't kept: every value is
+/// hashed into one of `2^precision` registers, which keep the longest run of leading zeros of
+/// the hashes they saw. The memory per group is `2^precision` bytes and the relative standard
+/// error of the estimate is about `1.04 / sqrt(2^precision)` (1.6% with a precision of 12).
+/// Small counts are estimated with linear counting and are close to exact. Nulls are skipped.
+pub(crate) struct ApproxNUniqueAgg {
+    registers: Vec<u8>,
+    precision: u8,
+    hasher: RandomState,
+}
+
+impl ApproxNUniqueAgg {
+    pub(crate) fn new(precision: u8) -> PolarsResult<Self> {
+        polars_ensure!(
+            (4..=16).contains(&precision),
+            ComputeError: "HyperLogLog precision should be between 4 and 16, got {}", precision
+        );
+        Ok(Self::new_unchecked(precision))
+    }
+
+    fn new_unchecked(precision: u8) -> Self {
+        ApproxNUniqueAgg {
+            registers: vec![0; 1 << precision],
+            precision,
+            hasher: seeded_random_state(SEED),
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new_unchecked(self.precision)
+    }
+
+    fn insert(&mut self, item: AnyValue) {
+        if matches!(item, AnyValue::Null) {
+            return;
+        }
+        let hash = self.hasher.hash_one(&item);
+        // the first bits pick the register, the rank of the others is kept
+        let idx = (hash >> (64 - self.precision)) as usize;
+        let rank = ((hash << self.precision).leading_zeros() + 1).min(65 - self.precision as u32);
+        let register = unsafe { self.registers.get_unchecked_mut(idx) };
+        *register = (*register).max(rank as u8);
+    }
+
+    fn estimate(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let sum = self
+            .registers
+            .iter()
+            .map(|r| 2f64.powi(-(*r as i32)))
+            .sum::<f64>();
+        let raw = alpha * m * m / sum;
+        let zeros = self.registers.iter().filter(|r| **r == 0).count();
+        // the hashes have 64 bits, so there is no correction for large counts
+        let estimate = if raw <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            raw
+        };
+        estimate.round() as u64
+    }
+}
+
+impl AggregateFn for ApproxNUniqueAgg {
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.insert(item);
+        Ok(())
+    }
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) -> PolarsResult<()> {
+        for i in offset..offset + length {
+            self.insert(unsafe { values.get_unchecked(i as usize) })
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "approx_n_unique"
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::UInt64
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        polars_ensure!(
+            self.precision == other.precision,
+            ComputeError: "cannot combine HyperLogLogs of precision {} and {}",
+            self.precision, other.precision
+        );
+        for (r, other) in self.registers.iter_mut().zip(&other.registers) {
+            *r = (*r).max(*other)
+        }
+        Ok(())
+    }
+
+    // Layout: the precision, followed by a byte per register.
+    fn serialize(&self) -> PolarsResult<Vec<u8>> {
+        let mut out = Vec::with_capacity(1 + self.registers.len());
+        out.push(self.precision);
+        out.extend_from_slice(&self.registers);
+        Ok(out)
+    }
+
+    fn deserialize(&mut self, bytes: &[u8]) -> PolarsResult<()> {
+        polars_ensure!(
+            bytes.len() == 1 + self.registers.len() && bytes[0] == self.precision,
+            ComputeError: "invalid serialized state for a `{}`", self.name()
+        );
+        self.registers.copy_from_slice(&bytes[1..]);
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        let out = self.estimate();
+        self.registers.fill(0);
+        AnyValue::UInt64(out)
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_approx_n_unique_against_exact() {
+        // every value occurs twice, in different parts
+        let n_distinct = 100_000i64;
+        let values = (0..2 * n_distinct)
+            .map(|i| ((i * 7_919) % n_distinct) * 31)
+            .collect::<Vec<_>>();
+        let s = Series::new("a", &values);
+        let precision = 12;
+
+        let mut agg = ApproxNUniqueAgg::new(precision).unwrap();
+        let mut parts = (0..4).map(|_| agg.split()).collect::<Vec<_>>();
+        let chunk = values.len() as IdxSize / 4;
+        for (i, part) in parts.iter_mut().enumerate() {
+            part.pre_agg_ordered(i as IdxSize, i as IdxSize * chunk, chunk, &s)
+                .unwrap();
+        }
+        // one part is shipped to another process
+        let mut remote = agg.split();
+        remote.deserialize(&parts[3].serialize().unwrap()).unwrap();
+        parts[3] = remote;
+        for part in &parts {
+            agg.combine(part.as_any()).unwrap();
+        }
+
+        let estimate = agg.finalize().extract::<f64>().unwrap();
+        let error = (estimate - n_distinct as f64).abs() / n_distinct as f64;
+        // three standard errors
+        assert!(error < 3.0 * 1.04 / 64.0, "estimate {estimate}");
+    }
+
+    #[test]
+    fn test_approx_n_unique_small_and_invalid() {
+        let s = Series::new(
+            "a",
+            &[Some("a"), None, Some("b"), Some("a"), Some("c"), None],
+        );
+        let mut agg = ApproxNUniqueAgg::new(12).unwrap();
+        agg.pre_agg_ordered(0, 0, 6, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::UInt64(3));
+        // finalizing resets the registers
+        assert_eq!(agg.finalize(), AnyValue::UInt64(0));
+
+        assert!(ApproxNUniqueAgg::new(3).is_err());
+        assert!(ApproxNUniqueAgg::new(17).is_err());
+        let other = ApproxNUniqueAgg::new(10).unwrap();
+        assert!(agg.combine(other.as_any()).is_err());
+        assert!(agg.deserialize(&other.serialize().unwrap()).is_err());
+    }
+}
@@ -0,0 +1,192 @@
+use std::any::Any;
+
+use arrow::array::PrimitiveArray;
+use polars_core::prelude::*;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+
+/// Index of the minimum or maximum value within a group, nulls and NaNs are skipped.
+///
+/// The partial states of different threads see the chunks in an arbitrary order, so the number
+/// of rows of the group is counted per chunk and the extreme value is kept together with its
+/// chunk and its position in that chunk. `finalize` turns this into the index within the group.
+/// On ties the first index wins.
+pub(crate) struct ArgMinMaxAgg<K: NumericNative> {
+    // (chunk_idx, rows of the group in that chunk)
+    counts: Vec<(IdxSize, IdxSize)>,
+    // (value, chunk_idx, position in that chunk)
+    best: Option<(K, IdxSize, IdxSize)>,
+    is_min: bool,
+}
+
+impl<K: NumericNative> ArgMinMaxAgg<K> {
+    pub(crate) fn new(is_min: bool) -> Self {
+        ArgMinMaxAgg {
+            counts: vec![],
+            best: None,
+            is_min,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.is_min)
+    }
+
+    /// Count `length` rows of `chunk_idx` and return the number of rows counted before.
+    fn add_rows(&mut self, chunk_idx: IdxSize, length: IdxSize) -> IdxSize {
+        match self.counts.last_mut() {
+            Some((idx, count)) if *idx == chunk_idx => {
+                *count += length;
+                *count - length
+            },
+            _ => {
+                self.counts.push((chunk_idx, length));
+                0
+            },
+        }
+    }
+
+    fn update(&mut self, val: K, chunk_idx: IdxSize, pos: IdxSize) {
+        if val.is_nan() {
+            return;
+        }
+        let wins = match self.best {
+            None => true,
+            Some((best, best_chunk_idx, best_pos)) => {
+                let better = if self.is_min { val < best } else { val > best };
+                better || (val == best && (chunk_idx, pos) < (best_chunk_idx, best_pos))
+            },
+        };
+        if wins {
+            self.best = Some((val, chunk_idx, pos))
+        }
+    }
+}
+
+impl<K: NumericNative> AggregateFn for ArgMinMaxAgg<K> {
+    fn pre_agg(
+        &mut self,
+        chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        let pos = self.add_rows(chunk_idx, 1);
+        if let Some(val) = item.extract::<K>() {
+            self.update(val, chunk_idx, pos)
+        }
+        Ok(())
+    }
+    fn pre_agg_ordered(
+        &mut self,
+        chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) -> PolarsResult<()> {
+        polars_ensure!(
+            values.dtype().is_numeric(),
+            SchemaMismatch: "expected a numeric dtype for `{}`, got {}", self.name(), values.dtype()
+        );
+        let arr = unsafe {
+            let arr = values.chunks().get_unchecked(0);
+            arr.sliced_unchecked(offset as usize, length as usize)
+        };
+        let dtype = K::PolarsType::get_dtype().to_arrow(true);
+        let arr = arrow::compute::cast::cast_unchecked(arr.as_ref(), &dtype)?;
+        let arr = unsafe {
+            arr.as_any()
+                .downcast_ref::<PrimitiveArray<K>>()
+                .unwrap_unchecked_release()
+        };
+        let start = self.add_rows(chunk_idx, length);
+        for (i, val) in arr.iter().enumerate() {
+            if let Some(val) = val {
+                self.update(*val, chunk_idx, start + i as IdxSize)
+            }
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        if self.is_min {
+            "arg_min"
+        } else {
+            "arg_max"
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        IDX_DTYPE
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        self.counts.extend_from_slice(&other.counts);
+        if let Some((val, chunk_idx, pos)) = other.best {
+            self.update(val, chunk_idx, pos)
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        let counts = std::mem::take(&mut self.counts);
+        match self.best.take() {
+            Some((_, chunk_idx, pos)) => {
+                let before: IdxSize = counts
+                    .iter()
+                    .filter(|(idx, _)| *idx < chunk_idx)
+                    .map(|(_, count)| *count)
+                    .sum();
+                AnyValue::from(before + pos)
+            },
+            None => AnyValue::Null,
+        }
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_arg_min_max_batches() {
+        let s = Series::new(
+            "a",
+            &[Some(3i32), None, Some(-1), Some(5), Some(-1), Some(5)],
+        );
+        // the batches are combined out of order
+        let mut agg = ArgMinMaxAgg::<i64>::new(true);
+        agg.pre_agg_ordered(2, 4, 2, &s).unwrap();
+        let mut other = agg.split();
+        other.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        other.pre_agg_ordered(1, 2, 2, &s).unwrap();
+        agg.combine(other.as_any()).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::from(2 as IdxSize));
+
+        let mut agg = ArgMinMaxAgg::<i64>::new(false);
+        agg.pre_agg_ordered(1, 2, 4, &s).unwrap();
+        let mut other = agg.split();
+        other.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        agg.combine(other.as_any()).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::from(3 as IdxSize));
+    }
+
+    #[test]
+    fn test_arg_min_max_nulls() {
+        let s = Series::new("a", &[None, Some(f64::NAN), Some(2.0), None]);
+        let mut agg = ArgMinMaxAgg::<f64>::new(false);
+        agg.pre_agg_ordered(0, 0, 4, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::from(2 as IdxSize));
+
+        let mut agg = ArgMinMaxAgg::<f64>::new(true);
+        agg.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Null);
+    }
+}
@@ -0,0 +1,175 @@
+use std::any::Any;
+use std::ops::{BitAnd, BitOr, Not};
+
+use arrow::array::PrimitiveArray;
+use polars_core::prelude::*;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+
+/// The bitwise `and` or `or` of the integer values of a group, e.g. to combine bitmask flags.
+///
+/// The values are folded into a mask that starts at all ones for `and` and at zero for `or`,
+/// which doesn't change the result. Nulls are skipped, a group without values is null.
+pub(crate) struct BitwiseAgg<K: NumericNative> {
+    mask: K,
+    has_value: bool,
+    is_and: bool,
+}
+
+impl<K> BitwiseAgg<K>
+where
+    K: NumericNative + BitAnd<Output = K> + BitOr<Output = K> + Not<Output = K>,
+{
+    pub(crate) fn new(is_and: bool) -> Self {
+        BitwiseAgg {
+            mask: if is_and { !K::zero() } else { K::zero() },
+            has_value: false,
+            is_and,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.is_and)
+    }
+
+    #[inline]
+    fn apply(&mut self, val: K) {
+        self.mask = if self.is_and {
+            self.mask & val
+        } else {
+            self.mask | val
+        };
+        self.has_value = true;
+    }
+}
+
+impl<K> AggregateFn for BitwiseAgg<K>
+where
+    K: NumericNative + BitAnd<Output = K> + BitOr<Output = K> + Not<Output = K>,
+{
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        if let Some(val) = item.extract::<K>() {
+            self.apply(val)
+        }
+        Ok(())
+    }
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) -> PolarsResult<()> {
+        polars_ensure!(
+            values.dtype().is_integer(),
+            SchemaMismatch: "expected integers for `{}`, got {}", self.name(), values.dtype()
+        );
+        let arr = unsafe {
+            let arr = values.chunks().get_unchecked(0);
+            arr.sliced_unchecked(offset as usize, length as usize)
+        };
+        // signed integers are sign extended, which keeps the bits of the result
+        let dtype = K::PolarsType::get_dtype().to_arrow(true);
+        let arr = arrow::compute::cast::cast_unchecked(arr.as_ref(), &dtype)?;
+        let arr = unsafe {
+            arr.as_any()
+                .downcast_ref::<PrimitiveArray<K>>()
+                .unwrap_unchecked_release()
+        };
+        arr.iter().flatten().for_each(|val| self.apply(*val));
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        if self.is_and {
+            "bitwise_and"
+        } else {
+            "bitwise_or"
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        K::PolarsType::get_dtype()
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        if other.has_value {
+            self.apply(other.mask)
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        let out = if self.has_value {
+            self.mask.into()
+        } else {
+            AnyValue::Null
+        };
+        *self = self.split();
+        out
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fold<K>(is_and: bool, s: &Series) -> AnyValue<'static>
+    where
+        K: NumericNative + BitAnd<Output = K> + BitOr<Output = K> + Not<Output = K>,
+    {
+        let mid = s.len() as IdxSize / 2;
+        let mut agg = BitwiseAgg::<K>::new(is_and);
+        agg.pre_agg_ordered(0, 0, mid, s).unwrap();
+        let mut other = agg.split();
+        other
+            .pre_agg_ordered(1, mid, s.len() as IdxSize - mid, s)
+            .unwrap();
+        agg.combine(other.as_any()).unwrap();
+        agg.finalize()
+    }
+
+    #[test]
+    fn test_bitwise_batches() {
+        let s = Series::new(
+            "flags",
+            &[Some(0b0111u32), None, Some(0b1101), Some(0b0101)],
+        );
+        assert_eq!(fold::<u64>(false, &s), AnyValue::UInt64(0b1111));
+        assert_eq!(fold::<u64>(true, &s), AnyValue::UInt64(0b0101));
+
+        let s = Series::new("flags", &[-1i32, -2, 6, 3]);
+        assert_eq!(fold::<i64>(false, &s), AnyValue::Int64(-1));
+        assert_eq!(fold::<i64>(true, &s), AnyValue::Int64(2));
+    }
+
+    #[test]
+    fn test_bitwise_nulls() {
+        // a group without values is null, also when combined with an empty state
+        let s = Series::new("flags", &[None::<i64>, None]);
+        assert_eq!(fold::<i64>(true, &s), AnyValue::Null);
+        assert_eq!(fold::<i64>(false, &s), AnyValue::Null);
+
+        // a state without values doesn't clear the mask of `and`
+        let s = Series::new("flags", &[None, Some(0b0110u64)]);
+        assert_eq!(fold::<u64>(true, &s), AnyValue::UInt64(0b0110));
+
+        let s = Series::new("flags", &[1.0f64, 2.0]);
+        assert!(BitwiseAgg::<i64>::new(false)
+            .pre_agg_ordered(0, 0, 2, &s)
+            .is_err());
+    }
+}
@@ -7,19 +7,28 @@ use polars_core::frame::DataFrame;
 use polars_core::prelude::{DataType, SchemaRef, Series, IDX_DTYPE};
 use polars_core::schema::Schema;
 use polars_io::predicates::PhysicalIoExpr;
-use polars_plan::dsl::Expr;
+#[cfg(feature = "concat_str")]
+use polars_plan::dsl::StringFunction;
+use polars_plan::dsl::{BooleanFunction, Expr, FunctionExpr};
 use polars_plan::logical_plan::{ArenaExprIter, Context};
 use polars_plan::prelude::{AAggExpr, AExpr};
 use polars_utils::arena::{Arena, Node};
-use polars_utils::IdxSize;
 
-use crate::executors::sinks::group_by::aggregates::count::CountAgg;
-use crate::executors::sinks::group_by::aggregates::first::FirstAgg;
-use crate::executors::sinks::group_by::aggregates::last::LastAgg;
-use crate::executors::sinks::group_by::aggregates::mean::MeanAgg;
-use crate::executors::sinks::group_by::aggregates::min_max::{new_max, new_min};
-use crate::executors::sinks::group_by::aggregates::null::NullAgg;
-use crate::executors::sinks::group_by::aggregates::{AggregateFunction, SumAgg};
+use crate::executors::sinks::group_by::aggregates::any_all::{AllAgg, AnyAgg};
+#[cfg(feature = "approx_unique")]
+use crate::executors::sinks::group_by::aggregates::approx_n_unique::{
+    ApproxNUniqueAgg, DEFAULT_PRECISION,
+};
+use crate::executors::sinks::group_by::aggregates::factory::AggregationFactory;
+#[cfg(feature = "moment")]
+use crate::executors::sinks::group_by::aggregates::moments::{KurtosisAgg, SkewAgg};
+use crate::executors::sinks::group_by::aggregates::n_unique::NUniqueAgg;
+use crate::executors::sinks::group_by::aggregates::quantile::QuantileAgg;
+#[cfg(feature = "concat_str")]
+use crate::executors::sinks::group_by::aggregates::str_concat::StrConcatAgg;
+use crate::executors::sinks::group_by::aggregates::var::{StdAgg, VarAgg};
+use crate::executors::sinks::group_by::aggregates::{AggregateFn, AggregateFunction};
+use crate::executors::sinks::group_by::random_state_from_env;
 use crate::expressions::PhysicalPipedExpr;
 use crate::operators::DataChunk;
 
@@ -64,13 +73,14 @@ pub fn can_convert_to_hash_agg(
                 | AExpr::BinaryExpr { .. }
                 | AExpr::Ternary { .. }
                 | AExpr::Alias(_, _) => {},
+                ae if is_agg_function(ae) => {},
                 _ => {
                     can_run_partitioned = false;
                 },
             }
             ae
         })
-        .filter(|ae| matches!(ae, AExpr::Agg(_) | AExpr::Len))
+        .filter(|ae| matches!(ae, AExpr::Agg(_) | AExpr::Len) || is_agg_function(ae))
         .count()
         == 1
         && can_run_partitioned
@@ -81,6 +91,47 @@ pub fn can_convert_to_hash_agg(
         }
         match expr_arena.get(node) {
             AExpr::Len => true,
+            ae if is_approx_n_unique(ae) => true,
+            ae @ AExpr::Function { input, .. } if is_str_concat(ae) => {
+                matches!(
+                    expr_arena
+                        .get(input[0])
+                        .to_field(input_schema, Context::Default, expr_arena),
+                    Ok(field) if field.dtype == DataType::String
+                )
+            },
+            ae @ AExpr::Function { input, .. } if is_any_all(ae) => {
+                matches!(
+                    expr_arena
+                        .get(input[0])
+                        .to_field(input_schema, Context::Default, expr_arena),
+                    Ok(field) if field.dtype == DataType::Boolean
+                )
+            },
+            ae @ AExpr::AnonymousFunction { input, .. } if is_product(ae) => {
+                matches!(
+                    expr_arena
+                        .get(input[0])
+                        .to_field(input_schema, Context::Default, expr_arena),
+                    Ok(field) if field.dtype.is_numeric() || field.dtype.is_bool()
+                )
+            },
+            ae @ AExpr::Function { input, .. } if is_bitwise(ae) => {
+                matches!(
+                    expr_arena
+                        .get(input[0])
+                        .to_field(input_schema, Context::Default, expr_arena),
+                    Ok(field) if field.dtype.is_integer()
+                )
+            },
+            ae @ AExpr::Function { input, .. } if is_arg_min_max(ae) || is_moment(ae) => {
+                matches!(
+                    expr_arena
+                        .get(input[0])
+                        .to_field(input_schema, Context::Default, expr_arena),
+                    Ok(field) if field.dtype.to_physical().is_numeric()
+                )
+            },
             ae @ AExpr::Agg(agg_fn) => {
                 matches!(
                     agg_fn,
@@ -89,6 +140,7 @@ pub fn can_convert_to_hash_agg(
                         | AAggExpr::Last(_)
                         | AAggExpr::Mean(_)
                         | AAggExpr::Count(_, false)
+                        | AAggExpr::NUnique(_)
                 ) || (matches!(
                     agg_fn,
                     AAggExpr::Max {
@@ -104,7 +156,28 @@ pub fn can_convert_to_hash_agg(
                     } else {
                         false
                     }
-                })
+                }) || match agg_fn {
+                    AAggExpr::Quantile { quantile, .. }
+                        if literal_quantile(*quantile, expr_arena).is_none() =>
+                    {
+                        false
+                    },
+                    AAggExpr::Std(input, _)
+                    | AAggExpr::Var(input, _)
+                    | AAggExpr::Median(input)
+                    | AAggExpr::Quantile { expr: input, .. } => {
+                        if let Ok(field) = expr_arena.get(*input).to_field(
+                            input_schema,
+                            Context::Default,
+                            expr_arena,
+                        ) {
+                            field.dtype.to_physical().is_numeric()
+                        } else {
+                            false
+                        }
+                    },
+                    _ => false,
+                }
             },
             _ => false,
         }
@@ -113,6 +186,139 @@ pub fn can_convert_to_hash_agg(
     }
 }
 
+/// `str.concat` reduces a group to a single value, so it runs as an aggregation.
+#[cfg(feature = "concat_str")]
+fn is_str_concat(ae: &AExpr) -> bool {
+    matches!(
+        ae,
+        AExpr::Function {
+            function: FunctionExpr::StringExpr(StringFunction::ConcatVertical { .. }),
+            ..
+        }
+    )
+}
+
+#[cfg(not(feature = "concat_str"))]
+fn is_str_concat(_ae: &AExpr) -> bool {
+    false
+}
+
+/// Functions that reduce a group to a single value and run as an aggregation.
+fn is_agg_function(ae: &AExpr) -> bool {
+    is_str_concat(ae)
+        || is_product(ae)
+        || is_any_all(ae)
+        || is_arg_min_max(ae)
+        || is_approx_n_unique(ae)
+        || is_moment(ae)
+        || is_bitwise(ae)
+}
+
+#[cfg(feature = "approx_unique")]
+fn is_approx_n_unique(ae: &AExpr) -> bool {
+    matches!(
+        ae,
+        AExpr::Function {
+            function: FunctionExpr::ApproxNUnique,
+            ..
+        }
+    )
+}
+
+#[cfg(not(feature = "approx_unique"))]
+fn is_approx_n_unique(_ae: &AExpr) -> bool {
+    false
+}
+
+/// `skew` and `kurtosis`.
+#[cfg(feature = "moment")]
+fn is_moment(ae: &AExpr) -> bool {
+    matches!(
+        ae,
+        AExpr::Function {
+            function: FunctionExpr::Skew(_) | FunctionExpr::Kurtosis(_, _),
+            ..
+        }
+    )
+}
+
+#[cfg(not(feature = "moment"))]
+fn is_moment(_ae: &AExpr) -> bool {
+    false
+}
+
+/// `product` is an anonymous function, it can only be recognized by its name.
+fn is_product(ae: &AExpr) -> bool {
+    matches!(
+        ae,
+        AExpr::AnonymousFunction { input, options, .. }
+            if options.fmt_str == "product" && input.len() == 1
+    )
+}
+
+fn is_arg_min_max(ae: &AExpr) -> bool {
+    matches!(
+        ae,
+        AExpr::Function {
+            function: FunctionExpr::ArgMin | FunctionExpr::ArgMax,
+            ..
+        }
+    )
+}
+
+fn is_bitwise(ae: &AExpr) -> bool {
+    matches!(
+        ae,
+        AExpr::Function {
+            function: FunctionExpr::BitwiseAnd | FunctionExpr::BitwiseOr,
+            ..
+        }
+    )
+}
+
+fn is_any_all(ae: &AExpr) -> bool {
+    matches!(
+        ae,
+        AExpr::Function {
+            function: FunctionExpr::Boolean(
+                BooleanFunction::Any { .. } | BooleanFunction::All { .. }
+            ),
+            ..
+        }
+    )
+}
+
+/// The quantile of a quantile aggregation if it is a literal in `[0, 1]`.
+fn literal_quantile(node: Node, expr_arena: &Arena<AExpr>) -> Option<f64> {
+    match expr_arena.get(node) {
+        AExpr::Literal(lv) => lv
+            .to_any_value()
+            .and_then(|av| av.extract::<f64>())
+            .filter(|q| (0.0..=1.0).contains(q)),
+        _ => None,
+    }
+}
+
+/// The output schema of the aggregations `agg_fns` that follow `n_keys` key columns.
+///
+/// The plan keeps the dtype of an `Int32` or `UInt32` sum, but the streaming engine accumulates
+/// those in 64 bits. A sum that doesn't fit in 32 bits would become null when it is cast back,
+/// so the output is widened to the dtype of the accumulator.
+pub(crate) fn hash_agg_output_schema(
+    output_schema: &SchemaRef,
+    n_keys: usize,
+    agg_fns: &[AggregateFunction],
+    input_dtypes: &[DataType],
+) -> SchemaRef {
+    let mut schema = output_schema.as_ref().clone();
+    for (i, (agg_fn, input_dtype)) in agg_fns.iter().zip(input_dtypes).enumerate() {
+        if agg_fn.name() == "sum" && matches!(input_dtype, DataType::Int32 | DataType::UInt32) {
+            schema.set_dtype_at_index(n_keys + i, agg_fn.output_dtype(input_dtype));
+        }
+    }
+    Arc::new(schema)
+}
+
 /// # Returns:
 ///  - input_dtype: dtype that goes into the agg expression
 ///  - physical expr: physical expression that produces the input of the aggregation
@@ -131,140 +337,206 @@ where
         AExpr::Len => (
             IDX_DTYPE,
             Arc::new(Len {}),
-            AggregateFunction::Len(CountAgg::new()),
+            AggregationFactory::Len.build(&IDX_DTYPE).unwrap(),
         ),
         AExpr::Agg(agg) => match agg {
             AAggExpr::Min { input, .. } => {
                 let phys_expr = to_physical(*input, expr_arena, Some(schema)).unwrap();
                 let logical_dtype = phys_expr.field(schema).unwrap().dtype;
-
-                let agg_fn = match logical_dtype.to_physical() {
-                    DataType::Int8 => AggregateFunction::MinMaxI8(new_min()),
-                    DataType::Int16 => AggregateFunction::MinMaxI16(new_min()),
-                    DataType::Int32 => AggregateFunction::MinMaxI32(new_min()),
-                    DataType::Int64 => AggregateFunction::MinMaxI64(new_min()),
-                    DataType::UInt8 => AggregateFunction::MinMaxU8(new_min()),
-                    DataType::UInt16 => AggregateFunction::MinMaxU16(new_min()),
-                    DataType::UInt32 => AggregateFunction::MinMaxU32(new_min()),
-                    DataType::UInt64 => AggregateFunction::MinMaxU64(new_min()),
-                    DataType::Float32 => AggregateFunction::MinMaxF32(new_min()),
-                    DataType::Float64 => AggregateFunction::MinMaxF64(new_min()),
-                    dt => panic!("{dt} unexpected"),
-                };
+                let agg_fn = AggregationFactory::Min.build(&logical_dtype).unwrap();
                 (logical_dtype, phys_expr, agg_fn)
             },
             AAggExpr::Max { input, .. } => {
                 let phys_expr = to_physical(*input, expr_arena, Some(schema)).unwrap();
                 let logical_dtype = phys_expr.field(schema).unwrap().dtype;
-
-                let agg_fn = match logical_dtype.to_physical() {
-                    DataType::Int8 => AggregateFunction::MinMaxI8(new_max()),
-                    DataType::Int16 => AggregateFunction::MinMaxI16(new_max()),
-                    DataType::Int32 => AggregateFunction::MinMaxI32(new_max()),
-                    DataType::Int64 => AggregateFunction::MinMaxI64(new_max()),
-                    DataType::UInt8 => AggregateFunction::MinMaxU8(new_max()),
-                    DataType::UInt16 => AggregateFunction::MinMaxU16(new_max()),
-                    DataType::UInt32 => AggregateFunction::MinMaxU32(new_max()),
-                    DataType::UInt64 => AggregateFunction::MinMaxU64(new_max()),
-                    DataType::Float32 => AggregateFunction::MinMaxF32(new_max()),
-                    DataType::Float64 => AggregateFunction::MinMaxF64(new_max()),
-                    dt => panic!("{dt} unexpected"),
-                };
+                let agg_fn = AggregationFactory::Max.build(&logical_dtype).unwrap();
                 (logical_dtype, phys_expr, agg_fn)
             },
             AAggExpr::Sum(input) => {
                 let phys_expr = to_physical(*input, expr_arena, Some(schema)).unwrap();
                 let logical_dtype = phys_expr.field(schema).unwrap().dtype;
-
-                #[cfg(feature = "dtype-categorical")]
-                if matches!(
-                    logical_dtype,
-                    DataType::Categorical(_, _) | DataType::Enum(_, _)
-                ) {
-                    return (
-                        logical_dtype.clone(),
-                        phys_expr,
-                        AggregateFunction::Null(NullAgg::new(logical_dtype)),
-                    );
-                }
-
-                let agg_fn = match logical_dtype.to_physical() {
-                    // Boolean is aggregated as the IDX type.
-                    DataType::Boolean => {
-                        if std::mem::size_of::<IdxSize>() == 4 {
-                            AggregateFunction::SumU32(SumAgg::<u32>::new())
-                        } else {
-                            AggregateFunction::SumU64(SumAgg::<u64>::new())
-                        }
-                    },
-                    // these are aggregated as i64 to prevent overflow
-                    DataType::Int8 => AggregateFunction::SumI64(SumAgg::<i64>::new()),
-                    DataType::Int16 => AggregateFunction::SumI64(SumAgg::<i64>::new()),
-                    DataType::UInt8 => AggregateFunction::SumI64(SumAgg::<i64>::new()),
-                    DataType::UInt16 => AggregateFunction::SumI64(SumAgg::<i64>::new()),
-                    //  these stay true to there types
-                    DataType::UInt32 => AggregateFunction::SumU32(SumAgg::<u32>::new()),
-                    DataType::UInt64 => AggregateFunction::SumU64(SumAgg::<u64>::new()),
-                    DataType::Int32 => AggregateFunction::SumI32(SumAgg::<i32>::new()),
-                    DataType::Int64 => AggregateFunction::SumI64(SumAgg::<i64>::new()),
-                    DataType::Float32 => AggregateFunction::SumF32(SumAgg::<f32>::new()),
-                    DataType::Float64 => AggregateFunction::SumF64(SumAgg::<f64>::new()),
-                    dt => AggregateFunction::Null(NullAgg::new(dt)),
-                };
+                let agg_fn = AggregationFactory::Sum.build(&logical_dtype).unwrap();
                 (logical_dtype, phys_expr, agg_fn)
             },
             AAggExpr::Mean(input) => {
                 let phys_expr = to_physical(*input, expr_arena, Some(schema)).unwrap();
-
                 let logical_dtype = phys_expr.field(schema).unwrap().dtype;
-                #[cfg(feature = "dtype-categorical")]
-                if matches!(
-                    logical_dtype,
-                    DataType::Categorical(_, _) | DataType::Enum(_, _)
-                ) {
-                    return (
-                        logical_dtype.clone(),
-                        phys_expr,
-                        AggregateFunction::Null(NullAgg::new(logical_dtype)),
-                    );
-                }
-                let agg_fn = match logical_dtype.to_physical() {
-                    dt if dt.is_integer() => AggregateFunction::MeanF64(MeanAgg::<f64>::new()),
-                    DataType::Float32 => AggregateFunction::MeanF32(MeanAgg::<f32>::new()),
-                    DataType::Float64 => AggregateFunction::MeanF64(MeanAgg::<f64>::new()),
-                    dt => AggregateFunction::Null(NullAgg::new(dt)),
-                };
+                let agg_fn = AggregationFactory::Mean.build(&logical_dtype).unwrap();
                 (logical_dtype, phys_expr, agg_fn)
             },
             AAggExpr::First(input) => {
+                let phys_expr = to_physical(*input, expr_arena, Some(schema)).unwrap();
+                let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+                let agg_fn = AggregationFactory::First.build(&logical_dtype).unwrap();
+                (logical_dtype, phys_expr, agg_fn)
+            },
+            AAggExpr::Last(input) => {
+                let phys_expr = to_physical(*input, expr_arena, Some(schema)).unwrap();
+                let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+                let agg_fn = AggregationFactory::Last.build(&logical_dtype).unwrap();
+                (logical_dtype, phys_expr, agg_fn)
+            },
+            AAggExpr::Count(input, _) => {
+                let phys_expr = to_physical(*input, expr_arena, Some(schema)).unwrap();
+                let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+                let agg_fn = AggregationFactory::Count.build(&logical_dtype).unwrap();
+                (logical_dtype, phys_expr, agg_fn)
+            },
+            AAggExpr::NUnique(input) => {
                 let phys_expr = to_physical(*input, expr_arena, Some(schema)).unwrap();
                 let logical_dtype = phys_expr.field(schema).unwrap().dtype;
                 (
-                    logical_dtype.clone(),
+                    logical_dtype,
                     phys_expr,
-                    AggregateFunction::First(FirstAgg::new(logical_dtype.to_physical())),
+                    AggregateFunction::NUnique(NUniqueAgg::with_random_state(
+                        random_state_from_env(),
+                    )),
                 )
             },
-            AAggExpr::Last(input) => {
+            AAggExpr::Var(input, ddof) => {
                 let phys_expr = to_physical(*input, expr_arena, Some(schema)).unwrap();
                 let logical_dtype = phys_expr.field(schema).unwrap().dtype;
                 (
-                    logical_dtype.clone(),
+                    logical_dtype,
                     phys_expr,
-                    AggregateFunction::Last(LastAgg::new(logical_dtype.to_physical())),
+                    AggregateFunction::Var(VarAgg::new(*ddof)),
                 )
             },
-            AAggExpr::Count(input, _) => {
+            AAggExpr::Median(input) => {
+                let phys_expr = to_physical(*input, expr_arena, Some(schema)).unwrap();
+                let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+                let agg = QuantileAgg::new_median(&logical_dtype);
+                (logical_dtype, phys_expr, AggregateFunction::Quantile(agg))
+            },
+            AAggExpr::Quantile {
+                expr,
+                quantile,
+                interpol,
+            } => {
+                let phys_expr = to_physical(*expr, expr_arena, Some(schema)).unwrap();
+                let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+                let quantile = literal_quantile(*quantile, expr_arena).unwrap();
+                let agg = QuantileAgg::new(quantile, *interpol, &logical_dtype);
+                (logical_dtype, phys_expr, AggregateFunction::Quantile(agg))
+            },
+            AAggExpr::Std(input, ddof) => {
                 let phys_expr = to_physical(*input, expr_arena, Some(schema)).unwrap();
                 let logical_dtype = phys_expr.field(schema).unwrap().dtype;
                 (
                     logical_dtype,
                     phys_expr,
-                    AggregateFunction::Count(CountAgg::new()),
+                    AggregateFunction::Std(StdAgg::new(*ddof)),
                 )
             },
             agg => panic!("{agg:?} not yet implemented."),
         },
+        #[cfg(feature = "concat_str")]
+        AExpr::Function {
+            input,
+            function:
+                FunctionExpr::StringExpr(StringFunction::ConcatVertical {
+                    delimiter,
+                    ignore_nulls,
+                }),
+            ..
+        } => {
+            let phys_expr = to_physical(input[0], expr_arena, Some(schema)).unwrap();
+            let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+            (
+                logical_dtype,
+                phys_expr,
+                AggregateFunction::StrConcat(StrConcatAgg::new(
+                    delimiter.as_str().into(),
+                    *ignore_nulls,
+                )),
+            )
+        },
+        AExpr::Function {
+            input,
+            function: FunctionExpr::Boolean(function),
+            ..
+        } => {
+            let phys_expr = to_physical(input[0], expr_arena, Some(schema)).unwrap();
+            let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+            let agg_fn = match function {
+                BooleanFunction::Any { ignore_nulls } => {
+                    AggregateFunction::Any(AnyAgg::new(*ignore_nulls))
+                },
+                BooleanFunction::All { ignore_nulls } => {
+                    AggregateFunction::All(AllAgg::new(*ignore_nulls))
+                },
+                _ => unreachable!(),
+            };
+            (logical_dtype, phys_expr, agg_fn)
+        },
+        ae @ AExpr::AnonymousFunction { input, .. } if is_product(ae) => {
+            let phys_expr = to_physical(input[0], expr_arena, Some(schema)).unwrap();
+            let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+            let agg_fn = AggregationFactory::Product.build(&logical_dtype).unwrap();
+            (logical_dtype, phys_expr, agg_fn)
+        },
+        AExpr::Function {
+            input,
+            function: function @ (FunctionExpr::ArgMin | FunctionExpr::ArgMax),
+            ..
+        } => {
+            let phys_expr = to_physical(input[0], expr_arena, Some(schema)).unwrap();
+            let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+            let factory = if matches!(function, FunctionExpr::ArgMin) {
+                AggregationFactory::ArgMin
+            } else {
+                AggregationFactory::ArgMax
+            };
+            let agg_fn = factory.build(&logical_dtype).unwrap();
+            (logical_dtype, phys_expr, agg_fn)
+        },
+        AExpr::Function {
+            input,
+            function: function @ (FunctionExpr::BitwiseAnd | FunctionExpr::BitwiseOr),
+            ..
+        } => {
+            let phys_expr = to_physical(input[0], expr_arena, Some(schema)).unwrap();
+            let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+            let factory = if matches!(function, FunctionExpr::BitwiseAnd) {
+                AggregationFactory::BitwiseAnd
+            } else {
+                AggregationFactory::BitwiseOr
+            };
+            let agg_fn = factory.build(&logical_dtype).unwrap();
+            (logical_dtype, phys_expr, agg_fn)
+        },
+        #[cfg(feature = "approx_unique")]
+        AExpr::Function {
+            input,
+            function: FunctionExpr::ApproxNUnique,
+            ..
+        } => {
+            let phys_expr = to_physical(input[0], expr_arena, Some(schema)).unwrap();
+            let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+            let agg = ApproxNUniqueAgg::new(DEFAULT_PRECISION).unwrap();
+            (
+                logical_dtype,
+                phys_expr,
+                AggregateFunction::ApproxNUnique(agg),
+            )
+        },
+        #[cfg(feature = "moment")]
+        AExpr::Function {
+            input,
+            function: function @ (FunctionExpr::Skew(_) | FunctionExpr::Kurtosis(_, _)),
+            ..
+        } => {
+            let phys_expr = to_physical(input[0], expr_arena, Some(schema)).unwrap();
+            let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+            let agg_fn = match function {
+                FunctionExpr::Skew(bias) => AggregateFunction::Skew(SkewAgg::new(*bias)),
+                FunctionExpr::Kurtosis(fisher, bias) => {
+                    AggregateFunction::Kurtosis(KurtosisAgg::new(*fisher, *bias))
+                },
+                _ => unreachable!(),
+            };
+            (logical_dtype, phys_expr, agg_fn)
+        },
         _ => todo!(),
     }
 }
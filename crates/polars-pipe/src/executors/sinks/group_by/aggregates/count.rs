@@ -1,7 +1,7 @@
 use std::any::Any;
 
 use polars_core::datatypes::{AnyValue, DataType};
-use polars_core::prelude::{Series, IDX_DTYPE};
+use polars_core::prelude::{polars_ensure, polars_err, PolarsResult, Series, IDX_DTYPE};
 use polars_utils::unwrap::UnwrapUncheckedRelease;
 
 use super::*;
@@ -22,13 +22,18 @@ impl<const INCLUDE_NULL: bool> AggregateFn for CountAgg<INCLUDE_NULL> {
         false
     }
 
-    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
         let item = unsafe { item.next().unwrap_unchecked_release() };
         if INCLUDE_NULL {
             self.count += 1;
         } else {
             self.count += !matches!(item, AnyValue::Null) as IdxSize;
         }
+        Ok(())
     }
     fn pre_agg_ordered(
         &mut self,
@@ -36,17 +41,48 @@ impl<const INCLUDE_NULL: bool> AggregateFn for CountAgg<INCLUDE_NULL> {
         _offset: IdxSize,
         length: IdxSize,
         _values: &Series,
-    ) {
-        self.count += length
+    ) -> PolarsResult<()> {
+        self.count += length;
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        if INCLUDE_NULL {
+            "len"
+        } else {
+            "count"
+        }
     }
 
     fn dtype(&self) -> DataType {
         IDX_DTYPE
     }
 
-    fn combine(&mut self, other: &dyn Any) {
-        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        // `count` and `len` are different types, so they can't be added up by accident. The
+        // mismatch deserves a clearer error than a different aggregation though.
+        let other_flavor =
+            !other.is::<Self>() && (other.is::<CountAgg<true>>() || other.is::<CountAgg<false>>());
+        polars_ensure!(
+            !other_flavor,
+            ComputeError: "cannot combine a `{}` with a `{}` partial, only one counts nulls",
+            self.name(), if INCLUDE_NULL { "count" } else { "len" }
+        );
+        let other = downcast_other::<Self>(other)?;
         self.count += other.count;
+        Ok(())
+    }
+
+    fn serialize(&self) -> PolarsResult<Vec<u8>> {
+        Ok(self.count.to_le_bytes().to_vec())
+    }
+
+    fn deserialize(&mut self, bytes: &[u8]) -> PolarsResult<()> {
+        let bytes = bytes.try_into().map_err(
+            |_| polars_err!(ComputeError: "invalid serialized state for a `{}`", self.name()),
+        )?;
+        self.count = IdxSize::from_le_bytes(bytes);
+        Ok(())
     }
 
     fn finalize(&mut self) -> AnyValue<'static> {
@@ -55,4 +91,61 @@ impl<const INCLUDE_NULL: bool> AggregateFn for CountAgg<INCLUDE_NULL> {
     fn as_any(&self) -> &dyn Any {
         self
     }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_count_serialize_round_trip() {
+        let s = Series::new("a", &[Some(1i32), None, Some(3)]);
+        let mut remote = CountAgg::<false>::new();
+        remote
+            .pre_agg(0, &mut [AnyValue::Int32(1)].into_iter())
+            .unwrap();
+        remote
+            .pre_agg(0, &mut [AnyValue::Null].into_iter())
+            .unwrap();
+
+        let mut restored = CountAgg::<false>::new();
+        restored.deserialize(&remote.serialize().unwrap()).unwrap();
+        let mut local = CountAgg::<false>::new();
+        local.pre_agg_ordered(0, 0, 3, &s).unwrap();
+        local.combine(restored.as_any()).unwrap();
+        assert_eq!(local.count, 4);
+
+        assert!(restored.deserialize(&[0]).is_err());
+    }
+
+    #[test]
+    fn test_count_as_any_mut() {
+        let s = Series::new("a", &[Some(1i32), None, Some(3)]);
+        let mut agg = AggregateFunction::Len(CountAgg::new());
+        agg.pre_agg_ordered(0, 0, 3, &s).unwrap();
+        // the enum dispatches to the inner aggregation
+        let inner = agg.as_any_mut().downcast_mut::<CountAgg<true>>().unwrap();
+        assert_eq!(std::mem::take(&mut inner.count), 3);
+        assert_eq!(agg.finalize().extract::<u64>(), Some(0));
+    }
+
+    #[test]
+    fn test_count_include_nulls_mismatch() {
+        let s = Series::new("a", &[Some(1i32), None, Some(3)]);
+        let mut count = CountAgg::<false>::new();
+        count.pre_agg_ordered(0, 0, 3, &s).unwrap();
+        let mut len = CountAgg::<true>::new();
+        len.pre_agg_ordered(0, 0, 3, &s).unwrap();
+
+        let err = count.combine(len.as_any()).unwrap_err();
+        assert!(err.to_string().contains("counts nulls"), "{err}");
+        assert!(len.combine(count.as_any()).is_err());
+        // nothing was added
+        assert_eq!((count.count, len.count), (3, 3));
+    }
 }
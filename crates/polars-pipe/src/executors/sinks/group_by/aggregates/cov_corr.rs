@@ -0,0 +1,253 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+
+use super::*;
+
+/// Covariance or Pearson correlation of two columns.
+///
+/// The pairs are fed with [`AggregateFn::pre_agg_ordered_pair`], rows where either value is
+/// null are skipped. Instead of raw sums of products the means and co-moments are updated
+/// online like in [`VarAgg`](super::var::VarAgg), and partial states are merged with the
+/// parallel formulas of Chan et al., so the result doesn't suffer from cancellation. The values
+/// are centered on the first pair of the state, so that a large offset of the values doesn't
+/// cost the precision of the running means.
+#[derive(Clone)]
+pub(crate) struct CovCorrAgg {
+    count: u64,
+    // the first pair, the means are relative to it
+    shift_x: f64,
+    shift_y: f64,
+    mean_x: f64,
+    mean_y: f64,
+    // sums of the (co-)deviations from the means
+    c_xy: f64,
+    m2_x: f64,
+    m2_y: f64,
+    ddof: u8,
+    pearson: bool,
+}
+
+impl CovCorrAgg {
+    pub(crate) fn new_cov(ddof: u8) -> Self {
+        CovCorrAgg {
+            count: 0,
+            shift_x: 0.0,
+            shift_y: 0.0,
+            mean_x: 0.0,
+            mean_y: 0.0,
+            c_xy: 0.0,
+            m2_x: 0.0,
+            m2_y: 0.0,
+            ddof,
+            pearson: false,
+        }
+    }
+
+    // not created by the planner yet
+    #[allow(dead_code)]
+    pub(crate) fn new_pearson() -> Self {
+        CovCorrAgg {
+            pearson: true,
+            ..Self::new_cov(0)
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        CovCorrAgg {
+            pearson: self.pearson,
+            ..Self::new_cov(self.ddof)
+        }
+    }
+
+    fn update(&mut self, x: f64, y: f64) {
+        if self.count == 0 {
+            self.shift_x = x;
+            self.shift_y = y;
+        }
+        let x = x - self.shift_x;
+        let y = y - self.shift_y;
+        self.count += 1;
+        let n = self.count as f64;
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x += dx / n;
+        self.mean_y += dy / n;
+        self.c_xy += dx * (y - self.mean_y);
+        self.m2_x += dx * (x - self.mean_x);
+        self.m2_y += dy * (y - self.mean_y);
+    }
+
+    fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = other.clone();
+            return;
+        }
+        let count = self.count + other.count;
+        let n = count as f64;
+        let weight = (self.count * other.count) as f64 / n;
+        // the means of `other` are relative to its own shift
+        let dx = (other.shift_x - self.shift_x) + other.mean_x - self.mean_x;
+        let dy = (other.shift_y - self.shift_y) + other.mean_y - self.mean_y;
+        self.mean_x += dx * other.count as f64 / n;
+        self.mean_y += dy * other.count as f64 / n;
+        self.c_xy += other.c_xy + dx * dy * weight;
+        self.m2_x += other.m2_x + dx * dx * weight;
+        self.m2_y += other.m2_y + dy * dy * weight;
+        self.count = count;
+    }
+}
+
+fn to_f64(s: &Series, offset: IdxSize, length: IdxSize, name: &str) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.dtype().is_numeric(),
+        SchemaMismatch: "expected a numeric dtype for `{}`, got {}", name, s.dtype()
+    );
+    s.slice(offset as i64, length as usize)
+        .cast(&DataType::Float64)
+}
+
+impl AggregateFn for CovCorrAgg {
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        _item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
+        polars_bail!(InvalidOperation: "`{}` needs a second column", self.name())
+    }
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        _offset: IdxSize,
+        _length: IdxSize,
+        _values: &Series,
+    ) -> PolarsResult<()> {
+        polars_bail!(InvalidOperation: "`{}` needs a second column", self.name())
+    }
+    fn pre_agg_ordered_pair(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+        weights: &Series,
+    ) -> PolarsResult<()> {
+        let x = to_f64(values, offset, length, self.name())?;
+        let y = to_f64(weights, offset, length, self.name())?;
+        x.f64()?.into_iter().zip(y.f64()?).for_each(|pair| {
+            if let (Some(x), Some(y)) = pair {
+                self.update(x, y)
+            }
+        });
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        if self.pearson {
+            "pearson_corr"
+        } else {
+            "cov"
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::Float64
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        self.merge(other);
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        let out = if self.pearson {
+            (self.count > 0).then(|| self.c_xy / (self.m2_x * self.m2_y).sqrt())
+        } else {
+            (self.count > self.ddof as u64)
+                .then(|| self.c_xy / (self.count - self.ddof as u64) as f64)
+        };
+        out.into()
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn stream(mut agg: CovCorrAgg, x: &Series, y: &Series) -> f64 {
+        let mut other = agg.split();
+        agg.pre_agg_ordered_pair(0, 0, 3, x, y).unwrap();
+        other.pre_agg_ordered_pair(1, 3, 4, x, y).unwrap();
+        agg.combine(other.as_any()).unwrap();
+        agg.finalize().extract::<f64>().unwrap()
+    }
+
+    #[test]
+    fn test_cov_corr() {
+        // a large offset to catch cancellation
+        let x = [
+            Some(1.0),
+            Some(2.0),
+            None,
+            Some(4.0),
+            Some(3.0),
+            Some(7.0),
+            Some(0.0),
+        ]
+        .iter()
+        .map(|x| x.map(|x| x + 1e9))
+        .collect::<Float64Chunked>()
+        .into_series();
+        let y = Series::new(
+            "y",
+            &[
+                Some(2i32),
+                Some(5),
+                Some(1),
+                None,
+                Some(4),
+                Some(9),
+                Some(0),
+            ],
+        );
+
+        // two-pass computation over the rows without nulls
+        let pairs = [(1.0, 2.0), (2.0, 5.0), (3.0, 4.0), (7.0, 9.0), (0.0, 0.0)];
+        let n = pairs.len() as f64;
+        let mean_x = pairs.iter().map(|p| p.0).sum::<f64>() / n;
+        let mean_y = pairs.iter().map(|p| p.1).sum::<f64>() / n;
+        let c_xy: f64 = pairs.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+        let m2_x: f64 = pairs.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+        let m2_y: f64 = pairs.iter().map(|p| (p.1 - mean_y).powi(2)).sum();
+
+        let cov = stream(CovCorrAgg::new_cov(1), &x, &y);
+        assert!((cov - c_xy / (n - 1.0)).abs() < 1e-6);
+        let cov = stream(CovCorrAgg::new_cov(0), &x, &y);
+        assert!((cov - c_xy / n).abs() < 1e-6);
+        let corr = stream(CovCorrAgg::new_pearson(), &x, &y);
+        assert!((corr - c_xy / (m2_x * m2_y).sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cov_corr_empty() {
+        let x = Series::new("x", &[Some(1.0), None]);
+        let y = Series::new("y", &[None, Some(1.0)]);
+        let mut agg = CovCorrAgg::new_cov(1);
+        agg.pre_agg_ordered_pair(0, 0, 2, &x, &y).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Null);
+        assert!(agg.pre_agg_ordered(0, 0, 2, &x).is_err());
+        assert!(agg
+            .pre_agg(0, &mut [AnyValue::Float64(1.0)].into_iter())
+            .is_err());
+    }
+}
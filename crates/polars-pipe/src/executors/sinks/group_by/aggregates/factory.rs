@@ -0,0 +1,190 @@
+use polars_core::prelude::{polars_bail, DataType, PolarsResult};
+use polars_utils::IdxSize;
+
+use crate::executors::sinks::group_by::aggregates::arg_min_max::ArgMinMaxAgg;
+use crate::executors::sinks::group_by::aggregates::bitwise::BitwiseAgg;
+use crate::executors::sinks::group_by::aggregates::count::CountAgg;
+use crate::executors::sinks::group_by::aggregates::first::FirstAgg;
+use crate::executors::sinks::group_by::aggregates::last::LastAgg;
+use crate::executors::sinks::group_by::aggregates::mean::MeanAgg;
+use crate::executors::sinks::group_by::aggregates::min_max::{new_max, new_min};
+use crate::executors::sinks::group_by::aggregates::null::NullAgg;
+use crate::executors::sinks::group_by::aggregates::product::ProductAgg;
+use crate::executors::sinks::group_by::aggregates::{AggregateFunction, SumAgg};
+
+/// Creates the accumulator of an aggregation for the dtype of its input.
+///
+/// The numeric aggregations are monomorphized per physical type, [`build`](Self::build) picks
+/// the variant of [`AggregateFunction`] that matches the input, so callers don't have to match
+/// on the dtype for every aggregation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum AggregationFactory {
+    Min,
+    Max,
+    Sum,
+    Mean,
+    Product,
+    ArgMin,
+    ArgMax,
+    BitwiseAnd,
+    BitwiseOr,
+    First,
+    Last,
+    Count,
+    Len,
+}
+
+impl AggregationFactory {
+    pub(crate) fn name(&self) -> &'static str {
+        use AggregationFactory::*;
+        match self {
+            Min => "min",
+            Max => "max",
+            Sum => "sum",
+            Mean => "mean",
+            Product => "product",
+            ArgMin => "arg_min",
+            ArgMax => "arg_max",
+            BitwiseAnd => "bitwise_and",
+            BitwiseOr => "bitwise_or",
+            First => "first",
+            Last => "last",
+            Count => "count",
+            Len => "len",
+        }
+    }
+
+    /// Create an accumulator for input of the (logical) `dtype`.
+    ///
+    /// Like the eager aggregations, a `sum` or `mean` of a non-numeric dtype is null. A `min` or
+    /// `max` of one is an error, as those aren't run by the streaming engine.
+    pub(crate) fn build(&self, dtype: &DataType) -> PolarsResult<AggregateFunction> {
+        use AggregationFactory::*;
+        #[cfg(feature = "dtype-categorical")]
+        if matches!(self, Sum | Mean)
+            && matches!(dtype, DataType::Categorical(_, _) | DataType::Enum(_, _))
+        {
+            return Ok(AggregateFunction::Null(NullAgg::new(dtype.clone())));
+        }
+        // summed as the physical i64, the sum is a duration again
+        #[cfg(feature = "dtype-duration")]
+        if matches!(self, Sum) && matches!(dtype, DataType::Duration(_)) {
+            return Ok(AggregateFunction::SumI64(SumAgg::new_logical(
+                dtype.clone(),
+            )));
+        }
+
+        let agg_fn = match (self, dtype.to_physical()) {
+            (Min, DataType::Int8) => AggregateFunction::MinMaxI8(new_min()),
+            (Min, DataType::Int16) => AggregateFunction::MinMaxI16(new_min()),
+            (Min, DataType::Int32) => AggregateFunction::MinMaxI32(new_min()),
+            (Min, DataType::Int64) => AggregateFunction::MinMaxI64(new_min()),
+            (Min, DataType::UInt8) => AggregateFunction::MinMaxU8(new_min()),
+            (Min, DataType::UInt16) => AggregateFunction::MinMaxU16(new_min()),
+            (Min, DataType::UInt32) => AggregateFunction::MinMaxU32(new_min()),
+            (Min, DataType::UInt64) => AggregateFunction::MinMaxU64(new_min()),
+            (Min, DataType::Float32) => AggregateFunction::MinMaxF32(new_min()),
+            (Min, DataType::Float64) => AggregateFunction::MinMaxF64(new_min()),
+            (Max, DataType::Int8) => AggregateFunction::MinMaxI8(new_max()),
+            (Max, DataType::Int16) => AggregateFunction::MinMaxI16(new_max()),
+            (Max, DataType::Int32) => AggregateFunction::MinMaxI32(new_max()),
+            (Max, DataType::Int64) => AggregateFunction::MinMaxI64(new_max()),
+            (Max, DataType::UInt8) => AggregateFunction::MinMaxU8(new_max()),
+            (Max, DataType::UInt16) => AggregateFunction::MinMaxU16(new_max()),
+            (Max, DataType::UInt32) => AggregateFunction::MinMaxU32(new_max()),
+            (Max, DataType::UInt64) => AggregateFunction::MinMaxU64(new_max()),
+            (Max, DataType::Float32) => AggregateFunction::MinMaxF32(new_max()),
+            (Max, DataType::Float64) => AggregateFunction::MinMaxF64(new_max()),
+            (Min | Max, dt) => polars_bail!(
+                InvalidOperation: "`{}` is not supported for dtype {}", self.name(), dt
+            ),
+            // Boolean is aggregated as the IDX type.
+            (Sum, DataType::Boolean) => {
+                if std::mem::size_of::<IdxSize>() == 4 {
+                    AggregateFunction::SumU32(SumAgg::<u32>::new())
+                } else {
+                    AggregateFunction::SumU64(SumAgg::<u64>::new())
+                }
+            },
+            // these are aggregated as i64 to prevent overflow
+            (Sum, DataType::Int8 | DataType::Int16 | DataType::UInt8 | DataType::UInt16) => {
+                AggregateFunction::SumI64(SumAgg::<i64>::new())
+            },
+            // these are accumulated in 64 bits so that the sum doesn't wrap, the output
+            // schema is widened to match, see `hash_agg_output_schema`
+            (Sum, DataType::Int32) => AggregateFunction::SumI64(SumAgg::<i64>::new()),
+            (Sum, DataType::UInt32) => AggregateFunction::SumU64(SumAgg::<u64>::new()),
+            //  these stay true to there types
+            (Sum, DataType::UInt64) => AggregateFunction::SumU64(SumAgg::<u64>::new()),
+            (Sum, DataType::Int64) => AggregateFunction::SumI64(SumAgg::<i64>::new()),
+            (Sum, DataType::Float32) => AggregateFunction::SumF32(SumAgg::<f32>::new()),
+            (Sum, DataType::Float64) => AggregateFunction::SumF64(SumAgg::<f64>::new()),
+            (Mean, dt) if dt.is_integer() => AggregateFunction::MeanF64(MeanAgg::<f64>::new()),
+            (Mean, DataType::Float32) => AggregateFunction::MeanF32(MeanAgg::<f32>::new()),
+            (Mean, DataType::Float64) => AggregateFunction::MeanF64(MeanAgg::<f64>::new()),
+            (Sum | Mean, dt) => AggregateFunction::Null(NullAgg::new(dt)),
+            // like `Series::product`, integers are multiplied as 64 bit integers
+            (Product, _) => match dtype {
+                DataType::Float32 => AggregateFunction::ProductF32(ProductAgg::new()),
+                DataType::Float64 => AggregateFunction::ProductF64(ProductAgg::new()),
+                DataType::UInt64 => AggregateFunction::ProductU64(ProductAgg::new()),
+                _ => AggregateFunction::ProductI64(ProductAgg::new()),
+            },
+            (ArgMin | ArgMax, dt) => {
+                let is_min = *self == ArgMin;
+                match dt {
+                    DataType::Float32 | DataType::Float64 => {
+                        AggregateFunction::ArgMinMaxF64(ArgMinMaxAgg::new(is_min))
+                    },
+                    DataType::UInt64 => AggregateFunction::ArgMinMaxU64(ArgMinMaxAgg::new(is_min)),
+                    _ => AggregateFunction::ArgMinMaxI64(ArgMinMaxAgg::new(is_min)),
+                }
+            },
+            (BitwiseAnd | BitwiseOr, dt) => {
+                let is_and = *self == BitwiseAnd;
+                match dt {
+                    dt if dt.is_unsigned_integer() => {
+                        AggregateFunction::BitwiseU64(BitwiseAgg::new(is_and))
+                    },
+                    dt if dt.is_integer() => AggregateFunction::BitwiseI64(BitwiseAgg::new(is_and)),
+                    dt => polars_bail!(
+                        InvalidOperation: "`{}` is not supported for dtype {}", self.name(), dt
+                    ),
+                }
+            },
+            (First, dt) => AggregateFunction::First(FirstAgg::new(dt)),
+            (Last, dt) => AggregateFunction::Last(LastAgg::new(dt)),
+            (Count, _) => AggregateFunction::Count(CountAgg::new()),
+            (Len, _) => AggregateFunction::Len(CountAgg::new()),
+        };
+        Ok(agg_fn)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::{AnyValue, NamedFrom, Series};
+
+    use super::*;
+    use crate::executors::sinks::group_by::aggregates::AggregateFn;
+
+    #[test]
+    fn test_factory_build() {
+        let s = Series::new("a", &[1i32, i32::MAX, 3]);
+        let mut sum = AggregationFactory::Sum.build(s.dtype()).unwrap();
+        assert!(matches!(sum, AggregateFunction::SumI64(_)));
+        sum.pre_agg_ordered(0, 0, 3, &s).unwrap();
+        // accumulated in 64 bits
+        assert_eq!(sum.finalize(), AnyValue::Int64(i32::MAX as i64 + 4));
+
+        let s = Series::new("a", &[Some(1.0f64), None, Some(2.5)]);
+        let mut mean = AggregationFactory::Mean.build(s.dtype()).unwrap();
+        assert!(matches!(mean, AggregateFunction::MeanF64(_)));
+        mean.pre_agg_ordered(0, 0, 3, &s).unwrap();
+        assert_eq!(mean.finalize(), AnyValue::Float64(1.75));
+
+        assert!(AggregationFactory::Max.build(&DataType::String).is_err());
+        let sum = AggregationFactory::Sum.build(&DataType::String).unwrap();
+        assert!(matches!(sum, AggregateFunction::Null(_)));
+    }
+}
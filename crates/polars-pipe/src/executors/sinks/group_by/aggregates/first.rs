@@ -1,12 +1,20 @@
 use std::any::Any;
 
 use polars_core::datatypes::DataType;
-use polars_core::prelude::{AnyValue, Series};
+use polars_core::prelude::{AnyValue, PolarsResult, Series};
 use polars_utils::unwrap::UnwrapUncheckedRelease;
 
-use crate::executors::sinks::group_by::aggregates::AggregateFn;
+use crate::executors::sinks::group_by::aggregates::{downcast_other, AggregateFn};
 use crate::operators::IdxSize;
 
+/// Keeps the first non-null value of a group.
+///
+/// Applied to every column this coalesces the rows of a group. Once a value is found the
+/// following values are ignored, nulls never replace it.
+///
+/// `chunk_idx` is the sequence number of the chunk the value was found in, `combine` uses it to
+/// pick the globally first value from the partial states of different threads. Without that
+/// ordering the result would only be well-defined when aggregating single-threaded.
 pub(crate) struct FirstAgg {
     chunk_idx: IdxSize,
     first: Option<AnyValue<'static>>,
@@ -24,40 +32,56 @@ impl FirstAgg {
 }
 
 impl AggregateFn for FirstAgg {
-    fn pre_agg(&mut self, chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+    fn pre_agg(
+        &mut self,
+        chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
         let item = unsafe { item.next().unwrap_unchecked_release() };
-        if self.first.is_none() {
+        if self.first.is_none() && !matches!(item, AnyValue::Null) {
             self.chunk_idx = chunk_idx;
             self.first = Some(item.into_static().unwrap())
         }
+        Ok(())
     }
     fn pre_agg_ordered(
         &mut self,
         chunk_idx: IdxSize,
         offset: IdxSize,
-        _length: IdxSize,
+        length: IdxSize,
         values: &Series,
-    ) {
+    ) -> PolarsResult<()> {
         if self.first.is_none() {
-            self.chunk_idx = chunk_idx;
-            self.first = Some(
-                unsafe { values.get_unchecked(offset as usize) }
-                    .into_static()
-                    .unwrap(),
-            )
+            let first = (offset..offset + length)
+                .map(|i| unsafe { values.get_unchecked(i as usize) })
+                .find(|av| !matches!(av, AnyValue::Null));
+            if let Some(first) = first {
+                self.chunk_idx = chunk_idx;
+                self.first = Some(first.into_static().unwrap())
+            }
         }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "first"
     }
 
     fn dtype(&self) -> DataType {
         self.dtype.clone()
     }
 
-    fn combine(&mut self, other: &dyn Any) {
-        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+    fn output_dtype(&self, input: &DataType) -> DataType {
+        input.clone()
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
         if other.first.is_some() && other.chunk_idx < self.chunk_idx {
             self.first.clone_from(&other.first);
             self.chunk_idx = other.chunk_idx;
-        };
+        }
+        Ok(())
     }
 
     fn finalize(&mut self) -> AnyValue<'static> {
@@ -66,4 +90,60 @@ impl AggregateFn for FirstAgg {
     fn as_any(&self) -> &dyn Any {
         self
     }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_first() {
+        let s = Series::new("a", &[None, Some(1i32), Some(2)]);
+        let mut agg = FirstAgg::new(DataType::Int32);
+        agg.pre_agg_ordered(0, 0, 1, &s).unwrap();
+        agg.pre_agg_ordered(0, 1, 2, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Int32(1));
+    }
+
+    #[test]
+    fn test_first_multiple_chunks() {
+        let s = Series::new("a", &[Some(1i32), Some(2)]);
+        let mut later = FirstAgg::new(DataType::Int32);
+        later.pre_agg_ordered(1, 1, 1, &s).unwrap();
+        let mut earlier = FirstAgg::new(DataType::Int32);
+        earlier.pre_agg_ordered(0, 0, 1, &s).unwrap();
+
+        // the order in which the partial states are combined doesn't matter
+        let mut agg = FirstAgg::new(DataType::Int32);
+        agg.combine(later.as_any()).unwrap();
+        agg.combine(earlier.as_any()).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Int32(1));
+        later.combine(earlier.as_any()).unwrap();
+        assert_eq!(later.finalize(), AnyValue::Int32(1));
+    }
+
+    #[test]
+    fn test_first_null_in_earlier_batch() {
+        let s = Series::new("a", &[None, Some(1i32), Some(2)]);
+        // the first batch only has a null, the second supplies the value
+        let mut agg = FirstAgg::new(DataType::Int32);
+        agg.pre_agg_ordered(0, 0, 1, &s).unwrap();
+        agg.pre_agg_ordered(1, 1, 1, &s).unwrap();
+        agg.pre_agg(2, &mut [AnyValue::Int32(2)].into_iter())
+            .unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Int32(1));
+
+        // a partial state of an earlier batch without a value doesn't win
+        let mut earlier = FirstAgg::new(DataType::Int32);
+        earlier.pre_agg_ordered(0, 0, 1, &s).unwrap();
+        let mut later = FirstAgg::new(DataType::Int32);
+        later.pre_agg_ordered(1, 1, 2, &s).unwrap();
+        earlier.combine(later.as_any()).unwrap();
+        assert_eq!(earlier.finalize(), AnyValue::Int32(1));
+    }
 }
@@ -0,0 +1,184 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+
+/// Counts the values of a group per bin.
+///
+/// With `n` (strictly increasing) edges there are `n + 1` counts: the first counts the values
+/// below the first edge (underflow), the last the values above the last edge (overflow), and
+/// count `i` in between the values in `[edges[i - 1], edges[i])`. The last regular bin also
+/// includes the last edge, so the maximum of an equal-width histogram isn't an overflow.
+/// Nulls and NaNs aren't counted.
+///
+/// The counts are finalized to a list, the edges are the same for every group.
+pub(crate) struct HistogramAgg {
+    edges: Arc<[f64]>,
+    counts: Vec<IdxSize>,
+}
+
+impl HistogramAgg {
+    // not created by the planner yet
+    #[allow(dead_code)]
+    pub(crate) fn new(edges: Vec<f64>) -> PolarsResult<Self> {
+        polars_ensure!(
+            edges.len() >= 2 && edges.windows(2).all(|w| w[0] < w[1]),
+            ComputeError: "histogram needs at least two strictly increasing bin edges"
+        );
+        Ok(Self::with_edges(edges.into()))
+    }
+
+    /// `n_bins` bins of equal width between `min` and `max`.
+    #[allow(dead_code)]
+    pub(crate) fn new_equal_width(n_bins: usize, min: f64, max: f64) -> PolarsResult<Self> {
+        polars_ensure!(
+            n_bins > 0 && min < max,
+            ComputeError: "histogram needs at least one bin and `min` < `max`"
+        );
+        let width = (max - min) / n_bins as f64;
+        let mut edges = (0..n_bins)
+            .map(|i| min + i as f64 * width)
+            .collect::<Vec<_>>();
+        edges.push(max);
+        Self::new(edges)
+    }
+
+    fn with_edges(edges: Arc<[f64]>) -> Self {
+        HistogramAgg {
+            counts: vec![0; edges.len() + 1],
+            edges,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::with_edges(self.edges.clone())
+    }
+
+    fn insert(&mut self, val: f64) {
+        if val.is_nan() {
+            return;
+        }
+        let mut idx = self.edges.partition_point(|edge| *edge <= val);
+        if idx == self.edges.len() && val == self.edges[idx - 1] {
+            idx -= 1;
+        }
+        self.counts[idx] += 1;
+    }
+}
+
+impl AggregateFn for HistogramAgg {
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        if let Some(val) = item.extract::<f64>() {
+            self.insert(val)
+        }
+        Ok(())
+    }
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) -> PolarsResult<()> {
+        polars_ensure!(
+            values.dtype().is_numeric(),
+            SchemaMismatch: "expected a numeric dtype for `histogram`, got {}", values.dtype()
+        );
+        let values = values
+            .slice(offset as i64, length as usize)
+            .cast(&DataType::Float64)?;
+        values
+            .f64()?
+            .into_iter()
+            .flatten()
+            .for_each(|val| self.insert(val));
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "histogram"
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::List(Box::new(IDX_DTYPE))
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        polars_ensure!(
+            self.edges == other.edges,
+            ComputeError: "cannot combine histograms with different bin edges"
+        );
+        self.counts
+            .iter_mut()
+            .zip(&other.counts)
+            .for_each(|(count, other)| *count += *other);
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        let counts = std::mem::replace(&mut self.counts, vec![0; self.edges.len() + 1]);
+        AnyValue::List(IdxCa::from_vec("", counts).into_series())
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn counts(mut agg: HistogramAgg) -> Vec<IdxSize> {
+        match agg.finalize() {
+            AnyValue::List(s) => s.idx().unwrap().into_no_null_iter().collect(),
+            av => panic!("expected a list, got {av}"),
+        }
+    }
+
+    #[test]
+    fn test_histogram_batches() {
+        let s = Series::new(
+            "a",
+            &[
+                Some(-1.0),
+                Some(0.0),
+                Some(0.5),
+                None,
+                Some(1.0),
+                Some(f64::NAN),
+                Some(2.0),
+                Some(3.0),
+            ],
+        );
+        let mut agg = HistogramAgg::new(vec![0.0, 1.0, 2.0]).unwrap();
+        agg.pre_agg_ordered(0, 0, 4, &s).unwrap();
+        let mut other = agg.split();
+        other.pre_agg_ordered(1, 4, 4, &s).unwrap();
+        agg.combine(other.as_any()).unwrap();
+        // underflow, [0, 1), [1, 2], overflow
+        assert_eq!(counts(agg), vec![1, 2, 2, 1]);
+    }
+
+    #[test]
+    fn test_histogram_equal_width() {
+        let s = Series::new("a", &[1i32, 2, 3, 4, 5]);
+        let mut agg = HistogramAgg::new_equal_width(2, 1.0, 5.0).unwrap();
+        agg.pre_agg_ordered(0, 0, 5, &s).unwrap();
+        assert_eq!(counts(agg), vec![0, 2, 3, 0]);
+
+        assert!(HistogramAgg::new(vec![1.0]).is_err());
+        assert!(HistogramAgg::new(vec![1.0, 1.0]).is_err());
+        assert!(HistogramAgg::new_equal_width(0, 1.0, 5.0).is_err());
+    }
+}
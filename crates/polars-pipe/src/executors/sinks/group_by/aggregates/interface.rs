@@ -3,14 +3,31 @@ use std::any::Any;
 use enum_dispatch::enum_dispatch;
 use num_traits::NumCast;
 use polars_core::datatypes::DataType;
-use polars_core::prelude::{AnyValue, Series};
+use polars_core::prelude::{polars_bail, polars_err, AnyValue, PolarsResult, Series};
 
+use crate::executors::sinks::group_by::aggregates::any_all::{AllAgg, AnyAgg};
+#[cfg(feature = "approx_unique")]
+use crate::executors::sinks::group_by::aggregates::approx_n_unique::ApproxNUniqueAgg;
+use crate::executors::sinks::group_by::aggregates::arg_min_max::ArgMinMaxAgg;
+use crate::executors::sinks::group_by::aggregates::bitwise::BitwiseAgg;
 use crate::executors::sinks::group_by::aggregates::count::CountAgg;
+use crate::executors::sinks::group_by::aggregates::cov_corr::CovCorrAgg;
 use crate::executors::sinks::group_by::aggregates::first::FirstAgg;
+use crate::executors::sinks::group_by::aggregates::histogram::HistogramAgg;
 use crate::executors::sinks::group_by::aggregates::last::LastAgg;
 use crate::executors::sinks::group_by::aggregates::mean::MeanAgg;
 use crate::executors::sinks::group_by::aggregates::min_max::MinMaxAgg;
+use crate::executors::sinks::group_by::aggregates::mode::ModeAgg;
+#[cfg(feature = "moment")]
+use crate::executors::sinks::group_by::aggregates::moments::{KurtosisAgg, SkewAgg};
+use crate::executors::sinks::group_by::aggregates::n_unique::NUniqueAgg;
 use crate::executors::sinks::group_by::aggregates::null::NullAgg;
+use crate::executors::sinks::group_by::aggregates::product::ProductAgg;
+use crate::executors::sinks::group_by::aggregates::quantile::QuantileAgg;
+#[cfg(feature = "concat_str")]
+use crate::executors::sinks::group_by::aggregates::str_concat::StrConcatAgg;
+use crate::executors::sinks::group_by::aggregates::var::{StdAgg, VarAgg};
+use crate::executors::sinks::group_by::aggregates::weighted_mean::WeightedMeanAgg;
 use crate::executors::sinks::group_by::aggregates::SumAgg;
 use crate::operators::IdxSize;
 
@@ -19,25 +36,108 @@ pub(crate) trait AggregateFn: Send + Sync {
     fn has_physical_agg(&self) -> bool {
         false
     }
-    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>);
+    /// Aggregate the next value of `item`.
+    ///
+    /// Returns an error if the aggregation can't be fed one value at a time, e.g. because it
+    /// aggregates two columns.
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()>;
+    /// Aggregate `length` values of `values` starting at `offset`.
+    ///
+    /// Returns an error if the dtype of `values` can't be aggregated by this aggregation.
     fn pre_agg_ordered(
         &mut self,
         _chunk_idx: IdxSize,
         offset: IdxSize,
         length: IdxSize,
         values: &Series,
-    );
+    ) -> PolarsResult<()>;
+    /// Aggregate `length` values of `values` together with their `weights`, starting at `offset`.
+    ///
+    /// Only aggregations over two columns implement this, the others return an error.
+    // the sinks don't feed two columns yet
+    #[allow(dead_code)]
+    fn pre_agg_ordered_pair(
+        &mut self,
+        _chunk_idx: IdxSize,
+        _offset: IdxSize,
+        _length: IdxSize,
+        _values: &Series,
+        _weights: &Series,
+    ) -> PolarsResult<()> {
+        polars_bail!(
+            InvalidOperation: "a `{}` aggregation doesn't take a second column", self.name()
+        )
+    }
     fn pre_agg_primitive<T: NumCast>(&mut self, _chunk_idx: IdxSize, _item: Option<T>) {
         unimplemented!()
     }
 
+    /// Name of the aggregation, e.g. `"sum"`.
+    fn name(&self) -> &str;
+
+    /// Physical dtype of the aggregated value, or the logical dtype if the accumulator casts
+    /// its result back to it.
     fn dtype(&self) -> DataType;
 
-    fn combine(&mut self, other: &dyn Any);
+    /// Dtype of the result for an (logical) `input` dtype.
+    ///
+    /// This doesn't need any data, so it can be used to build the output schema up front.
+    fn output_dtype(&self, _input: &DataType) -> DataType {
+        self.dtype()
+    }
+
+    /// Merge the partial state of `other` into `self`.
+    ///
+    /// `other` must be the same aggregation as `self`, otherwise an error is returned.
+    /// Implementations downcast `other` with [`downcast_other`].
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()>;
+
+    /// Serialize the partial state, so that it can be combined in another process.
+    ///
+    /// Only the state is written, not the configuration of the aggregation. Returns an error if
+    /// the aggregation doesn't support this.
+    // no partial states are shipped to other processes yet
+    #[allow(dead_code)]
+    fn serialize(&self) -> PolarsResult<Vec<u8>> {
+        polars_bail!(
+            InvalidOperation: "cannot serialize the state of a `{}` aggregation", self.name()
+        )
+    }
+
+    /// Restore a partial state written by [`AggregateFn::serialize`], replacing the current state.
+    #[allow(dead_code)]
+    fn deserialize(&mut self, _bytes: &[u8]) -> PolarsResult<()> {
+        polars_bail!(
+            InvalidOperation: "cannot deserialize the state of a `{}` aggregation", self.name()
+        )
+    }
 
     fn finalize(&mut self) -> AnyValue<'static>;
 
     fn as_any(&self) -> &dyn Any;
+
+    /// Mutable access to the concrete aggregation.
+    ///
+    /// [`AggregateFn::as_any`] is enough to read the state of `other` in
+    /// [`AggregateFn::combine`]. This is for merges that consume or rearrange the state of
+    /// `other` in place, e.g. moving buffers out of it instead of cloning them.
+    // no merge consumes `other` yet
+    #[allow(dead_code)]
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+/// Downcast the state of an aggregation that is combined into a `T`.
+pub(crate) fn downcast_other<T: 'static>(other: &dyn Any) -> PolarsResult<&T> {
+    other.downcast_ref::<T>().ok_or_else(|| {
+        polars_err!(
+            ComputeError: "cannot combine a `{}` aggregation with a different aggregation",
+            std::any::type_name::<T>()
+        )
+    })
 }
 
 // We dispatch via an enum
@@ -48,14 +148,44 @@ pub(crate) enum AggregateFunction {
     Last(LastAgg),
     Count(CountAgg<false>),
     Len(CountAgg<true>),
+    NUnique(NUniqueAgg<true>),
+    #[cfg(feature = "approx_unique")]
+    ApproxNUnique(ApproxNUniqueAgg),
+    // not created by the planner yet, the `mode` expression returns all modes of a group
+    Mode(ModeAgg),
+    Any(AnyAgg),
+    All(AllAgg),
+    ArgMinMaxF64(ArgMinMaxAgg<f64>),
+    ArgMinMaxU64(ArgMinMaxAgg<u64>),
+    ArgMinMaxI64(ArgMinMaxAgg<i64>),
+    BitwiseU64(BitwiseAgg<u64>),
+    BitwiseI64(BitwiseAgg<i64>),
+    Var(VarAgg),
+    Std(StdAgg),
+    #[cfg(feature = "moment")]
+    Skew(SkewAgg),
+    #[cfg(feature = "moment")]
+    Kurtosis(KurtosisAgg),
+    Quantile(QuantileAgg),
+    #[cfg(feature = "concat_str")]
+    StrConcat(StrConcatAgg),
     SumF32(SumAgg<f32>),
     SumF64(SumAgg<f64>),
     SumU32(SumAgg<u32>),
     SumU64(SumAgg<u64>),
-    SumI32(SumAgg<i32>),
     SumI64(SumAgg<i64>),
+    ProductF32(ProductAgg<f32>),
+    ProductF64(ProductAgg<f64>),
+    ProductU64(ProductAgg<u64>),
+    ProductI64(ProductAgg<i64>),
     MeanF32(MeanAgg<f32>),
     MeanF64(MeanAgg<f64>),
+    // not created by the planner yet, there is no weighted mean expression
+    WeightedMean(WeightedMeanAgg),
+    // not created by the planner yet, `cov` and `pearson_corr` aren't aggregations there
+    CovCorr(CovCorrAgg),
+    // not created by the planner yet, `hist` isn't an aggregation there
+    Histogram(HistogramAgg),
     Null(NullAgg),
     MinMaxF32(MinMaxAgg<f32, fn(f32, f32) -> f32>),
     MinMaxF64(MinMaxAgg<f64, fn(f64, f64) -> f64>),
@@ -70,21 +200,52 @@ pub(crate) enum AggregateFunction {
 }
 
 impl AggregateFunction {
+    /// Create a new accumulator for another group or thread.
+    ///
+    /// The aggregation state is never carried over, so the result always behaves as a freshly
+    /// created accumulator. Configuration (the dtype of `first`/`last` or of a logical `sum`,
+    /// the `ddof` of `var`, the quantile, ...) does persist.
     pub(crate) fn split(&self) -> Self {
         use AggregateFunction::*;
         match self {
             First(agg) => First(FirstAgg::new(agg.dtype.clone())),
             Last(agg) => Last(LastAgg::new(agg.dtype.clone())),
-            SumF32(_) => SumF32(SumAgg::new()),
-            SumF64(_) => SumF64(SumAgg::new()),
-            SumU32(_) => SumU32(SumAgg::new()),
-            SumU64(_) => SumU64(SumAgg::new()),
-            SumI32(_) => SumI32(SumAgg::new()),
-            SumI64(_) => SumI64(SumAgg::new()),
+            SumF32(agg) => SumF32(agg.split()),
+            SumF64(agg) => SumF64(agg.split()),
+            SumU32(agg) => SumU32(agg.split()),
+            SumU64(agg) => SumU64(agg.split()),
+            SumI64(agg) => SumI64(agg.split()),
+            ProductF32(_) => ProductF32(ProductAgg::new()),
+            ProductF64(_) => ProductF64(ProductAgg::new()),
+            ProductU64(_) => ProductU64(ProductAgg::new()),
+            ProductI64(_) => ProductI64(ProductAgg::new()),
             MeanF32(_) => MeanF32(MeanAgg::new()),
             MeanF64(_) => MeanF64(MeanAgg::new()),
+            WeightedMean(_) => WeightedMean(WeightedMeanAgg::new()),
+            CovCorr(inner) => CovCorr(inner.split()),
+            Histogram(inner) => Histogram(inner.split()),
             Count(_) => Count(CountAgg::new()),
             Len(_) => Len(CountAgg::new()),
+            NUnique(inner) => NUnique(inner.split()),
+            #[cfg(feature = "approx_unique")]
+            ApproxNUnique(inner) => ApproxNUnique(inner.split()),
+            Mode(inner) => Mode(inner.split()),
+            Any(inner) => Any(inner.split()),
+            All(inner) => All(inner.split()),
+            ArgMinMaxF64(inner) => ArgMinMaxF64(inner.split()),
+            ArgMinMaxU64(inner) => ArgMinMaxU64(inner.split()),
+            ArgMinMaxI64(inner) => ArgMinMaxI64(inner.split()),
+            BitwiseU64(inner) => BitwiseU64(inner.split()),
+            BitwiseI64(inner) => BitwiseI64(inner.split()),
+            Var(inner) => Var(inner.split()),
+            Std(inner) => Std(inner.split()),
+            #[cfg(feature = "moment")]
+            Skew(inner) => Skew(inner.split()),
+            #[cfg(feature = "moment")]
+            Kurtosis(inner) => Kurtosis(inner.split()),
+            Quantile(inner) => Quantile(inner.split()),
+            #[cfg(feature = "concat_str")]
+            StrConcat(inner) => StrConcat(inner.split()),
             Null(a) => Null(a.clone()),
             MinMaxF32(inner) => MinMaxF32(inner.split()),
             MinMaxF64(inner) => MinMaxF64(inner.split()),
@@ -99,3 +260,99 @@ impl AggregateFunction {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+    use crate::executors::sinks::group_by::aggregates::min_max::new_min;
+
+    /// `split` of a used accumulator must give the same results as a fresh one.
+    fn check_split(mut used: AggregateFunction, mut fresh: AggregateFunction) {
+        let s = Series::new("a", &[Some(3i64), None, Some(-1), Some(3)]);
+        used.pre_agg_ordered(0, 0, 4, &s).unwrap();
+        let mut split = used.split();
+        split.pre_agg_ordered(0, 1, 3, &s).unwrap();
+        fresh.pre_agg_ordered(0, 1, 3, &s).unwrap();
+        assert_eq!(split.finalize(), fresh.finalize());
+    }
+
+    #[test]
+    fn test_split_resets_state() {
+        check_split(
+            AggregateFunction::SumF64(SumAgg::new()),
+            AggregateFunction::SumF64(SumAgg::new()),
+        );
+        check_split(
+            AggregateFunction::Count(CountAgg::new()),
+            AggregateFunction::Count(CountAgg::new()),
+        );
+        check_split(
+            AggregateFunction::MinMaxI64(new_min()),
+            AggregateFunction::MinMaxI64(new_min()),
+        );
+        check_split(
+            AggregateFunction::First(FirstAgg::new(DataType::Int64)),
+            AggregateFunction::First(FirstAgg::new(DataType::Int64)),
+        );
+        check_split(
+            AggregateFunction::MeanF64(MeanAgg::new()),
+            AggregateFunction::MeanF64(MeanAgg::new()),
+        );
+        check_split(
+            AggregateFunction::NUnique(NUniqueAgg::new()),
+            AggregateFunction::NUnique(NUniqueAgg::new()),
+        );
+        check_split(
+            AggregateFunction::Var(VarAgg::new(0)),
+            AggregateFunction::Var(VarAgg::new(0)),
+        );
+        check_split(
+            AggregateFunction::Quantile(QuantileAgg::new(
+                0.25,
+                QuantileInterpolOptions::Lower,
+                &DataType::Int64,
+            )),
+            AggregateFunction::Quantile(QuantileAgg::new(
+                0.25,
+                QuantileInterpolOptions::Lower,
+                &DataType::Int64,
+            )),
+        );
+    }
+
+    #[test]
+    fn test_output_dtype() {
+        // integer sums are promoted, see `convert`
+        let sum = AggregateFunction::SumI64(SumAgg::new());
+        assert_eq!(sum.name(), "sum");
+        assert_eq!(sum.output_dtype(&DataType::Int32), DataType::Int64);
+        let mean = AggregateFunction::MeanF64(MeanAgg::new());
+        assert_eq!(mean.output_dtype(&DataType::Int32), DataType::Float64);
+        let count = AggregateFunction::Count(CountAgg::new());
+        assert_eq!(count.name(), "count");
+        assert_eq!(count.output_dtype(&DataType::String), IDX_DTYPE);
+        let len = AggregateFunction::Len(CountAgg::new());
+        assert_eq!(len.name(), "len");
+        let min = AggregateFunction::MinMaxI32(new_min());
+        assert_eq!(min.name(), "min");
+        assert_eq!(min.output_dtype(&DataType::Int32), DataType::Int32);
+    }
+
+    #[test]
+    fn test_combine_mismatch() {
+        let mut agg = AggregateFunction::Count(CountAgg::new());
+        let other = AggregateFunction::SumF64(SumAgg::new());
+        assert!(agg.combine(other.as_any()).is_err());
+        let other = AggregateFunction::Count(CountAgg::new());
+        assert!(agg.combine(other.as_any()).is_ok());
+    }
+
+    #[test]
+    fn test_serialize_unsupported() {
+        let mut agg = AggregateFunction::MeanF64(MeanAgg::new());
+        assert!(agg.serialize().is_err());
+        assert!(agg.deserialize(&[]).is_err());
+    }
+}
@@ -1,12 +1,17 @@
 use std::any::Any;
 
 use polars_core::datatypes::DataType;
-use polars_core::prelude::{AnyValue, Series};
+use polars_core::prelude::{AnyValue, PolarsResult, Series};
 use polars_utils::unwrap::UnwrapUncheckedRelease;
 
-use crate::executors::sinks::group_by::aggregates::AggregateFn;
+use crate::executors::sinks::group_by::aggregates::{downcast_other, AggregateFn};
 use crate::operators::IdxSize;
 
+/// Keeps the last non-null value of a group.
+///
+/// `chunk_idx` is the sequence number of the chunk the value was found in, `combine` uses it to
+/// pick the globally last value from the partial states of different threads. Without that
+/// ordering the result would only be well-defined when aggregating single-threaded.
 pub(crate) struct LastAgg {
     chunk_idx: IdxSize,
     last: Option<AnyValue<'static>>,
@@ -24,10 +29,17 @@ impl LastAgg {
 }
 
 impl AggregateFn for LastAgg {
-    fn pre_agg(&mut self, chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+    fn pre_agg(
+        &mut self,
+        chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
         let item = unsafe { item.next().unwrap_unchecked_release() };
-        self.chunk_idx = chunk_idx;
-        self.last = Some(unsafe { item.into_static().unwrap_unchecked() });
+        if !matches!(item, AnyValue::Null) {
+            self.chunk_idx = chunk_idx;
+            self.last = Some(unsafe { item.into_static().unwrap_unchecked() });
+        }
+        Ok(())
     }
     fn pre_agg_ordered(
         &mut self,
@@ -35,26 +47,37 @@ impl AggregateFn for LastAgg {
         offset: IdxSize,
         length: IdxSize,
         values: &Series,
-    ) {
-        self.chunk_idx = chunk_idx;
-        self.last = Some(unsafe {
-            values
-                .get_unchecked((offset + length - 1) as usize)
-                .into_static()
-                .unwrap_unchecked()
-        })
+    ) -> PolarsResult<()> {
+        let last = (offset..offset + length)
+            .rev()
+            .map(|i| unsafe { values.get_unchecked(i as usize) })
+            .find(|av| !matches!(av, AnyValue::Null));
+        if let Some(last) = last {
+            self.chunk_idx = chunk_idx;
+            self.last = Some(unsafe { last.into_static().unwrap_unchecked() })
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "last"
     }
 
     fn dtype(&self) -> DataType {
         self.dtype.clone()
     }
 
-    fn combine(&mut self, other: &dyn Any) {
-        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+    fn output_dtype(&self, input: &DataType) -> DataType {
+        input.clone()
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
         if other.last.is_some() && other.chunk_idx >= self.chunk_idx {
             self.last.clone_from(&other.last);
             self.chunk_idx = other.chunk_idx;
-        };
+        }
+        Ok(())
     }
 
     fn finalize(&mut self) -> AnyValue<'static> {
@@ -63,4 +86,40 @@ impl AggregateFn for LastAgg {
     fn as_any(&self) -> &dyn Any {
         self
     }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_last() {
+        let s = Series::new("a", &[Some(1i32), Some(2), None]);
+        let mut agg = LastAgg::new(DataType::Int32);
+        agg.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        agg.pre_agg_ordered(0, 2, 1, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Int32(2));
+    }
+
+    #[test]
+    fn test_last_multiple_chunks() {
+        let s = Series::new("a", &[Some(1i32), Some(2)]);
+        let mut later = LastAgg::new(DataType::Int32);
+        later.pre_agg_ordered(1, 1, 1, &s).unwrap();
+        let mut earlier = LastAgg::new(DataType::Int32);
+        earlier.pre_agg_ordered(0, 0, 1, &s).unwrap();
+
+        // the order in which the partial states are combined doesn't matter
+        let mut agg = LastAgg::new(DataType::Int32);
+        agg.combine(later.as_any()).unwrap();
+        agg.combine(earlier.as_any()).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Int32(2));
+        earlier.combine(later.as_any()).unwrap();
+        assert_eq!(earlier.finalize(), AnyValue::Int32(2));
+    }
 }
@@ -50,7 +50,11 @@ where
         }
     }
 
-    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
         let item = unsafe { item.next().unwrap_unchecked_release() };
         match (item.extract::<K>(), self.sum) {
             (Some(val), Some(sum)) => {
@@ -63,6 +67,7 @@ where
             },
             _ => {},
         }
+        Ok(())
     }
 
     fn pre_agg_ordered(
@@ -71,14 +76,14 @@ where
         offset: IdxSize,
         length: IdxSize,
         values: &Series,
-    ) {
+    ) -> PolarsResult<()> {
         // we must cast because mean might be a different dtype
         let arr = unsafe {
             let arr = values.chunks().get_unchecked(0);
             arr.sliced_unchecked(offset as usize, length as usize)
         };
         let dtype = K::PolarsType::get_dtype().to_arrow(true);
-        let arr = arrow::compute::cast::cast_unchecked(arr.as_ref(), &dtype).unwrap();
+        let arr = arrow::compute::cast::cast_unchecked(arr.as_ref(), &dtype)?;
         let arr = unsafe {
             arr.as_any()
                 .downcast_ref::<PrimitiveArray<K>>()
@@ -95,14 +100,19 @@ where
             },
             _ => {},
         }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "mean"
     }
 
     fn dtype(&self) -> DataType {
         (&ArrowDataType::from(K::PRIMITIVE)).into()
     }
 
-    fn combine(&mut self, other: &dyn Any) {
-        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
         match (self.sum, other.sum) {
             (Some(lhs), Some(rhs)) => {
                 self.sum = Some(lhs + rhs);
@@ -113,7 +123,8 @@ where
                 self.count = other.count;
             },
             _ => {},
-        };
+        }
+        Ok(())
     }
 
     fn finalize(&mut self) -> AnyValue<'static> {
@@ -136,4 +147,40 @@ where
     fn as_any(&self) -> &dyn Any {
         self
     }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mean_nulls_across_batches() {
+        // integer input is averaged in the f64 state, see `convert`
+        let s = Series::new("a", &[Some(1i32), None, Some(3), None, None]);
+        let mut agg = MeanAgg::<f64>::new();
+        assert_eq!(agg.dtype(), DataType::Float64);
+        agg.pre_agg_ordered(0, 0, 3, &s).unwrap();
+        agg.pre_agg_ordered(0, 3, 2, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Float64(2.0));
+
+        let mut other = MeanAgg::<f64>::new();
+        other.pre_agg(0, &mut [AnyValue::Null].into_iter()).unwrap();
+        other
+            .pre_agg(0, &mut [AnyValue::Float64(6.0)].into_iter())
+            .unwrap();
+        agg.combine(other.as_any()).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Float64(10.0 / 3.0));
+    }
+
+    #[test]
+    fn test_mean_all_null() {
+        let s = Series::new("a", &[None::<f64>, None]);
+        let mut agg = MeanAgg::<f64>::new();
+        agg.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        agg.combine(MeanAgg::<f64>::new().as_any()).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Null);
+    }
 }
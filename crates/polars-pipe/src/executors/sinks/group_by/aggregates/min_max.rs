@@ -50,9 +50,14 @@ where
         true
     }
 
-    fn pre_agg(&mut self, chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+    fn pre_agg(
+        &mut self,
+        chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
         let item = unsafe { item.next().unwrap_unchecked_release() };
-        self.pre_agg_primitive(chunk_idx, item.extract::<K>())
+        self.pre_agg_primitive(chunk_idx, item.extract::<K>());
+        Ok(())
     }
 
     fn pre_agg_primitive<T: NumCast>(&mut self, _chunk_idx: IdxSize, item: Option<T>) {
@@ -71,7 +76,7 @@ where
         offset: IdxSize,
         length: IdxSize,
         values: &Series,
-    ) {
+    ) -> PolarsResult<()> {
         let ca: &ChunkedArray<K::PolarsType> = values.as_ref().as_ref();
         let arr = ca.downcast_iter().next().unwrap();
         let arr = unsafe { arr.slice_typed_unchecked(offset as usize, length as usize) };
@@ -82,16 +87,31 @@ where
         } else {
             arr.max_ignore_nan_kernel()
         };
-        self.pre_agg_primitive(0, agg)
+        self.pre_agg_primitive(0, agg);
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        if self.is_min {
+            "min"
+        } else {
+            "max"
+        }
     }
 
     fn dtype(&self) -> DataType {
         (&ArrowDataType::from(K::PRIMITIVE)).into()
     }
 
-    fn combine(&mut self, other: &dyn Any) {
-        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
-        self.pre_agg_primitive(0, other.agg)
+    fn output_dtype(&self, input: &DataType) -> DataType {
+        // the physical minimum is also the minimum of the logical type
+        input.clone()
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        self.pre_agg_primitive(0, other.agg);
+        Ok(())
     }
 
     fn finalize(&mut self) -> AnyValue<'static> {
@@ -104,4 +124,72 @@ where
     fn as_any(&self) -> &dyn Any {
         self
     }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_min_multiple_batches() {
+        let s = Series::new("a", &[Some(3i64), None, Some(-1), Some(5)]);
+        let mut agg = new_min::<i64>();
+        agg.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Int64(3));
+        agg.pre_agg_ordered(0, 2, 2, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Int64(-1));
+
+        let mut other = agg.split();
+        other.pre_agg_primitive(0, Some(-7i64));
+        agg.combine(other.as_any()).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Int64(-7));
+
+        // a partition that saw no values doesn't change the state
+        let other = agg.split();
+        agg.combine(other.as_any()).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Int64(-7));
+    }
+
+    #[test]
+    fn test_min_empty() {
+        let mut agg = new_min::<i64>();
+        assert_eq!(agg.finalize(), AnyValue::Null);
+
+        let s = Series::new("a", &[None::<i64>, None]);
+        agg.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        agg.combine(new_min::<i64>().as_any()).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Null);
+    }
+
+    #[test]
+    fn test_max_all_negative() {
+        // the state starts unset, so the max of negative values is not `0`
+        let s = Series::new("a", &[Some(-3i64), None, Some(-8), Some(-5)]);
+        let mut agg = new_max::<i64>();
+        agg.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        agg.pre_agg_ordered(0, 2, 2, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Int64(-3));
+
+        let mut other = agg.split();
+        other.pre_agg_primitive(0, Some(-1i64));
+        agg.combine(other.as_any()).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Int64(-1));
+
+        let s = Series::new("a", &[-2.5f64, -0.5]);
+        let mut agg = new_max::<f64>();
+        agg.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Float64(-0.5));
+    }
+
+    #[test]
+    fn test_max_empty() {
+        let mut agg = new_max::<i32>();
+        let s = Series::new("a", &[None::<i32>]);
+        agg.pre_agg_ordered(0, 0, 1, &s).unwrap();
+        agg.combine(new_max::<i32>().as_any()).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Null);
+    }
 }
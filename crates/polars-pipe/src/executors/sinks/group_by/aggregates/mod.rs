@@ -1,13 +1,31 @@
+mod any_all;
+#[cfg(feature = "approx_unique")]
+mod approx_n_unique;
+mod arg_min_max;
+mod bitwise;
 mod convert;
 mod count;
+mod cov_corr;
+mod factory;
 mod first;
+mod histogram;
 mod interface;
 mod last;
 mod mean;
 mod min_max;
+mod mode;
+#[cfg(feature = "moment")]
+mod moments;
+mod n_unique;
 mod null;
+mod product;
+mod quantile;
+#[cfg(feature = "concat_str")]
+mod str_concat;
 mod sum;
+mod var;
+mod weighted_mean;
 
 pub use convert::*;
-pub(crate) use interface::{AggregateFn, AggregateFunction};
+pub(crate) use interface::{downcast_other, AggregateFn, AggregateFunction};
 pub(crate) use sum::SumAgg;
@@ -0,0 +1,156 @@
+use std::any::Any;
+use std::cmp::Ordering;
+
+use polars_core::datatypes::{AnyValue, DataType};
+use polars_core::prelude::{PlHashMap, PolarsResult, Series};
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+use crate::operators::IdxSize;
+
+/// Most frequent (physical) value of a group.
+///
+/// Every distinct value is counted in a hash map until the group is finalized. On ties the
+/// smallest value wins, so the result doesn't depend on the order in which the partial states
+/// are combined. Nulls are skipped unless `include_nulls` is set, then a null is ordered before
+/// all values and wins a tie.
+pub(crate) struct ModeAgg {
+    counts: PlHashMap<AnyValue<'static>, IdxSize>,
+    null_count: IdxSize,
+    include_nulls: bool,
+    pub(crate) dtype: DataType,
+}
+
+impl ModeAgg {
+    pub(crate) fn new(dtype: DataType, include_nulls: bool) -> Self {
+        ModeAgg {
+            counts: PlHashMap::default(),
+            null_count: 0,
+            include_nulls,
+            dtype,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.dtype.clone(), self.include_nulls)
+    }
+
+    fn insert(&mut self, item: AnyValue) {
+        match item {
+            AnyValue::Null => self.null_count += 1,
+            item => *self.counts.entry(item.into_static().unwrap()).or_insert(0) += 1,
+        }
+    }
+}
+
+impl AggregateFn for ModeAgg {
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.insert(item);
+        Ok(())
+    }
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) -> PolarsResult<()> {
+        for i in offset..offset + length {
+            self.insert(unsafe { values.get_unchecked(i as usize) })
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "mode"
+    }
+
+    fn dtype(&self) -> DataType {
+        self.dtype.clone()
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        for (val, count) in &other.counts {
+            *self.counts.entry(val.clone()).or_insert(0) += *count;
+        }
+        self.null_count += other.null_count;
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        let mut best: Option<(AnyValue<'static>, IdxSize)> = None;
+        for (val, count) in std::mem::take(&mut self.counts) {
+            let wins = match &best {
+                None => true,
+                Some((best_val, best_count)) => {
+                    count > *best_count
+                        || (count == *best_count
+                            && val.partial_cmp(best_val) == Some(Ordering::Less))
+                },
+            };
+            if wins {
+                best = Some((val, count))
+            }
+        }
+        let null_count = std::mem::take(&mut self.null_count);
+        match best {
+            Some((_, count)) if self.include_nulls && null_count >= count => AnyValue::Null,
+            Some((val, _)) => val,
+            None => AnyValue::Null,
+        }
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    fn mode(s: &Series, mid: IdxSize, include_nulls: bool) -> AnyValue<'static> {
+        let mut agg = ModeAgg::new(s.dtype().clone(), include_nulls);
+        agg.pre_agg_ordered(0, 0, mid, s).unwrap();
+        let mut other = agg.split();
+        other
+            .pre_agg_ordered(1, mid, s.len() as IdxSize - mid, s)
+            .unwrap();
+        agg.combine(other.as_any()).unwrap();
+        agg.finalize()
+    }
+
+    #[test]
+    fn test_mode() {
+        let s = Series::new("a", &[Some(3i32), Some(1), None, Some(3), None, Some(2)]);
+        assert_eq!(mode(&s, 3, false), AnyValue::Int32(3));
+        // the null ties with 3 and is ordered first
+        assert_eq!(mode(&s, 3, true), AnyValue::Null);
+
+        let s = Series::new("a", &["b", "a", "c", "a", "b"]);
+        assert_eq!(mode(&s, 2, false), AnyValue::StringOwned("a".into()));
+    }
+
+    #[test]
+    fn test_mode_tie_across_batches() {
+        // 5 and 4 both occur twice, the tie is resolved the same for every split point
+        let s = Series::new("a", &[5i64, 4, 1, 5, 4, 2]);
+        for mid in 0..=6 {
+            assert_eq!(mode(&s, mid, false), AnyValue::Int64(4));
+        }
+
+        let s = Series::new("a", &[None::<i64>, None]);
+        assert_eq!(mode(&s, 1, false), AnyValue::Null);
+        assert_eq!(mode(&s, 1, true), AnyValue::Null);
+    }
+}
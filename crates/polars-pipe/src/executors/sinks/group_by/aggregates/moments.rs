@@ -0,0 +1,319 @@
+use std::any::Any;
+
+use polars_core::datatypes::{AnyValue, DataType};
+use polars_core::prelude::{PolarsResult, Series};
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+use crate::operators::IdxSize;
+
+/// The central moments up to the fourth, accumulated like the variance of
+/// [`VarAgg`](super::var::VarAgg): the sums of the powered deviations are updated per value
+/// and partial states are merged with the pairwise formulas of Pébay.
+#[derive(Clone, Copy, Default)]
+struct Moments {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+}
+
+impl Moments {
+    fn update(&mut self, item: AnyValue) {
+        let Some(val) = item.extract::<f64>() else {
+            return;
+        };
+        let n1 = self.count as f64;
+        self.count += 1;
+        let n = self.count as f64;
+        let delta = val - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term = delta * delta_n * n1;
+        self.mean += delta_n;
+        self.m4 += term * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2
+            - 4.0 * delta_n * self.m3;
+        self.m3 += term * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term;
+    }
+
+    fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        let (na, nb) = (self.count as f64, other.count as f64);
+        let n = na + nb;
+        let delta = other.mean - self.mean;
+        let delta2 = delta * delta;
+        let m2 = self.m2 + other.m2 + delta2 * na * nb / n;
+        let m3 = self.m3
+            + other.m3
+            + delta2 * delta * na * nb * (na - nb) / (n * n)
+            + 3.0 * delta * (na * other.m2 - nb * self.m2) / n;
+        let m4 = self.m4
+            + other.m4
+            + delta2 * delta2 * na * nb * (na * na - na * nb + nb * nb) / (n * n * n)
+            + 6.0 * delta2 * (na * na * other.m2 + nb * nb * self.m2) / (n * n)
+            + 4.0 * delta * (na * other.m3 - nb * self.m3) / n;
+        self.mean += delta * nb / n;
+        self.m2 = m2;
+        self.m3 = m3;
+        self.m4 = m4;
+        self.count += other.count;
+    }
+
+    fn pre_agg_ordered(&mut self, offset: IdxSize, length: IdxSize, values: &Series) {
+        for i in offset..offset + length {
+            self.update(unsafe { values.get_unchecked(i as usize) })
+        }
+    }
+
+    /// Same as the eager `skew`, null without values.
+    fn skew(&self, bias: bool) -> Option<f64> {
+        if self.count == 0 {
+            return None;
+        }
+        let n = self.count as f64;
+        let out = n.sqrt() * self.m3 / self.m2.powf(1.5);
+        if bias {
+            Some(out)
+        } else {
+            Some(((n - 1.0) * n).sqrt() / (n - 2.0) * out)
+        }
+    }
+
+    /// Same as the eager `kurtosis`, null without values.
+    fn kurtosis(&self, fisher: bool, bias: bool) -> Option<f64> {
+        if self.count == 0 {
+            return None;
+        }
+        let n = self.count as f64;
+        let ratio = n * self.m4 / (self.m2 * self.m2);
+        let out = if bias {
+            ratio
+        } else {
+            3.0 + 1.0 / (n - 2.0) / (n - 3.0)
+                * ((n * n - 1.0) * ratio - 3.0 * (n - 1.0) * (n - 1.0))
+        };
+        Some(if fisher { out - 3.0 } else { out })
+    }
+}
+
+/// Sample skewness, with `bias` as in the eager `skew`.
+pub(crate) struct SkewAgg {
+    moments: Moments,
+    bias: bool,
+}
+
+impl SkewAgg {
+    pub(crate) fn new(bias: bool) -> Self {
+        SkewAgg {
+            moments: Moments::default(),
+            bias,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        SkewAgg {
+            moments: Moments::default(),
+            bias: self.bias,
+        }
+    }
+}
+
+impl AggregateFn for SkewAgg {
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.moments.update(item);
+        Ok(())
+    }
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) -> PolarsResult<()> {
+        self.moments.pre_agg_ordered(offset, length, values);
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "skew"
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::Float64
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        self.moments.merge(&other.moments);
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        std::mem::take(&mut self.moments).skew(self.bias).into()
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Kurtosis, Fisher's (excess) definition or Pearson's, with `bias` as in the eager `kurtosis`.
+pub(crate) struct KurtosisAgg {
+    moments: Moments,
+    fisher: bool,
+    bias: bool,
+}
+
+impl KurtosisAgg {
+    pub(crate) fn new(fisher: bool, bias: bool) -> Self {
+        KurtosisAgg {
+            moments: Moments::default(),
+            fisher,
+            bias,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        KurtosisAgg {
+            moments: Moments::default(),
+            fisher: self.fisher,
+            bias: self.bias,
+        }
+    }
+}
+
+impl AggregateFn for KurtosisAgg {
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.moments.update(item);
+        Ok(())
+    }
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) -> PolarsResult<()> {
+        self.moments.pre_agg_ordered(offset, length, values);
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "kurtosis"
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::Float64
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        self.moments.merge(&other.moments);
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        std::mem::take(&mut self.moments)
+            .kurtosis(self.fisher, self.bias)
+            .into()
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    fn assert_close(av: AnyValue, expected: f64) {
+        let val = av.extract::<f64>().unwrap();
+        assert!((val - expected).abs() < 1e-6, "{val} != {expected}");
+    }
+
+    // the reference values are the ones of the eager `skew` and `kurtosis` (and scipy)
+    #[test]
+    fn test_skew_kurtosis_merged() {
+        let s = Series::new("a", &[1i64, 2, 3, 4, 5, 23]);
+        for (bias, expected) in [(true, 1.6727687946848508), (false, 2.2905330058490514)] {
+            let mut single = SkewAgg::new(bias);
+            single.pre_agg_ordered(0, 0, 6, &s).unwrap();
+            assert_close(single.finalize(), expected);
+
+            let mut agg = SkewAgg::new(bias);
+            agg.pre_agg_ordered(0, 0, 2, &s).unwrap();
+            let mut other = agg.split();
+            other.pre_agg_ordered(1, 2, 4, &s).unwrap();
+            agg.combine(other.as_any()).unwrap();
+            // an empty partial state doesn't change the result
+            agg.combine(SkewAgg::new(bias).as_any()).unwrap();
+            assert_close(agg.finalize(), expected);
+        }
+
+        for (fisher, bias, expected) in [
+            (true, true, 0.9945668771797536),
+            (true, false, 5.400820058440946),
+            (false, true, 3.994566877179754),
+            (false, false, 8.400820058440946),
+        ] {
+            let mut single = KurtosisAgg::new(fisher, bias);
+            single.pre_agg_ordered(0, 0, 6, &s).unwrap();
+            assert_close(single.finalize(), expected);
+
+            let mut agg = KurtosisAgg::new(fisher, bias);
+            agg.pre_agg_ordered(0, 0, 5, &s).unwrap();
+            let mut other = agg.split();
+            other.pre_agg_ordered(1, 5, 1, &s).unwrap();
+            agg.combine(other.as_any()).unwrap();
+            assert_close(agg.finalize(), expected);
+        }
+    }
+
+    #[test]
+    fn test_skew_kurtosis_nulls() {
+        let s = Series::new(
+            "a",
+            &[
+                Some(1i64),
+                Some(2),
+                Some(3),
+                None,
+                Some(1),
+                Some(2),
+                Some(3),
+            ],
+        );
+        let mut agg = KurtosisAgg::new(true, false);
+        agg.pre_agg_ordered(0, 0, 4, &s).unwrap();
+        let mut other = agg.split();
+        other.pre_agg_ordered(1, 4, 3, &s).unwrap();
+        agg.combine(other.as_any()).unwrap();
+        assert_close(agg.finalize(), -1.875);
+
+        let s = Series::new("a", &[None::<f64>]);
+        let mut agg = SkewAgg::new(true);
+        agg.pre_agg_ordered(0, 0, 1, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Null);
+    }
+}
@@ -0,0 +1,155 @@
+use std::any::Any;
+
+use polars_core::datatypes::{AnyValue, DataType};
+use polars_core::export::ahash::RandomState;
+use polars_core::prelude::{PlHashSet, PolarsResult, Series};
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+use crate::operators::IdxSize;
+
+/// Counts the distinct values of a group.
+///
+/// Every distinct (physical) value of a group is kept in a hash set until the group is
+/// finalized, so memory grows with the cardinality of the groups and not with the number of
+/// groups alone. With `INCLUDE_NULL` a null counts as a distinct value.
+///
+/// The set is randomly seeded, unless a seeded hasher is given to
+/// [`with_random_state`](Self::with_random_state). Then the values hash the same in every process.
+pub(crate) struct NUniqueAgg<const INCLUDE_NULL: bool> {
+    seen: PlHashSet<AnyValue<'static>>,
+}
+
+impl<const INCLUDE_NULL: bool> NUniqueAgg<INCLUDE_NULL> {
+    pub(crate) fn new() -> Self {
+        NUniqueAgg {
+            seen: PlHashSet::default(),
+        }
+    }
+
+    pub(crate) fn with_random_state(hb: Option<RandomState>) -> Self {
+        match hb {
+            Some(hb) => NUniqueAgg {
+                seen: PlHashSet::with_hasher(hb),
+            },
+            None => Self::new(),
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::with_random_state(Some(self.seen.hasher().clone()))
+    }
+
+    fn insert(&mut self, item: AnyValue) {
+        if INCLUDE_NULL || !matches!(item, AnyValue::Null) {
+            self.seen.insert(item.into_static().unwrap());
+        }
+    }
+}
+
+impl<const INCLUDE_NULL: bool> AggregateFn for NUniqueAgg<INCLUDE_NULL> {
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.insert(item);
+        Ok(())
+    }
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) -> PolarsResult<()> {
+        for i in offset..offset + length {
+            self.insert(unsafe { values.get_unchecked(i as usize) })
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "n_unique"
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::UInt64
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        self.seen.extend(other.seen.iter().cloned());
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        AnyValue::UInt64(std::mem::take(&mut self.seen).len() as u64)
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+    use crate::executors::sinks::group_by::seeded_random_state;
+
+    #[test]
+    fn test_n_unique_overlapping_batches() {
+        let s = Series::new("a", &[Some(1i32), Some(2), None, Some(2), Some(3), None]);
+        let mut agg = NUniqueAgg::<true>::new();
+        agg.pre_agg_ordered(0, 0, 3, &s).unwrap();
+        let mut other = NUniqueAgg::<true>::new();
+        other.pre_agg_ordered(1, 3, 3, &s).unwrap();
+        agg.combine(other.as_any()).unwrap();
+        // 1, 2, 3 and null
+        assert_eq!(agg.finalize(), AnyValue::UInt64(4));
+
+        let mut agg = NUniqueAgg::<false>::new();
+        agg.pre_agg_ordered(0, 0, 3, &s).unwrap();
+        let mut other = NUniqueAgg::<false>::new();
+        other.pre_agg_ordered(1, 3, 3, &s).unwrap();
+        agg.combine(other.as_any()).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::UInt64(3));
+    }
+
+    #[test]
+    fn test_n_unique_seeded_partitioning() {
+        let n_partitions = 8;
+        let partitions = |agg: &NUniqueAgg<true>| {
+            let mut partitions = vec![vec![]; n_partitions];
+            for val in &agg.seen {
+                let hash = agg.seen.hasher().hash_one(val);
+                partitions[hash as usize % n_partitions].push(val.extract::<i64>());
+            }
+            partitions.iter_mut().for_each(|p| p.sort());
+            partitions
+        };
+
+        let values = (0..100i64)
+            .map(|i| (i % 7 != 0).then_some(i * 31))
+            .collect::<Vec<_>>();
+        // a permutation of the same values
+        let shuffled = (0..100).map(|i| values[(i * 37) % 100]).collect::<Vec<_>>();
+        let mut left = NUniqueAgg::<true>::with_random_state(Some(seeded_random_state(42)));
+        left.pre_agg_ordered(0, 0, 100, &Series::new("a", &values))
+            .unwrap();
+        // another process, a split accumulator keeps the hasher
+        let other = NUniqueAgg::<true>::with_random_state(Some(seeded_random_state(42)));
+        let mut right = other.split();
+        right
+            .pre_agg_ordered(0, 0, 100, &Series::new("a", &shuffled))
+            .unwrap();
+
+        assert_eq!(partitions(&left), partitions(&right));
+        assert_eq!(left.finalize(), right.finalize());
+    }
+}
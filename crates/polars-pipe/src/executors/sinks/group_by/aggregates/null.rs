@@ -14,8 +14,12 @@ impl NullAgg {
 }
 
 impl AggregateFn for NullAgg {
-    fn pre_agg(&mut self, _chunk_idx: IdxSize, _item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
-        // no-op
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        _item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
+        Ok(())
     }
     fn pre_agg_ordered(
         &mut self,
@@ -23,16 +27,20 @@ impl AggregateFn for NullAgg {
         _offset: IdxSize,
         _length: IdxSize,
         _values: &Series,
-    ) {
-        // no-op
+    ) -> PolarsResult<()> {
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "null"
     }
 
     fn dtype(&self) -> DataType {
         self.0.clone()
     }
 
-    fn combine(&mut self, _other: &dyn Any) {
-        // no-op
+    fn combine(&mut self, _other: &dyn Any) -> PolarsResult<()> {
+        Ok(())
     }
 
     fn finalize(&mut self) -> AnyValue<'static> {
@@ -42,4 +50,7 @@ impl AggregateFn for NullAgg {
     fn as_any(&self) -> &dyn Any {
         self
     }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
 }
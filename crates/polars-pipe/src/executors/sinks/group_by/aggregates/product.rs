@@ -0,0 +1,146 @@
+use std::any::Any;
+
+use arrow::array::PrimitiveArray;
+use polars_core::export::num::NumCast;
+use polars_core::prelude::*;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+
+/// Product of the non-null values of a group.
+///
+/// Small integers are multiplied as `i64` (see `convert_to_hash_agg`) to postpone overflow. A
+/// group without any non-null value has no product and is finalized to null, not to the
+/// multiplicative identity.
+pub(crate) struct ProductAgg<K: NumericNative> {
+    product: Option<K>,
+}
+
+impl<K: NumericNative> ProductAgg<K> {
+    pub(crate) fn new() -> Self {
+        ProductAgg { product: None }
+    }
+
+    #[inline]
+    fn mul(&mut self, val: K) {
+        self.product = Some(self.product.unwrap_or(K::one()) * val);
+    }
+}
+
+impl<K: NumericNative> AggregateFn for ProductAgg<K> {
+    fn has_physical_agg(&self) -> bool {
+        true
+    }
+
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.pre_agg_primitive(0, item.extract::<K>());
+        Ok(())
+    }
+    fn pre_agg_primitive<T: NumCast>(&mut self, _chunk_idx: IdxSize, item: Option<T>) {
+        if let Some(val) = item {
+            self.mul(K::from(val).unwrap())
+        }
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) -> PolarsResult<()> {
+        polars_ensure!(
+            values.dtype().is_numeric() || values.dtype().is_bool(),
+            SchemaMismatch: "expected dtype {} for `product`, got {}", self.dtype(), values.dtype()
+        );
+        let arr = unsafe {
+            let arr = values.chunks().get_unchecked(0);
+            arr.sliced_unchecked(offset as usize, length as usize)
+        };
+        let dtype = K::PolarsType::get_dtype().to_arrow(true);
+        let arr = arrow::compute::cast::cast_unchecked(arr.as_ref(), &dtype)?;
+        let arr = unsafe {
+            arr.as_any()
+                .downcast_ref::<PrimitiveArray<K>>()
+                .unwrap_unchecked_release()
+        };
+        arr.iter().flatten().for_each(|val| self.mul(*val));
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "product"
+    }
+
+    fn dtype(&self) -> DataType {
+        (&ArrowDataType::from(K::PRIMITIVE)).into()
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        if let Some(val) = other.product {
+            self.mul(val);
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        self.product.take().into()
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_product() {
+        let s = Series::new("a", &[Some(2i32), None, Some(-3), Some(-4)]);
+        let mut agg = ProductAgg::<i64>::new();
+        agg.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        let mut other = ProductAgg::<i64>::new();
+        other.pre_agg_ordered(0, 2, 2, &s).unwrap();
+        agg.combine(other.as_any()).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Int64(24));
+
+        let s = Series::new("a", &[-1.5f64, -2.0, 0.0, 4.0]);
+        let mut agg = ProductAgg::<f64>::new();
+        agg.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Float64(3.0));
+        agg.pre_agg_ordered(0, 0, 4, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Float64(0.0));
+    }
+
+    #[test]
+    fn test_product_zero() {
+        let s = Series::new("a", &[3i64, 0, 5]);
+        let mut agg = ProductAgg::<i64>::new();
+        agg.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        let mut other = ProductAgg::<i64>::new();
+        other.pre_agg_ordered(0, 2, 1, &s).unwrap();
+        agg.combine(other.as_any()).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Int64(0));
+    }
+
+    #[test]
+    fn test_product_empty() {
+        let s = Series::new("a", &[None::<i64>, None]);
+        let mut agg = ProductAgg::<i64>::new();
+        // an empty batch
+        agg.pre_agg_ordered(0, 0, 0, &s).unwrap();
+        agg.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        agg.combine(ProductAgg::<i64>::new().as_any()).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Null);
+    }
+}
@@ -0,0 +1,216 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+
+/// Exact quantile of a group.
+///
+/// An exact quantile needs all values of a group, so every non-null value is buffered (as `f64`)
+/// until the group is finalized. Memory therefore grows with the number of rows, not with the
+/// number of groups.
+///
+/// The quantile is computed by the same kernel as `Series::quantile_as_series`, so the result
+/// is the one of the default engine, also for a `Float32` input, which gives a `Float32`. It
+/// can differ in the last bits: the nulls aren't buffered, while the default engine may
+/// interpolate at an index that is offset by the number of nulls. Integers are buffered as
+/// `f64`, so integers above 2^53 in magnitude are rounded before they are interpolated.
+pub(crate) struct QuantileAgg {
+    values: Vec<f64>,
+    quantile: f64,
+    interpol: QuantileInterpolOptions,
+    /// The input is `Float32`, so is the output
+    float32: bool,
+}
+
+impl QuantileAgg {
+    pub(crate) fn new(quantile: f64, interpol: QuantileInterpolOptions, input: &DataType) -> Self {
+        debug_assert!((0.0..=1.0).contains(&quantile));
+        QuantileAgg {
+            values: vec![],
+            quantile,
+            interpol,
+            float32: matches!(input, DataType::Float32),
+        }
+    }
+
+    pub(crate) fn new_median(input: &DataType) -> Self {
+        Self::new(0.5, QuantileInterpolOptions::Linear, input)
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        QuantileAgg {
+            values: vec![],
+            ..*self
+        }
+    }
+
+    fn update(&mut self, item: AnyValue) {
+        if let Some(val) = item.extract::<f64>() {
+            self.values.push(val)
+        }
+    }
+}
+
+impl AggregateFn for QuantileAgg {
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.update(item);
+        Ok(())
+    }
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) -> PolarsResult<()> {
+        for i in offset..offset + length {
+            self.update(unsafe { values.get_unchecked(i as usize) })
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "quantile"
+    }
+
+    fn dtype(&self) -> DataType {
+        if self.float32 {
+            DataType::Float32
+        } else {
+            DataType::Float64
+        }
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        self.values.extend_from_slice(&other.values);
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        let ca = Float64Chunked::from_vec("", std::mem::take(&mut self.values));
+        // the quantile is checked when the aggregation is created
+        let out = ca.quantile(self.quantile, self.interpol).unwrap();
+        if self.float32 {
+            // like `ChunkQuantile` for `Float32Chunked`: interpolated in f64, then narrowed
+            out.map(|v| v as f32).into()
+        } else {
+            out.into()
+        }
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn stream(s: &Series, quantile: f64, interpol: QuantileInterpolOptions) -> AnyValue<'static> {
+        let mid = s.len() as IdxSize / 2;
+        let mut agg = QuantileAgg::new(quantile, interpol, s.dtype());
+        agg.pre_agg_ordered(0, 0, mid, s).unwrap();
+        let mut other = agg.split();
+        other
+            .pre_agg_ordered(1, mid, s.len() as IdxSize - mid, s)
+            .unwrap();
+        agg.combine(other.as_any()).unwrap();
+        agg.finalize()
+    }
+
+    fn quantile(values: &[i32], quantile: f64, interpol: QuantileInterpolOptions) -> AnyValue {
+        stream(&Series::new("a", values), quantile, interpol)
+    }
+
+    #[test]
+    fn test_median() {
+        let s = Series::new("a", &[Some(3i32), None, Some(1), Some(2)]);
+        let mut agg = QuantileAgg::new_median(s.dtype());
+        agg.pre_agg_ordered(0, 0, 4, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Float64(2.0));
+
+        let s = Series::new("a", &[4i32, 1, 3, 2]);
+        let mut agg = QuantileAgg::new_median(s.dtype());
+        agg.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        let mut other = agg.split();
+        other.pre_agg_ordered(1, 2, 2, &s).unwrap();
+        agg.combine(other.as_any()).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Float64(2.5));
+
+        assert_eq!(
+            QuantileAgg::new_median(&DataType::Int32).finalize(),
+            AnyValue::Null
+        );
+    }
+
+    #[test]
+    fn test_quantile_interpolation() {
+        use QuantileInterpolOptions::*;
+        let values = [5, 1, 4, 2, 3];
+        assert_eq!(quantile(&values, 0.4375, Nearest), AnyValue::Float64(3.0));
+        assert_eq!(quantile(&values, 0.4375, Lower), AnyValue::Float64(2.0));
+        assert_eq!(quantile(&values, 0.4375, Higher), AnyValue::Float64(3.0));
+        assert_eq!(quantile(&values, 0.4375, Midpoint), AnyValue::Float64(2.5));
+        assert_eq!(quantile(&values, 0.4375, Linear), AnyValue::Float64(2.75));
+        // even length
+        let values = [4, 1, 3, 2];
+        assert_eq!(quantile(&values, 0.5, Lower), AnyValue::Float64(2.0));
+        assert_eq!(quantile(&values, 0.5, Higher), AnyValue::Float64(3.0));
+        assert_eq!(quantile(&values, 0.5, Linear), AnyValue::Float64(2.5));
+    }
+
+    #[test]
+    fn test_quantile_against_eager() {
+        use QuantileInterpolOptions::*;
+        let inputs = [
+            Series::new("a", &[5i32, 1, 4, 2, 3]),
+            Series::new(
+                "a",
+                &[Some(7i64), None, Some(-3), Some(7), None, Some(0), Some(12)],
+            ),
+            Series::new("a", &[1u32, 1, 1, 9]),
+            Series::new(
+                "a",
+                &[Some(0.1f64), Some(f64::NAN), None, Some(-2.5), Some(1e10)],
+            ),
+            Series::new("a", &[0.1f32, 0.7, 0.3, 0.2, 1e-3, 5.5]),
+            // sorted, so the eager kernel doesn't take the quickselect path
+            Series::new("a", &[Some(3.0f64), None, Some(1.0), Some(2.0)]).sort(false, false),
+            Series::new("a", &[42i64]),
+            Series::new("a", &[None::<f64>, None]),
+        ];
+        for s in &inputs {
+            for interpol in [Nearest, Lower, Higher, Midpoint, Linear] {
+                for q in [0.0, 0.1, 0.25, 1.0 / 3.0, 0.5, 0.75, 0.9, 1.0] {
+                    let eager_s = s.quantile_as_series(q, interpol).unwrap();
+                    let eager = eager_s.get(0).unwrap();
+                    let streaming = stream(s, q, interpol);
+                    // NaN is sorted last, so it is the result of the upper quantiles
+                    let same = match (streaming.extract::<f64>(), eager.extract::<f64>()) {
+                        (Some(a), Some(b)) => {
+                            (a - b).abs() <= 1e-6 * b.abs().max(1.0) || (a.is_nan() && b.is_nan())
+                        },
+                        (a, b) => a.is_none() && b.is_none(),
+                    };
+                    assert!(
+                        same && streaming.dtype() == eager.dtype(),
+                        "{s:?}, q = {q}, {interpol:?}: {streaming} != {eager}"
+                    );
+                    let agg = QuantileAgg::new(q, interpol, s.dtype());
+                    assert_eq!(&agg.dtype(), eager_s.dtype());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use polars_core::datatypes::{AnyValue, DataType};
+use polars_core::prelude::{PolarsResult, Series};
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+use crate::operators::IdxSize;
+
+/// Concatenates the string values of a group with a delimiter, like `str.concat`.
+///
+/// The values are concatenated per chunk and kept together with the `chunk_idx` of that chunk,
+/// so that `finalize` can restore the original order after `combine` merged the partial states
+/// of different threads.
+pub(crate) struct StrConcatAgg {
+    parts: Vec<(IdxSize, String)>,
+    delimiter: Arc<str>,
+    ignore_nulls: bool,
+    has_null: bool,
+}
+
+impl StrConcatAgg {
+    pub(crate) fn new(delimiter: Arc<str>, ignore_nulls: bool) -> Self {
+        StrConcatAgg {
+            parts: vec![],
+            delimiter,
+            ignore_nulls,
+            has_null: false,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.delimiter.clone(), self.ignore_nulls)
+    }
+
+    fn push(&mut self, chunk_idx: IdxSize, val: &str) {
+        match self.parts.last_mut() {
+            Some((idx, part)) if *idx == chunk_idx => {
+                part.push_str(&self.delimiter);
+                part.push_str(val);
+            },
+            _ => self.parts.push((chunk_idx, val.to_string())),
+        }
+    }
+
+    fn update(&mut self, chunk_idx: IdxSize, item: AnyValue) {
+        match item {
+            AnyValue::String(val) => self.push(chunk_idx, val),
+            AnyValue::StringOwned(val) => self.push(chunk_idx, &val),
+            _ => self.has_null = true,
+        }
+    }
+}
+
+impl AggregateFn for StrConcatAgg {
+    fn pre_agg(
+        &mut self,
+        chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.update(chunk_idx, item);
+        Ok(())
+    }
+    fn pre_agg_ordered(
+        &mut self,
+        chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) -> PolarsResult<()> {
+        for i in offset..offset + length {
+            self.update(chunk_idx, unsafe { values.get_unchecked(i as usize) })
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "str_concat"
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::String
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        self.parts.extend_from_slice(&other.parts);
+        self.has_null |= other.has_null;
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        let mut parts = std::mem::take(&mut self.parts);
+        if std::mem::take(&mut self.has_null) && !self.ignore_nulls {
+            return AnyValue::Null;
+        }
+        // stable, so values of the same chunk keep their order
+        parts.sort_by_key(|(chunk_idx, _)| *chunk_idx);
+        let out = parts
+            .into_iter()
+            .map(|(_, part)| part)
+            .collect::<Vec<_>>()
+            .join(&self.delimiter);
+        AnyValue::StringOwned(out.into())
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_str_concat() {
+        let s = Series::new("a", &[Some("a"), None, Some("b"), Some("c")]);
+        let mut later = StrConcatAgg::new(", ".into(), true);
+        later.pre_agg_ordered(1, 2, 2, &s).unwrap();
+        let mut earlier = later.split();
+        earlier.pre_agg_ordered(0, 0, 2, &s).unwrap();
+
+        // the partial states are combined out of order
+        later.combine(earlier.as_any()).unwrap();
+        assert_eq!(later.finalize(), AnyValue::StringOwned("a, b, c".into()));
+
+        let mut agg = StrConcatAgg::new("-".into(), true);
+        agg.pre_agg_ordered(0, 1, 1, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::StringOwned("".into()));
+    }
+
+    #[test]
+    fn test_str_concat_nulls() {
+        let s = Series::new("a", &[Some("a"), None, Some("b")]);
+        let mut agg = StrConcatAgg::new("-".into(), false);
+        agg.pre_agg_ordered(0, 0, 3, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Null);
+
+        let mut agg = StrConcatAgg::new("".into(), true);
+        agg.pre_agg_ordered(0, 0, 3, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::StringOwned("ab".into()));
+    }
+}
@@ -4,6 +4,7 @@ use std::ops::Add;
 use arrow::array::PrimitiveArray;
 use arrow::compute::aggregate::Sum;
 use arrow::types::simd::Simd;
+use polars_core::export::arrow::datatypes::PrimitiveType;
 use polars_core::export::num::NumCast;
 use polars_core::prelude::*;
 use polars_core::utils::arrow::compute::aggregate::sum_primitive;
@@ -13,11 +14,72 @@ use super::*;
 
 pub struct SumAgg<K: NumericNative> {
     sum: Option<K>,
+    // the low-order bits lost in the float `sum` (Kahan-Babuška), always zero for integers
+    compensation: K,
+    // the logical dtype of the input, a `Duration`, the physical values are summed and
+    // `finalize` returns the sum as that dtype
+    logical_dtype: Option<DataType>,
+}
+
+#[inline]
+fn is_float<K: NumericNative>() -> bool {
+    matches!(
+        K::PRIMITIVE,
+        PrimitiveType::Float32 | PrimitiveType::Float64
+    )
+}
+
+#[inline]
+fn abs<K: NumericNative>(v: K) -> K {
+    if v < K::zero() {
+        K::zero() - v
+    } else {
+        v
+    }
 }
 
 impl<K: NumericNative> SumAgg<K> {
     pub(crate) fn new() -> Self {
-        SumAgg { sum: None }
+        SumAgg {
+            sum: None,
+            compensation: K::zero(),
+            logical_dtype: None,
+        }
+    }
+
+    /// Sum the physical values of a logical `dtype` and return the sum as that `dtype`.
+    #[cfg(feature = "dtype-duration")]
+    pub(crate) fn new_logical(dtype: DataType) -> Self {
+        SumAgg {
+            logical_dtype: Some(dtype),
+            ..Self::new()
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        SumAgg {
+            logical_dtype: self.logical_dtype.clone(),
+            ..Self::new()
+        }
+    }
+
+    #[inline]
+    fn add(&mut self, val: K) {
+        match self.sum {
+            Some(sum) => {
+                let new = sum + val;
+                // a non-finite sum would turn the compensation into NaN
+                if is_float::<K>() && new.is_finite() {
+                    self.compensation += if abs(sum) >= abs(val) {
+                        (sum - new) + val
+                    } else {
+                        (val - new) + sum
+                    };
+                }
+                self.sum = Some(new);
+            },
+            None => self.sum = Some(val),
+        }
     }
 }
 
@@ -31,15 +93,18 @@ where
         true
     }
 
-    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
         let item = unsafe { item.next().unwrap_unchecked_release() };
-        self.pre_agg_primitive(0, item.extract::<K>())
+        self.pre_agg_primitive(0, item.extract::<K>());
+        Ok(())
     }
     fn pre_agg_primitive<T: NumCast>(&mut self, _chunk_idx: IdxSize, item: Option<T>) {
-        match (item.map(|v| K::from(v).unwrap()), self.sum) {
-            (Some(val), Some(sum)) => self.sum = Some(sum + val),
-            (Some(val), None) => self.sum = Some(val),
-            (None, _) => {},
+        if let Some(val) = item {
+            self.add(K::from(val).unwrap())
         }
     }
 
@@ -49,53 +114,234 @@ where
         offset: IdxSize,
         length: IdxSize,
         values: &Series,
-    ) {
+    ) -> PolarsResult<()> {
+        let values = values.to_physical_repr();
+        // booleans are summed as the idx type
+        polars_ensure!(
+            values.dtype().is_numeric() || values.dtype().is_bool(),
+            SchemaMismatch: "expected dtype {} for `sum`, got {}", self.dtype(), values.dtype()
+        );
         // we must cast because sum output type might be different than input type.
         let arr = unsafe {
             let arr = values.chunks().get_unchecked(0);
             arr.sliced_unchecked(offset as usize, length as usize)
         };
         let dtype = K::PolarsType::get_dtype().to_arrow(true);
-        let arr = arrow::compute::cast::cast_unchecked(arr.as_ref(), &dtype).unwrap();
+        let arr = arrow::compute::cast::cast_unchecked(arr.as_ref(), &dtype)?;
         let arr = unsafe {
             arr.as_any()
                 .downcast_ref::<PrimitiveArray<K>>()
                 .unwrap_unchecked_release()
         };
-        match (sum_primitive(arr), self.sum) {
-            (Some(val), Some(sum)) => {
-                self.sum = Some(sum + val);
-            },
-            (Some(val), None) => {
-                self.sum = Some(val);
-            },
-            _ => {},
+        if is_float::<K>() {
+            // compensate per value, a batch sum has already lost the precision
+            arr.iter().flatten().for_each(|val| self.add(*val))
+        } else if let Some(val) = sum_primitive(arr) {
+            self.add(val)
         }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "sum"
     }
 
     fn dtype(&self) -> DataType {
-        (&ArrowDataType::from(K::PRIMITIVE)).into()
+        match &self.logical_dtype {
+            Some(dtype) => dtype.clone(),
+            None => (&ArrowDataType::from(K::PRIMITIVE)).into(),
+        }
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        if let Some(val) = other.sum {
+            self.add(val);
+            self.compensation += other.compensation;
+        }
+        Ok(())
+    }
+
+    // Layout: a validity byte, followed by `sum` and `compensation` in little endian.
+    fn serialize(&self) -> PolarsResult<Vec<u8>> {
+        let mut out = Vec::with_capacity(1 + 2 * std::mem::size_of::<K>());
+        out.push(self.sum.is_some() as u8);
+        out.extend_from_slice(self.sum.unwrap_or_default().to_le_bytes().as_ref());
+        out.extend_from_slice(self.compensation.to_le_bytes().as_ref());
+        Ok(out)
     }
 
-    fn combine(&mut self, other: &dyn Any) {
-        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
-        let sum = match (self.sum, other.sum) {
-            (Some(lhs), Some(rhs)) => Some(lhs + rhs),
-            (Some(lhs), None) => Some(lhs),
-            (None, Some(rhs)) => Some(rhs),
-            (None, None) => None,
+    fn deserialize(&mut self, bytes: &[u8]) -> PolarsResult<()> {
+        let size = std::mem::size_of::<K>();
+        polars_ensure!(
+            bytes.len() == 1 + 2 * size && bytes[0] <= 1,
+            ComputeError: "invalid serialized state for a `sum` of {}", self.dtype()
+        );
+        let read = |bytes: &[u8]| {
+            let mut buf = K::Bytes::default();
+            buf.as_mut().copy_from_slice(bytes);
+            K::from_le_bytes(buf)
         };
-        self.sum = sum;
+        self.sum = (bytes[0] == 1).then(|| read(&bytes[1..1 + size]));
+        self.compensation = read(&bytes[1 + size..]);
+        Ok(())
     }
 
     fn finalize(&mut self) -> AnyValue<'static> {
+        // a group that only saw nulls has no sum
         if let Some(val) = self.sum {
-            val.into()
+            let sum = val + self.compensation;
+            match &self.logical_dtype {
+                #[cfg(feature = "dtype-duration")]
+                Some(DataType::Duration(tu)) => {
+                    AnyValue::Duration(NumCast::from(sum).unwrap(), *tu)
+                },
+                _ => sum.into(),
+            }
         } else {
-            K::zero().into()
+            AnyValue::Null
         }
     }
     fn as_any(&self) -> &dyn Any {
         self
     }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sum_all_null() {
+        let s = Series::new("a", &[None::<i64>, None]);
+        let mut agg = SumAgg::<i64>::new();
+        agg.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        let mut other = SumAgg::<i64>::new();
+        other.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        agg.combine(other.as_any()).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Null);
+
+        let mut other = SumAgg::<i64>::new();
+        other
+            .pre_agg(0, &mut [AnyValue::Int64(3)].into_iter())
+            .unwrap();
+        agg.combine(other.as_any()).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Int64(3));
+    }
+
+    #[test]
+    fn test_sum_i32_no_overflow() {
+        let s = Series::new("a", &[i32::MAX, i32::MAX, i32::MAX]);
+        let mut agg = SumAgg::<i64>::new();
+        agg.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        let mut other = SumAgg::<i64>::new();
+        other.pre_agg_ordered(0, 2, 1, &s).unwrap();
+        agg.combine(other.as_any()).unwrap();
+        assert_eq!(agg.dtype(), DataType::Int64);
+        assert_eq!(agg.finalize(), AnyValue::Int64(3 * i32::MAX as i64));
+    }
+
+    #[test]
+    fn test_sum_compensated() {
+        let mut values = vec![1.0f64];
+        values.extend(std::iter::repeat(1e-16).take(10_000));
+        let naive: f64 = values.iter().sum();
+        assert_eq!(naive, 1.0);
+
+        let s = Series::new("a", &values);
+        let mut agg = SumAgg::<f64>::new();
+        agg.pre_agg_ordered(0, 0, 5_000, &s).unwrap();
+        let mut other = SumAgg::<f64>::new();
+        other
+            .pre_agg_ordered(0, 5_000, values.len() as IdxSize - 5_000, &s)
+            .unwrap();
+        agg.combine(other.as_any()).unwrap();
+        let AnyValue::Float64(sum) = agg.finalize() else {
+            unreachable!()
+        };
+        assert!((sum - (1.0 + 1e-12)).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_sum_serialize_round_trip() {
+        let s = Series::new("a", &[Some(1i64), None, Some(2), Some(3)]);
+        let mut remote = SumAgg::<i64>::new();
+        remote.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        let bytes = remote.serialize().unwrap();
+
+        let mut restored = SumAgg::<i64>::new();
+        restored.deserialize(&bytes).unwrap();
+        let mut local = SumAgg::<i64>::new();
+        local.pre_agg_ordered(0, 2, 2, &s).unwrap();
+        local.combine(restored.as_any()).unwrap();
+        assert_eq!(local.finalize(), AnyValue::Int64(6));
+
+        // a state without values stays null
+        let mut restored = SumAgg::<i64>::new();
+        restored
+            .deserialize(&SumAgg::<i64>::new().serialize().unwrap())
+            .unwrap();
+        assert_eq!(restored.finalize(), AnyValue::Null);
+
+        assert!(restored.deserialize(&bytes[1..]).is_err());
+    }
+
+    #[test]
+    fn test_sum_serialize_compensation() {
+        let mut values = vec![1.0f64];
+        values.extend(std::iter::repeat(1e-16).take(10_000));
+        let s = Series::new("a", &values);
+        let mut remote = SumAgg::<f64>::new();
+        remote.pre_agg_ordered(0, 0, 5_000, &s).unwrap();
+        assert_ne!(remote.compensation, 0.0);
+
+        let mut restored = SumAgg::<f64>::new();
+        restored.deserialize(&remote.serialize().unwrap()).unwrap();
+        assert_eq!(restored.sum, remote.sum);
+        assert_eq!(restored.compensation, remote.compensation);
+
+        let mut local = SumAgg::<f64>::new();
+        local
+            .pre_agg_ordered(0, 5_000, values.len() as IdxSize - 5_000, &s)
+            .unwrap();
+        local.combine(restored.as_any()).unwrap();
+        let AnyValue::Float64(sum) = local.finalize() else {
+            unreachable!()
+        };
+        assert!((sum - (1.0 + 1e-12)).abs() < 1e-15);
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-duration")]
+    fn test_sum_duration() {
+        let s = Series::new("a", &[Some(1_000i64), None, Some(250), Some(-50)])
+            .cast(&DataType::Duration(TimeUnit::Milliseconds))
+            .unwrap();
+        let mut agg = SumAgg::<i64>::new_logical(s.dtype().clone());
+        agg.pre_agg_ordered(0, 0, 2, &s).unwrap();
+        let mut other = agg.split();
+        other.pre_agg_ordered(1, 2, 2, &s).unwrap();
+        agg.combine(other.as_any()).unwrap();
+        assert_eq!(agg.dtype(), DataType::Duration(TimeUnit::Milliseconds));
+        assert_eq!(
+            agg.finalize(),
+            AnyValue::Duration(1_200, TimeUnit::Milliseconds)
+        );
+
+        let mut empty = agg.split();
+        assert_eq!(empty.finalize(), AnyValue::Null);
+    }
+
+    #[test]
+    fn test_sum_dtype_mismatch() {
+        let s = Series::new("a", &["1", "2"]);
+        let mut agg = SumAgg::<i64>::new();
+        let err = agg.pre_agg_ordered(0, 0, 2, &s).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("expected dtype i64 for `sum`, got str"));
+    }
 }
@@ -0,0 +1,218 @@
+use std::any::Any;
+
+use polars_core::datatypes::{AnyValue, DataType};
+use polars_core::prelude::{PolarsResult, Series};
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+use crate::operators::IdxSize;
+
+/// Variance with Welford's online algorithm, partial states are merged with the
+/// parallel algorithm of Chan et al. so that the result stays numerically stable.
+pub(crate) struct VarAgg {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    ddof: u8,
+}
+
+impl VarAgg {
+    pub(crate) fn new(ddof: u8) -> Self {
+        VarAgg {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            ddof,
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.ddof)
+    }
+
+    fn update(&mut self, item: AnyValue) {
+        if let Some(val) = item.extract::<f64>() {
+            self.count += 1;
+            let delta = val - self.mean;
+            self.mean += delta / self.count as f64;
+            self.m2 += delta * (val - self.mean);
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        self.mean += delta * other.count as f64 / count as f64;
+        self.m2 += other.m2 + delta * delta * (self.count * other.count) as f64 / count as f64;
+        self.count = count;
+    }
+
+    fn var(&self) -> Option<f64> {
+        (self.count > self.ddof as u64).then(|| self.m2 / (self.count - self.ddof as u64) as f64)
+    }
+}
+
+impl AggregateFn for VarAgg {
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.update(item);
+        Ok(())
+    }
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) -> PolarsResult<()> {
+        for i in offset..offset + length {
+            self.update(unsafe { values.get_unchecked(i as usize) })
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "var"
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::Float64
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        self.merge(other);
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        self.var().into()
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Standard deviation, the square root of [`VarAgg`].
+pub(crate) struct StdAgg(VarAgg);
+
+impl StdAgg {
+    pub(crate) fn new(ddof: u8) -> Self {
+        StdAgg(VarAgg::new(ddof))
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        StdAgg(self.0.split())
+    }
+}
+
+impl AggregateFn for StdAgg {
+    fn pre_agg(
+        &mut self,
+        chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
+        self.0.pre_agg(chunk_idx, item)
+    }
+    fn pre_agg_ordered(
+        &mut self,
+        chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) -> PolarsResult<()> {
+        self.0.pre_agg_ordered(chunk_idx, offset, length, values)
+    }
+
+    fn name(&self) -> &str {
+        "std"
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::Float64
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        self.0.merge(&other.0);
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        self.0.var().map(f64::sqrt).into()
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    fn extract(av: AnyValue) -> f64 {
+        av.extract::<f64>().unwrap()
+    }
+
+    #[test]
+    fn test_var_parallel_merge() {
+        let s = Series::new(
+            "a",
+            &[
+                Some(1e9 + 4.0),
+                None,
+                Some(1e9 + 7.0),
+                Some(1e9 + 13.0),
+                Some(1e9 + 16.0),
+            ],
+        );
+        let mut single = VarAgg::new(1);
+        single.pre_agg_ordered(0, 0, 5, &s).unwrap();
+
+        let mut agg = VarAgg::new(1);
+        agg.pre_agg_ordered(0, 0, 3, &s).unwrap();
+        let mut other = agg.split();
+        other.pre_agg_ordered(1, 3, 2, &s).unwrap();
+        agg.combine(other.as_any()).unwrap();
+        // an empty partial state doesn't change the result
+        agg.combine(VarAgg::new(1).as_any()).unwrap();
+
+        let expected = 30.0;
+        assert!((extract(single.finalize()) - expected).abs() < 1e-6);
+        assert!((extract(agg.finalize()) - expected).abs() < 1e-6);
+
+        let mut std = StdAgg::new(0);
+        std.pre_agg_ordered(0, 0, 3, &s).unwrap();
+        let mut other = std.split();
+        other.pre_agg_ordered(1, 3, 2, &s).unwrap();
+        std.combine(other.as_any()).unwrap();
+        assert!((extract(std.finalize()) - 22.5f64.sqrt()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_var_ddof() {
+        let s = Series::new("a", &[1.0f64]);
+        let mut agg = VarAgg::new(1);
+        agg.pre_agg_ordered(0, 0, 1, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Null);
+
+        let mut agg = VarAgg::new(0);
+        agg.pre_agg_ordered(0, 0, 1, &s).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Float64(0.0));
+    }
+}
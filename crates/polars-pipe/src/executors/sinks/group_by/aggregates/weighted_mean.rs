@@ -0,0 +1,145 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+
+/// Weighted mean `sum(v * w) / sum(w)` of a group.
+///
+/// The weights are fed with [`AggregateFn::pre_agg_ordered_pair`]. Rows where either the value
+/// or the weight is null are skipped. Values that come in without weights (`pre_agg` and
+/// `pre_agg_ordered`) have a weight of one, so this degrades to an ordinary mean.
+pub(crate) struct WeightedMeanAgg {
+    sum_vw: f64,
+    sum_w: f64,
+}
+
+impl WeightedMeanAgg {
+    pub(crate) fn new() -> Self {
+        WeightedMeanAgg {
+            sum_vw: 0.0,
+            sum_w: 0.0,
+        }
+    }
+
+    fn update(&mut self, value: Option<f64>, weight: Option<f64>) {
+        if let (Some(v), Some(w)) = (value, weight) {
+            self.sum_vw += v * w;
+            self.sum_w += w;
+        }
+    }
+}
+
+fn to_f64(s: &Series, offset: IdxSize, length: IdxSize) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.dtype().is_numeric(),
+        SchemaMismatch: "expected a numeric dtype for `weighted_mean`, got {}", s.dtype()
+    );
+    s.slice(offset as i64, length as usize)
+        .cast(&DataType::Float64)
+}
+
+impl AggregateFn for WeightedMeanAgg {
+    fn pre_agg(
+        &mut self,
+        _chunk_idx: IdxSize,
+        item: &mut dyn ExactSizeIterator<Item = AnyValue>,
+    ) -> PolarsResult<()> {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.update(item.extract::<f64>(), Some(1.0));
+        Ok(())
+    }
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) -> PolarsResult<()> {
+        let values = to_f64(values, offset, length)?;
+        values
+            .f64()?
+            .into_iter()
+            .for_each(|v| self.update(v, Some(1.0)));
+        Ok(())
+    }
+    fn pre_agg_ordered_pair(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+        weights: &Series,
+    ) -> PolarsResult<()> {
+        let values = to_f64(values, offset, length)?;
+        let weights = to_f64(weights, offset, length)?;
+        values
+            .f64()?
+            .into_iter()
+            .zip(weights.f64()?)
+            .for_each(|(v, w)| self.update(v, w));
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "weighted_mean"
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::Float64
+    }
+
+    fn combine(&mut self, other: &dyn Any) -> PolarsResult<()> {
+        let other = downcast_other::<Self>(other)?;
+        self.sum_vw += other.sum_vw;
+        self.sum_w += other.sum_w;
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        // no (non-null) weights
+        if self.sum_w == 0.0 {
+            AnyValue::Null
+        } else {
+            AnyValue::Float64(self.sum_vw / self.sum_w)
+        }
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_weighted_mean() {
+        let values = Series::new("v", &[Some(1i32), Some(2), None, Some(4), Some(8)]);
+        let weights = Series::new("w", &[Some(3.0), Some(1.0), Some(5.0), None, Some(4.0)]);
+        let mut agg = WeightedMeanAgg::new();
+        agg.pre_agg_ordered_pair(0, 0, 3, &values, &weights)
+            .unwrap();
+        let mut other = WeightedMeanAgg::new();
+        other
+            .pre_agg_ordered_pair(1, 3, 2, &values, &weights)
+            .unwrap();
+        agg.combine(other.as_any()).unwrap();
+        // (1 * 3 + 2 * 1 + 8 * 4) / (3 + 1 + 4)
+        assert_eq!(agg.finalize(), AnyValue::Float64(37.0 / 8.0));
+
+        // without weights
+        let mut agg = WeightedMeanAgg::new();
+        agg.pre_agg_ordered(0, 0, 5, &values).unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Float64(15.0 / 4.0));
+
+        let mut agg = WeightedMeanAgg::new();
+        agg.pre_agg_ordered_pair(0, 2, 2, &values, &weights)
+            .unwrap();
+        assert_eq!(agg.finalize(), AnyValue::Null);
+    }
+}
@@ -1,6 +1,5 @@
 use std::cell::UnsafeCell;
 
-use polars_core::export::ahash::RandomState;
 use polars_row::{RowsEncoded, SortField};
 
 use super::*;
@@ -13,7 +12,7 @@ pub(super) struct Eval {
     key_columns_expr: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
     // the columns that will be aggregated
     aggregation_columns_expr: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
-    hb: RandomState,
+    pub(super) hb: RandomState,
     // amortize allocations
     aggregation_series: UnsafeCell<Vec<Series>>,
     keys_columns: UnsafeCell<Vec<ArrayRef>>,
@@ -99,7 +99,7 @@ impl GlobalTable {
         self.spill_partitions.insert(partition, payload);
     }
 
-    pub(super) fn early_merge(&self) {
+    pub(super) fn early_merge(&self) -> PolarsResult<()> {
         // round robin a partition to merge early
         let partition =
             self.early_merge_counter.fetch_add(1, Ordering::Relaxed) as usize % PARTITION_SIZE;
@@ -132,7 +132,7 @@ impl GlobalTable {
         chunk_indexes: &[IdxSize],
         keys: &BinaryArray<i64>,
         agg_cols: &[Series],
-    ) {
+    ) -> PolarsResult<()> {
         debug_assert_eq!(hashes.len(), chunk_indexes.len());
         debug_assert_eq!(hashes.len(), keys.len());
 
@@ -146,20 +146,25 @@ impl GlobalTable {
                 let chunk_index = *chunk_indexes.get_unchecked(i);
 
                 // SAFETY: keys_iters and cols_iters are not depleted
-                let overflow = hash_map.insert(hash, row, &mut agg_cols_iters, chunk_index);
+                let overflow = hash_map.insert(hash, row, &mut agg_cols_iters, chunk_index)?;
                 // should never overflow
                 debug_assert!(!overflow);
             }
         }
+        Ok(())
     }
 
-    pub(super) fn process_partition_from_dumped(&self, partition: usize, spilled: &DataFrame) {
+    pub(super) fn process_partition_from_dumped(
+        &self,
+        partition: usize,
+        spilled: &DataFrame,
+    ) -> PolarsResult<()> {
         let mut hash_map = self.inner_maps[partition].lock().unwrap();
         let (hashes, chunk_indexes, keys, aggs) = SpillPayload::spilled_to_columns(spilled);
-        self.process_partition_impl(&mut hash_map, hashes, chunk_indexes, keys, aggs);
+        self.process_partition_impl(&mut hash_map, hashes, chunk_indexes, keys, aggs)
     }
 
-    fn process_partition(&self, partition: usize) {
+    fn process_partition(&self, partition: usize) -> PolarsResult<()> {
         if let Some(bucket) = self.spill_partitions.drain_partition(partition, 0) {
             let mut hash_map = self.inner_maps[partition].lock().unwrap();
 
@@ -168,9 +173,10 @@ impl GlobalTable {
                 let keys = payload.keys();
                 let chunk_indexes = payload.chunk_index();
                 let agg_cols = payload.cols();
-                self.process_partition_impl(&mut hash_map, hashes, chunk_indexes, keys, agg_cols);
+                self.process_partition_impl(&mut hash_map, hashes, chunk_indexes, keys, agg_cols)?;
             }
         }
+        Ok(())
     }
 
     pub(super) fn merge_local_map(&self, finalized_local_map: &AggHashTable<true>) {
@@ -186,32 +192,35 @@ impl GlobalTable {
         &self,
         partition: usize,
         slice: &mut Option<(i64, usize)>,
-    ) -> DataFrame {
+    ) -> PolarsResult<DataFrame> {
         // ensure all spilled partitions are processed
-        self.process_partition(partition);
+        self.process_partition(partition)?;
         let mut hash_map = self.inner_maps[partition].lock().unwrap();
-        hash_map.finalize(slice)
+        Ok(hash_map.finalize(slice))
     }
 
     // only should be called if all state is in-memory
-    pub(super) fn finalize(&self, slice: &mut Option<(i64, usize)>) -> Vec<DataFrame> {
+    pub(super) fn finalize(
+        &self,
+        slice: &mut Option<(i64, usize)>,
+    ) -> PolarsResult<Vec<DataFrame>> {
         if slice.is_none() {
             POOL.install(|| {
                 (0..PARTITION_SIZE)
                     .into_par_iter()
                     .map(|part_i| {
-                        self.process_partition(part_i);
+                        self.process_partition(part_i)?;
                         let mut hash_map = self.inner_maps[part_i].lock().unwrap();
-                        hash_map.finalize(&mut None)
+                        Ok(hash_map.finalize(&mut None))
                     })
                     .collect()
             })
         } else {
             (0..PARTITION_SIZE)
                 .map(|part_i| {
-                    self.process_partition(part_i);
+                    self.process_partition(part_i)?;
                     let mut hash_map = self.inner_maps[part_i].lock().unwrap();
-                    hash_map.finalize(slice)
+                    Ok(hash_map.finalize(slice))
                 })
                 .collect()
         }
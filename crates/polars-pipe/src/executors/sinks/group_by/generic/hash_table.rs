@@ -127,17 +127,18 @@ impl<const FIXED: bool> AggHashTable<FIXED> {
 
     /// # Safety
     /// Caller must ensure that `keys` and `agg_iters` are not depleted.
-    /// # Returns &keys
+    /// # Returns
+    /// Whether the table overflowed, the aggregations are not applied in that case.
     pub(super) unsafe fn insert(
         &mut self,
         hash: u64,
         key: &[u8],
         agg_iters: &mut [SeriesPhysIter],
         chunk_index: IdxSize,
-    ) -> bool {
+    ) -> PolarsResult<bool> {
         let agg_idx = match self.insert_key(hash, key) {
             // overflow
-            None => return true,
+            None => return Ok(true),
             Some(agg_idx) => agg_idx,
         };
 
@@ -146,10 +147,10 @@ impl<const FIXED: bool> AggHashTable<FIXED> {
             let i = agg_idx as usize + i;
             let agg_fn = unsafe { self.running_aggregations.get_unchecked_release_mut(i) };
 
-            agg_fn.pre_agg(chunk_index, agg_iter.as_mut())
+            agg_fn.pre_agg(chunk_index, agg_iter.as_mut())?
         }
         // no overflow
-        false
+        Ok(false)
     }
 
     pub(super) fn combine(&mut self, other: &Self) {
@@ -202,7 +203,7 @@ impl<const FIXED: bool> AggHashTable<FIXED> {
                         let agg_self = aggs_self.get_unchecked_release_mut(i);
                         let other = aggs_other.get_unchecked_release(i);
                         // TODO!: try transmutes
-                        agg_self.combine(other.as_any())
+                        agg_self.combine(other.as_any()).unwrap()
                     }
                 }
             }
@@ -23,7 +23,11 @@ impl Default for OocState {
         let to_disk_threshold = if std::env::var(FORCE_OOC).is_ok() {
             1.0
         } else {
-            TO_DISK_THRESHOLD
+            parse_spill_threshold(
+                std::env::var("POLARS_GROUP_BY_SPILL_THRESHOLD")
+                    .ok()
+                    .as_deref(),
+            )
         };
 
         Self {
@@ -40,9 +44,29 @@ impl Default for OocState {
 // to free up memory
 const EARLY_MERGE_THRESHOLD: f64 = 0.5;
 // If this is reached we spill to disk and
-// aggregate in a second run
+// aggregate in a second run.
+// Can be set with `POLARS_GROUP_BY_SPILL_THRESHOLD`.
 const TO_DISK_THRESHOLD: f64 = 0.3;
 
+/// Parse the value of `POLARS_GROUP_BY_SPILL_THRESHOLD`, a value that isn't a number falls back
+/// on the default.
+fn parse_spill_threshold(value: Option<&str>) -> f64 {
+    let Some(value) = value else {
+        return TO_DISK_THRESHOLD;
+    };
+    match value.trim().parse::<f64>() {
+        Ok(threshold) if !threshold.is_nan() => threshold,
+        _ => {
+            if verbose() {
+                eprintln!(
+                    "invalid POLARS_GROUP_BY_SPILL_THRESHOLD: '{value}', using {TO_DISK_THRESHOLD}"
+                );
+            }
+            TO_DISK_THRESHOLD
+        },
+    }
+}
+
 pub(super) enum SpillAction {
     EarlyMerge,
     Dump,
@@ -97,3 +121,18 @@ impl OocState {
         iot.dump_partition(partition_no as IdxSize, df)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_spill_threshold() {
+        assert_eq!(parse_spill_threshold(None), TO_DISK_THRESHOLD);
+        assert_eq!(parse_spill_threshold(Some("0.5")), 0.5);
+        assert_eq!(parse_spill_threshold(Some(" 1.5 ")), 1.5);
+        for invalid in ["", "half", "NaN", "0,5"] {
+            assert_eq!(parse_spill_threshold(Some(invalid)), TO_DISK_THRESHOLD);
+        }
+    }
+}
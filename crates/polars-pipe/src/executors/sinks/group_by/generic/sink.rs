@@ -55,6 +55,15 @@ impl GenericGroupby2 {
             ooc_state: Default::default(),
         }
     }
+
+    /// Hash the keys with `hb` instead of a randomly seeded hasher, see
+    /// [`seeded_random_state`](crate::executors::sinks::group_by::seeded_random_state).
+    pub(crate) fn with_random_state(mut self, hb: Option<RandomState>) -> Self {
+        if let Some(hb) = hb {
+            self.eval.hb = hb;
+        }
+        self
+    }
 }
 
 impl Sink for GenericGroupby2 {
@@ -79,7 +88,7 @@ impl Sink for GenericGroupby2 {
 
             for (hash, row) in self.eval.hashes().iter().zip(keys.values_iter()) {
                 if let Some((partition, spill_payload)) =
-                    table.insert(*hash, row, &mut aggs, chunk_idx)
+                    table.insert(*hash, row, &mut aggs, chunk_idx)?
                 {
                     self.global_table.spill(partition, spill_payload)
                 }
@@ -100,7 +109,7 @@ impl Sink for GenericGroupby2 {
             .check_memory_usage(&|| self.global_table.get_ooc_dump_schema())?
         {
             SpillAction::None => {},
-            SpillAction::EarlyMerge => self.global_table.early_merge(),
+            SpillAction::EarlyMerge => self.global_table.early_merge()?,
             SpillAction::Dump => {
                 if let Some((partition_no, spill)) = self.global_table.get_ooc_dump() {
                     self.ooc_state.dump(partition_no, spill)
@@ -157,7 +166,7 @@ impl Sink for GenericGroupby2 {
                     eprintln!("finish streaming aggregation with global in-memory table")
                 }
 
-                let out = self.global_table.finalize(&mut self.slice);
+                let out = self.global_table.finalize(&mut self.slice)?;
                 let src = DataFrameSource::from_df(accumulate_dataframes_vertical_unchecked(out));
                 Ok(FinalizedSink::Source(Box::new(src)))
             }
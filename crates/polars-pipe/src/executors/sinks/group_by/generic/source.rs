@@ -70,18 +70,18 @@ impl Source for GroupBySource {
                 if spilled.n_chunks() > 1 {
                     for spilled in flatten_df_iter(&spilled) {
                         self.global_table
-                            .process_partition_from_dumped(partition, &spilled)
+                            .process_partition_from_dumped(partition, &spilled)?
                     }
                 } else {
                     self.global_table
-                        .process_partition_from_dumped(partition, &spilled)
+                        .process_partition_from_dumped(partition, &spilled)?
                 }
             }
         }
 
         let df = self
             .global_table
-            .finalize_partition(partition, &mut self.slice);
+            .finalize_partition(partition, &mut self.slice)?;
 
         let chunk_idx = self.partition_processed as IdxSize;
         Ok(SourceResult::GotMoreData(vec![DataChunk::new(
@@ -269,15 +269,16 @@ impl ThreadLocalTable {
         keys_row: &[u8],
         agg_iters: &mut [SeriesPhysIter],
         chunk_index: IdxSize,
-    ) -> Option<(usize, SpillPayload)> {
-        if self
+    ) -> PolarsResult<Option<(usize, SpillPayload)>> {
+        let overflow = self
             .inner_map
-            .insert(hash, keys_row, agg_iters, chunk_index)
-        {
-            self.spill_partitions
-                .insert(hash, chunk_index, keys_row, agg_iters)
+            .insert(hash, keys_row, agg_iters, chunk_index)?;
+        if overflow {
+            Ok(self
+                .spill_partitions
+                .insert(hash, chunk_index, keys_row, agg_iters))
         } else {
-            None
+            Ok(None)
         }
     }
 
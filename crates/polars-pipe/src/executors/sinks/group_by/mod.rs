@@ -7,12 +7,32 @@ mod string;
 mod utils;
 
 pub(crate) use generic::GenericGroupby2;
+use polars_core::export::ahash::RandomState;
 use polars_core::prelude::*;
 #[cfg(feature = "dtype-categorical")]
 use polars_core::using_string_cache;
 pub(crate) use primitive::*;
 pub(crate) use string::*;
 
+/// A hasher that hashes the same in every process for the same `seed`.
+///
+/// The group-by sinks partition the groups by their hash, with a seeded hasher the partitions
+/// match across the workers of a distributed run. The default hasher is seeded randomly.
+pub(crate) fn seeded_random_state(seed: u64) -> RandomState {
+    RandomState::with_seeds(
+        seed,
+        seed.rotate_left(16),
+        seed.rotate_left(32),
+        seed.rotate_left(48),
+    )
+}
+
+/// The hasher seeded by `POLARS_STREAMING_HASH_SEED`, if that is set.
+pub(crate) fn random_state_from_env() -> Option<RandomState> {
+    let seed = std::env::var("POLARS_STREAMING_HASH_SEED").ok()?;
+    Some(seeded_random_state(seed.parse().ok()?))
+}
+
 pub(super) fn physical_agg_to_logical(cols: &mut [Series], output_schema: &Schema) {
     for (s, (name, dtype)) in cols.iter_mut().zip(output_schema.iter()) {
         if s.name() != name {
@@ -105,6 +105,15 @@ where
         )
     }
 
+    /// Hash the keys with `hb` instead of a randomly seeded hasher, see
+    /// [`seeded_random_state`](crate::executors::sinks::group_by::seeded_random_state).
+    pub(crate) fn with_random_state(mut self, hb: Option<RandomState>) -> Self {
+        if let Some(hb) = hb {
+            self.hb = hb;
+        }
+        self
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn new_inner(
         key: Arc<dyn PhysicalPipedExpr>,
@@ -256,7 +265,7 @@ where
                     self.aggregators
                         .get_unchecked_release_mut((agg_idx + i) as usize)
                 };
-                agg_fn.pre_agg_ordered(chunk.chunk_index, *offset, *length, aggregation_s)
+                agg_fn.pre_agg_ordered(chunk.chunk_index, *offset, *length, aggregation_s)?;
             }
         }
         self.aggregation_series.clear();
@@ -342,7 +351,7 @@ where
             &self.aggregation_series,
             &self.agg_fns,
             &mut self.aggregators,
-        );
+        )?;
 
         self.ooc_state.dump(chunk.data, &mut self.hashes);
 
@@ -406,7 +415,7 @@ where
             &self.aggregation_series,
             &self.agg_fns,
             &mut self.aggregators,
-        );
+        )?;
 
         self.aggregation_series.clear();
         self.ooc_state.check_memory_usage(&self.input_schema)?;
@@ -445,7 +454,7 @@ where
                             let agg_fn_self = self
                                 .aggregators
                                 .get_unchecked_release_mut(agg_idx_self as usize + i);
-                            agg_fn_self.combine(agg_fn_other.as_any())
+                            agg_fn_self.combine(agg_fn_other.as_any()).unwrap()
                         }
                     }
                 }
@@ -557,7 +566,7 @@ pub(super) fn apply_aggregation(
     aggregation_series: &[Series],
     agg_fns: &[AggregateFunction],
     aggregators: &mut [AggregateFunction],
-) {
+) -> PolarsResult<()> {
     let chunk_idx = chunk.chunk_index;
     for (agg_i, aggregation_s) in (0..num_aggs).zip(aggregation_series) {
         let has_physical_agg = agg_fns[agg_i].has_physical_agg();
@@ -568,6 +577,7 @@ pub(super) fn apply_aggregation(
             aggregation_s,
             has_physical_agg,
             aggregators,
-        );
+        )?;
     }
+    Ok(())
 }
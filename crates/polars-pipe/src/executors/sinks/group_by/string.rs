@@ -104,6 +104,15 @@ impl StringGroupbySink {
         )
     }
 
+    /// Hash the keys with `hb` instead of a randomly seeded hasher, see
+    /// [`seeded_random_state`](crate::executors::sinks::group_by::seeded_random_state).
+    pub(crate) fn with_random_state(mut self, hb: Option<RandomState>) -> Self {
+        if let Some(hb) = hb {
+            self.hb = hb;
+        }
+        self
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn new_inner(
         key_column: Arc<dyn PhysicalPipedExpr>,
@@ -310,7 +319,7 @@ impl StringGroupbySink {
             &self.aggregation_series,
             &agg_fns,
             &mut aggregators,
-        );
+        )?;
         self.ooc_state.dump(chunk.data, &mut hashes);
 
         self.aggregation_series.clear();
@@ -390,7 +399,7 @@ impl Sink for StringGroupbySink {
             &self.aggregation_series,
             &agg_fns,
             &mut aggregators,
-        );
+        )?;
         self.aggregation_series.clear();
         self.hashes = hashes;
         self.keys = keys;
@@ -472,7 +481,7 @@ impl Sink for StringGroupbySink {
                             let agg_fn_self = self
                                 .aggregators
                                 .get_unchecked_release_mut(agg_idx_self as usize + i);
-                            agg_fn_self.combine(agg_fn_other.as_any())
+                            agg_fn_self.combine(agg_fn_other.as_any()).unwrap()
                         }
                     }
                 }
@@ -532,7 +541,7 @@ pub(super) fn apply_aggregate(
     aggregation_s: &Series,
     has_physical_agg: bool,
     aggregators: &mut [AggregateFunction],
-) {
+) -> PolarsResult<()> {
     macro_rules! apply_agg {
                 ($self:expr, $macro:ident $(, $opt_args:expr)*) => {{
                     match $self.dtype() {
@@ -575,9 +584,10 @@ pub(super) fn apply_aggregate(
         for &agg_idx in agg_idxs.iter() {
             let i = agg_idx as usize + agg_i;
             let agg_fn = unsafe { aggregators.get_unchecked_release_mut(i) };
-            agg_fn.pre_agg(chunk_idx, &mut iter)
+            agg_fn.pre_agg(chunk_idx, &mut iter)?
         }
     }
+    Ok(())
 }
 
 #[inline]
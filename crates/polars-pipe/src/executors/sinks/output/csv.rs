@@ -14,7 +14,7 @@ impl CsvSink {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(path: &Path, options: CsvWriterOptions, schema: &Schema) -> PolarsResult<FilesSink> {
         let file = std::fs::File::create(path)?;
-        let writer = CsvWriter::new(file)
+        let mut writer = CsvWriter::new(file)
             .include_bom(options.include_bom)
             .include_header(options.include_header)
             .with_separator(options.serialize_options.separator)
@@ -25,10 +25,23 @@ impl CsvSink {
             .with_date_format(options.serialize_options.date_format)
             .with_time_format(options.serialize_options.time_format)
             .with_float_precision(options.serialize_options.float_precision)
+            .with_float_scientific(options.serialize_options.float_scientific)
+            .with_float_special_values(
+                options.serialize_options.nan_value,
+                options.serialize_options.inf_value,
+                options.serialize_options.neg_inf_value,
+            )
             .with_null_value(options.serialize_options.null)
+            .with_bool_representation(
+                options.serialize_options.true_value,
+                options.serialize_options.false_value,
+            )
             .with_quote_style(options.serialize_options.quote_style)
-            .n_threads(1)
-            .batched(schema)?;
+            .n_threads(1);
+        for (column, format) in options.serialize_options.column_formats {
+            writer = writer.with_column_date_format(column, format);
+        }
+        let writer = writer.batched(schema)?;
 
         let writer = Box::new(writer) as Box<dyn SinkWriter + Send + Sync>;
 
@@ -73,6 +73,7 @@ impl CsvSource {
             .with_encoding(CsvEncoding::LossyUtf8)
             ._with_comment_prefix(options.comment_prefix)
             .with_quote_char(options.quote_char)
+            .with_escape_char(options.escape_char)
             .with_end_of_line_char(options.eol_char)
             .with_encoding(options.encoding)
             // never rechunk in streaming
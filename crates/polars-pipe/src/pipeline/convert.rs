@@ -10,7 +10,7 @@ use polars_ops::prelude::JoinType;
 use polars_plan::prelude::*;
 
 use crate::executors::operators::{HstackOperator, PlaceHolder};
-use crate::executors::sinks::group_by::aggregates::convert_to_hash_agg;
+use crate::executors::sinks::group_by::aggregates::{convert_to_hash_agg, hash_agg_output_schema};
 use crate::executors::sinks::group_by::GenericGroupby2;
 use crate::executors::sinks::*;
 use crate::executors::{operators, sources};
@@ -436,14 +436,17 @@ where
             }
             let aggregation_columns = Arc::new(aggregation_columns);
 
-            let group_by_sink = Box::new(GenericGroupby2::new(
-                key_columns,
-                aggregation_columns,
-                Arc::from(agg_fns),
-                output_schema,
-                input_agg_dtypes,
-                options.slice,
-            ));
+            let group_by_sink = Box::new(
+                GenericGroupby2::new(
+                    key_columns,
+                    aggregation_columns,
+                    Arc::from(agg_fns),
+                    output_schema,
+                    input_agg_dtypes,
+                    options.slice,
+                )
+                .with_random_state(group_by::random_state_from_env()),
+            );
 
             Box::new(ReProjectSink::new(input_schema, group_by_sink))
         },
@@ -475,16 +478,22 @@ where
                 input_agg_dtypes.push(input_dtype);
             }
             let aggregation_columns = Arc::new(aggregation_columns);
+            let output_schema =
+                hash_agg_output_schema(output_schema, keys.len(), &agg_fns, &input_agg_dtypes);
+            let hb = group_by::random_state_from_env();
 
             if std::env::var("POLARS_STREAMING_GB2").as_deref() == Ok("1") {
-                Box::new(GenericGroupby2::new(
-                    key_columns,
-                    aggregation_columns,
-                    Arc::from(agg_fns),
-                    output_schema.clone(),
-                    input_agg_dtypes,
-                    options.slice,
-                ))
+                Box::new(
+                    GenericGroupby2::new(
+                        key_columns,
+                        aggregation_columns,
+                        Arc::from(agg_fns),
+                        output_schema.clone(),
+                        input_agg_dtypes,
+                        options.slice,
+                    )
+                    .with_random_state(hb),
+                )
             } else {
                 match (
                     output_schema.get_at_index(0).unwrap().1.to_physical(),
@@ -499,25 +508,31 @@ where
                                 input_schema,
                                 output_schema.clone(),
                                 options.slice,
-                            )) as Box<dyn SinkTrait>
+                            ).with_random_state(hb)) as Box<dyn SinkTrait>
                         })
                     },
-                    (DataType::String, 1) => Box::new(group_by::StringGroupbySink::new(
-                        key_columns[0].clone(),
-                        aggregation_columns,
-                        agg_fns,
-                        input_schema,
-                        output_schema.clone(),
-                        options.slice,
-                    )) as Box<dyn SinkTrait>,
-                    _ => Box::new(GenericGroupby2::new(
-                        key_columns,
-                        aggregation_columns,
-                        Arc::from(agg_fns),
-                        output_schema.clone(),
-                        input_agg_dtypes,
-                        options.slice,
-                    )),
+                    (DataType::String, 1) => Box::new(
+                        group_by::StringGroupbySink::new(
+                            key_columns[0].clone(),
+                            aggregation_columns,
+                            agg_fns,
+                            input_schema,
+                            output_schema.clone(),
+                            options.slice,
+                        )
+                        .with_random_state(hb),
+                    ) as Box<dyn SinkTrait>,
+                    _ => Box::new(
+                        GenericGroupby2::new(
+                            key_columns,
+                            aggregation_columns,
+                            Arc::from(agg_fns),
+                            output_schema.clone(),
+                            input_agg_dtypes,
+                            options.slice,
+                        )
+                        .with_random_state(hb),
+                    ),
                 }
             }
         },
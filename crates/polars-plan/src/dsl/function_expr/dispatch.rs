@@ -1,3 +1,5 @@
+use polars_core::with_match_physical_integer_polars_type;
+
 use super::*;
 
 pub(super) fn reverse(s: &Series) -> PolarsResult<Series> {
@@ -117,6 +119,44 @@ pub(super) fn arg_unique(s: &Series) -> PolarsResult<Series> {
     s.arg_unique().map(|ok| ok.into_series())
 }
 
+pub(super) fn arg_min(s: &Series) -> PolarsResult<Series> {
+    Ok(Series::new(
+        s.name(),
+        &[s.arg_min().map(|idx| idx as IdxSize)],
+    ))
+}
+
+pub(super) fn arg_max(s: &Series) -> PolarsResult<Series> {
+    Ok(Series::new(
+        s.name(),
+        &[s.arg_max().map(|idx| idx as IdxSize)],
+    ))
+}
+
+pub(super) fn bitwise_and(s: &Series) -> PolarsResult<Series> {
+    bitwise_reduce(s, true)
+}
+
+pub(super) fn bitwise_or(s: &Series) -> PolarsResult<Series> {
+    bitwise_reduce(s, false)
+}
+
+fn bitwise_reduce(s: &Series, is_and: bool) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.dtype().is_integer(),
+        InvalidOperation: "`bitwise_{}` operation not supported for dtype `{}`",
+        if is_and { "and" } else { "or" }, s.dtype()
+    );
+    with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+        let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+        let out = ca
+            .into_iter()
+            .flatten()
+            .reduce(|a, b| if is_and { a & b } else { a | b });
+        Ok(ChunkedArray::<$T>::from_slice_options(s.name(), &[out]).into_series())
+    })
+}
+
 #[cfg(feature = "rank")]
 pub(super) fn rank(s: &Series, options: RankOptions, seed: Option<u64>) -> PolarsResult<Series> {
     Ok(s.rank(options, seed))
@@ -91,7 +91,7 @@ pub(super) use self::range::RangeFunction;
 #[cfg(feature = "rolling_window")]
 pub(super) use self::rolling::RollingFunction;
 #[cfg(feature = "strings")]
-pub(crate) use self::strings::StringFunction;
+pub use self::strings::StringFunction;
 #[cfg(feature = "dtype-struct")]
 pub(super) use self::struct_::StructFunction;
 #[cfg(feature = "trigonometry")]
@@ -164,6 +164,10 @@ pub enum FunctionExpr {
     #[cfg(feature = "repeat_by")]
     RepeatBy,
     ArgUnique,
+    ArgMin,
+    ArgMax,
+    BitwiseAnd,
+    BitwiseOr,
     #[cfg(feature = "rank")]
     Rank {
         options: RankOptions,
@@ -377,7 +381,8 @@ impl Hash for FunctionExpr {
                 symbol.hash(state);
             },
             MaxHorizontal | MinHorizontal | SumHorizontal | MeanHorizontal | DropNans
-            | DropNulls | Reverse | ArgUnique | Shift | ShiftAndFill => {},
+            | DropNulls | Reverse | ArgUnique | ArgMin | ArgMax | BitwiseAnd | BitwiseOr
+            | Shift | ShiftAndFill => {},
             #[cfg(feature = "mode")]
             Mode => {},
             #[cfg(feature = "abs")]
@@ -591,6 +596,10 @@ impl Display for FunctionExpr {
             #[cfg(feature = "moment")]
             Kurtosis(..) => "kurtosis",
             ArgUnique => "arg_unique",
+            ArgMin => "arg_min",
+            ArgMax => "arg_max",
+            BitwiseAnd => "bitwise_and",
+            BitwiseOr => "bitwise_or",
             #[cfg(feature = "rank")]
             Rank { .. } => "rank",
             #[cfg(feature = "round_series")]
@@ -911,6 +920,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "moment")]
             Kurtosis(fisher, bias) => map!(dispatch::kurtosis, fisher, bias),
             ArgUnique => map!(dispatch::arg_unique),
+            ArgMin => map!(dispatch::arg_min),
+            ArgMax => map!(dispatch::arg_max),
+            BitwiseAnd => map!(dispatch::bitwise_and),
+            BitwiseOr => map!(dispatch::bitwise_or),
             #[cfg(feature = "rank")]
             Rank { options, seed } => map!(dispatch::rank, options, seed),
             #[cfg(feature = "dtype-struct")]
@@ -300,44 +300,38 @@ impl Expr {
 
     /// Get the index value that has the minimum value.
     pub fn arg_min(self) -> Self {
-        let options = FunctionOptions {
-            collect_groups: ApplyOptions::GroupWise,
-            returns_scalar: true,
-            fmt_str: "arg_min",
-            ..Default::default()
-        };
-
-        self.function_with_options(
-            move |s: Series| {
-                Ok(Some(Series::new(
-                    s.name(),
-                    &[s.arg_min().map(|idx| idx as u32)],
-                )))
-            },
-            GetOutput::from_type(IDX_DTYPE),
-            options,
-        )
+        self.apply_private(FunctionExpr::ArgMin)
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
     }
 
     /// Get the index value that has the maximum value.
     pub fn arg_max(self) -> Self {
-        let options = FunctionOptions {
-            collect_groups: ApplyOptions::GroupWise,
-            returns_scalar: true,
-            fmt_str: "arg_max",
-            ..Default::default()
-        };
+        self.apply_private(FunctionExpr::ArgMax)
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
 
-        self.function_with_options(
-            move |s: Series| {
-                Ok(Some(Series::new(
-                    s.name(),
-                    &[s.arg_max().map(|idx| idx as u32)],
-                )))
-            },
-            GetOutput::from_type(IDX_DTYPE),
-            options,
-        )
+    /// Get the bitwise `and` of the integer values, nulls are skipped.
+    pub fn bitwise_and(self) -> Self {
+        self.apply_private(FunctionExpr::BitwiseAnd)
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
+    }
+
+    /// Get the bitwise `or` of the integer values, nulls are skipped.
+    pub fn bitwise_or(self) -> Self {
+        self.apply_private(FunctionExpr::BitwiseOr)
+            .with_function_options(|mut options| {
+                options.returns_scalar = true;
+                options
+            })
     }
 
     /// Get the index values that would sort this expression.
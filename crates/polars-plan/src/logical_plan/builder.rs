@@ -312,6 +312,7 @@ impl LogicalPlanBuilder {
         low_memory: bool,
         comment_prefix: Option<CommentPrefix>,
         quote_char: Option<u8>,
+        escape_char: Option<u8>,
         eol_char: u8,
         null_values: Option<NullValues>,
         infer_schema_length: Option<usize>,
@@ -352,13 +353,21 @@ impl LogicalPlanBuilder {
             separator,
             infer_schema_length,
             has_header,
+            true,
             schema_overwrite,
             &mut skip_rows,
             skip_rows_after_header,
             comment_prefix.as_ref(),
             quote_char,
+            escape_char,
             eol_char,
+            encoding,
             null_values.as_ref(),
+            false,
+            false,
+            None,
+            None,
+            None,
             try_parse_dates,
             raise_if_empty,
             &mut n_threads,
@@ -410,6 +419,7 @@ impl LogicalPlanBuilder {
                     low_memory,
                     comment_prefix,
                     quote_char,
+                    escape_char,
                     eol_char,
                     null_values,
                     encoding,
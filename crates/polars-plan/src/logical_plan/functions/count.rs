@@ -29,6 +29,7 @@ pub fn count_rows(paths: &Arc<[PathBuf]>, scan_type: &FileScan) -> PolarsResult<
                         path,
                         options.separator,
                         options.quote_char,
+                        options.escape_char,
                         options.comment_prefix.as_ref(),
                         options.eol_char,
                         options.has_header,
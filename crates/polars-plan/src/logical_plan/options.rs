@@ -29,6 +29,7 @@ pub struct CsvParserOptions {
     pub separator: u8,
     pub comment_prefix: Option<CommentPrefix>,
     pub quote_char: Option<u8>,
+    pub escape_char: Option<u8>,
     pub eol_char: u8,
     pub has_header: bool,
     pub skip_rows: usize,
@@ -1,4 +1,6 @@
+use std::fmt::Write as _;
 use std::io::Cursor;
+use std::sync::Mutex;
 
 use polars::io::RowIndex;
 
@@ -36,6 +38,280 @@ fn write_csv() {
     assert_eq!("0,22.1\r\n1,19.9\r\n2,7.0\r\n3,2.0\r\n4,3.0\r\n", csv);
 }
 
+#[test]
+fn write_csv_null_value() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [Some(1), None, Some(3)],
+        "b" => [None, Some("x"), Some("y")],
+    ]?;
+
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .with_null_value("NULL".into())
+        .finish(&mut df)?;
+    let csv = std::str::from_utf8(&buf).unwrap();
+    assert_eq!("a,b\n1,NULL\nNULL,x\n3,y\n", csv);
+    Ok(())
+}
+
+#[test]
+fn write_csv_quote_style() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => ["x,y", "z", "cr\r"],
+        "b" => [1, 2, 3],
+    ]?;
+
+    for (quote_style, expected) in [
+        (
+            QuoteStyle::Always,
+            "\"x,y\",\"1\"\n\"z\",\"2\"\n\"cr\r\",\"3\"\n",
+        ),
+        (QuoteStyle::Necessary, "\"x,y\",1\nz,2\n\"cr\r\",3\n"),
+        (QuoteStyle::NonNumeric, "\"x,y\",1\n\"z\",2\n\"cr\r\",3\n"),
+        (QuoteStyle::Never, "x,y,1\nz,2\ncr\r,3\n"),
+    ] {
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf)
+            .include_header(false)
+            .with_quote_style(quote_style)
+            .finish(&mut df)?;
+        let csv = std::str::from_utf8(&buf).unwrap();
+        assert_eq!(expected, csv, "{quote_style:?}");
+    }
+    Ok(())
+}
+
+#[test]
+fn write_csv_float_precision() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [1.0 / 3.0, 1500.0],
+        "b" => [1, 2],
+        "c" => ["x", "y"],
+    ]?;
+
+    for (precision, scientific, expected) in [
+        (None, false, "0.3333333333333333,1,x\n1500.0,2,y\n"),
+        (Some(3), false, "0.333,1,x\n1500.000,2,y\n"),
+        (None, true, "3.333333333333333e-1,1,x\n1.5e3,2,y\n"),
+        (Some(3), true, "3.333e-1,1,x\n1.500e3,2,y\n"),
+    ] {
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf)
+            .include_header(false)
+            .with_float_precision(precision)
+            .with_float_scientific(scientific)
+            .finish(&mut df)?;
+        let csv = std::str::from_utf8(&buf).unwrap();
+        assert_eq!(expected, csv, "{precision:?} {scientific}");
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "decompress")]
+fn write_csv_gzip() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [1, 2, 3],
+        "b" => ["x", "y,z", "\"q\""],
+        "c" => [Some(1.5), None, Some(-0.25)],
+    ]?;
+
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .with_compression(Some(CsvCompression::Gzip))
+        .finish(&mut df)?;
+    // gzip magic bytes
+    assert!(buf.starts_with(&[0x1f, 0x8b]));
+
+    let out = CsvReader::new(Cursor::new(buf)).finish()?;
+    assert!(out.equals_missing(&df));
+    Ok(())
+}
+
+#[test]
+fn write_csv_columns() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [1, 2],
+        "b" => ["x", "y"],
+        "c" => [1.5, 2.5],
+        "d" => [true, false],
+    ]?;
+
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .with_columns(Some(vec!["c".to_string(), "a".to_string()]))
+        .finish(&mut df)?;
+    let csv = std::str::from_utf8(&buf).unwrap();
+    assert_eq!("c,a\n1.5,1\n2.5,2\n", csv);
+
+    let mut buf: Vec<u8> = Vec::new();
+    let out = CsvWriter::new(&mut buf)
+        .with_columns(Some(vec!["e".to_string()]))
+        .finish(&mut df);
+    assert!(matches!(out, Err(PolarsError::ColumnNotFound(_))));
+    Ok(())
+}
+
+#[test]
+fn write_csv_bom() -> PolarsResult<()> {
+    let mut df = df!["a" => ["é"]]?;
+
+    let mut buf: Vec<u8> = Vec::new();
+    let mut writer = CsvWriter::new(&mut buf).include_bom(true);
+    writer.finish(&mut df)?;
+    // appending to the same stream doesn't write a second BOM
+    writer.include_header(false).finish(&mut df)?;
+    assert_eq!(&buf[..3], &[0xEF, 0xBB, 0xBF]);
+    assert_eq!(std::str::from_utf8(&buf[3..]).unwrap(), "a\né\né\n");
+    Ok(())
+}
+
+#[test]
+fn write_csv_bool_representation() -> PolarsResult<()> {
+    let mut df = df!["a" => [Some(true), None, Some(false)]]?;
+
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .include_header(false)
+        .with_null_value("NA".to_string())
+        .with_bool_representation("1".to_string(), "0".to_string())
+        .finish(&mut df)?;
+    let csv = std::str::from_utf8(&buf).unwrap();
+    assert_eq!("1\nNA\n0\n", csv);
+    Ok(())
+}
+
+#[test]
+fn write_csv_float_special_values() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [Some(f64::NAN), Some(f64::INFINITY), Some(f64::NEG_INFINITY), Some(1.5), None],
+    ]?;
+
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .include_header(false)
+        .finish(&mut df)?;
+    let csv = std::str::from_utf8(&buf).unwrap();
+    assert_eq!("NaN\ninf\n-inf\n1.5\n\n", csv);
+
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .include_header(false)
+        .with_float_precision(Some(2))
+        .with_float_special_values(
+            "".to_string(),
+            "Infinity".to_string(),
+            "-Infinity".to_string(),
+        )
+        .finish(&mut df)?;
+    let csv = std::str::from_utf8(&buf).unwrap();
+    assert_eq!("\nInfinity\n-Infinity\n1.50\n\n", csv);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-datetime", feature = "dtype-date"))]
+fn write_csv_column_date_format() -> PolarsResult<()> {
+    // 2022-01-08 13:45:30
+    let ts = Int64Chunked::new("a", &[1_641_649_530_000])
+        .into_datetime(TimeUnit::Milliseconds, None)
+        .into_series();
+    let mut df = DataFrame::new(vec![
+        ts.clone(),
+        ts.with_name("b"),
+        Int32Chunked::new("c", &[19000]).into_date().into_series(),
+    ])?;
+
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .include_header(false)
+        .with_datetime_format(Some("%Y-%m-%d %H:%M".to_string()))
+        .with_date_format(Some("%d/%m/%Y".to_string()))
+        .with_column_date_format("a".to_string(), "%Y-%m-%d".to_string())
+        .finish(&mut df)?;
+    let csv = std::str::from_utf8(&buf).unwrap();
+    assert_eq!("2022-01-08,2022-01-08 13:45,08/01/2022\n", csv);
+    Ok(())
+}
+
+#[test]
+fn write_csv_header_transform() -> PolarsResult<()> {
+    let mut df = df!["a" => [1], "b;c" => [2]]?;
+    let upper: HeaderTransform = Arc::new(|name: &str| name.to_uppercase());
+
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .with_separator(b';')
+        .with_header_transform(Some(upper.clone()))
+        .finish(&mut df)?;
+    let csv = std::str::from_utf8(&buf).unwrap();
+    // the transformed name is still quoted if it contains the separator
+    assert_eq!("A;\"B;C\"\n1;2\n", csv);
+    assert_eq!(df.get_column_names(), &["a", "b;c"]);
+
+    let mut buf: Vec<u8> = Vec::new();
+    let mut writer = CsvWriter::new(&mut buf)
+        .with_header_transform(Some(Arc::new(|name: &str| format!("col_{name}"))))
+        .batched(&df.schema())?;
+    writer.write_batch(&df)?;
+    writer.finish()?;
+    let csv = std::str::from_utf8(&buf).unwrap();
+    assert_eq!("col_a,col_b;c\n1,2\n", csv);
+    Ok(())
+}
+
+#[test]
+fn write_csv_append() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join(format!("polars_csv_append_{}.csv", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let mut first = df!["a" => [1i64, 2], "b" => ["x", "y"]]?;
+    let mut second = df!["a" => [3i64], "b" => ["z"]]?;
+
+    // the file doesn't exist yet, so the header is written
+    CsvWriter::to_path_append(&path)?.finish(&mut first)?;
+    CsvWriter::to_path_append(&path)?.finish(&mut second)?;
+    let out = CsvReader::from_path(&path)?.finish()?;
+    let mut expected = first.clone();
+    expected.vstack_mut(&second)?;
+    assert!(out.equals(&expected));
+
+    let mut other = df!["b" => ["w"], "a" => [4]]?;
+    let res = CsvWriter::to_path_append(&path)?.finish(&mut other);
+    assert!(matches!(res, Err(PolarsError::SchemaMismatch(_))));
+    // nothing was written
+    assert_eq!(CsvReader::from_path(&path)?.finish()?.height(), 3);
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn write_csv_batched() -> PolarsResult<()> {
+    let batches = [
+        df!["a" => [1, 2], "b" => ["x", "y"]]?,
+        df!["a" => [3], "b" => ["z"]]?,
+        df!["a" => [4, 5], "b" => [None, Some("w")]]?,
+    ];
+
+    let mut buf: Vec<u8> = Vec::new();
+    let mut writer = CsvWriter::new(&mut buf).batched(&batches[0].schema())?;
+    for batch in &batches {
+        writer.write_batch(batch)?;
+    }
+    let mismatch = df!["b" => ["x"], "a" => [1]]?;
+    assert!(matches!(
+        writer.write_batch(&mismatch),
+        Err(PolarsError::SchemaMismatch(_))
+    ));
+    writer.finish()?;
+
+    let out = CsvReader::new(Cursor::new(buf)).finish()?;
+    let mut expected = batches[0].clone();
+    expected.vstack_mut(&batches[1])?.vstack_mut(&batches[2])?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
 #[test]
 fn test_read_csv_file() {
     let file = std::fs::File::open(FOODS_CSV).unwrap();
@@ -47,6 +323,32 @@ fn test_read_csv_file() {
     assert_eq!(df.shape(), (27, 4));
 }
 
+#[test]
+fn test_read_csv_progress_callback() -> PolarsResult<()> {
+    let mut csv = "a,b\n".to_string();
+    for i in 0..10_000 {
+        csv.push_str(&format!("{i},{}\n", i * 2));
+    }
+    let n_bytes = csv.len();
+
+    let calls = Arc::new(Mutex::new(vec![]));
+    let calls_cb = calls.clone();
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_chunk_size(100)
+        .with_progress_callback(Some(Arc::new(move |read: usize, total: Option<usize>| {
+            calls_cb.lock().unwrap().push((read, total))
+        })))
+        .finish()?;
+    assert_eq!(df.height(), 10_000);
+
+    let calls = calls.lock().unwrap();
+    assert!(!calls.is_empty());
+    assert!(calls.windows(2).all(|w| w[0].0 < w[1].0));
+    assert!(calls.iter().all(|(_, total)| *total == Some(n_bytes)));
+    assert_eq!(calls.last().unwrap().0, n_bytes);
+    Ok(())
+}
+
 #[test]
 fn test_parser() -> PolarsResult<()> {
     let s = r#"
@@ -254,6 +556,286 @@ fn test_newline_in_custom_quote_char() {
     assert_eq!(df.shape(), (2, 2));
 }
 
+#[test]
+fn test_multi_line_field_across_chunks() -> PolarsResult<()> {
+    // the lines of the quoted field look like records and span the middle of the file, where
+    // the file is split in chunks
+    let inner = (0..400)
+        .map(|i| format!("{i},c,z"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let rows = (0..300).fold(String::new(), |mut rows, i| {
+        writeln!(rows, "{i},a,x").unwrap();
+        rows
+    });
+    let csv = format!("a,b,c\n{rows}300,\"{inner}\",q\n{rows}");
+
+    for n_threads in [1, 2, 4, 7] {
+        let df = CsvReader::new(Cursor::new(csv.as_str()))
+            .with_n_threads(Some(n_threads))
+            .finish()?;
+        assert_eq!(df.shape(), (601, 3));
+        let b = df.column("b")?.str()?;
+        assert_eq!(b.get(300), Some(inner.as_str()));
+        assert_eq!(df.column("c")?.str()?.get(300), Some("q"));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_row_order_multithreaded() -> PolarsResult<()> {
+    let csv = (0..5000).fold(String::new(), |mut csv, i| {
+        writeln!(csv, "{i},{}", i % 7).unwrap();
+        csv
+    });
+    let csv = format!("a,b\n{csv}");
+    for chunk_bytes in [None, Some(100)] {
+        let df = CsvReader::new(Cursor::new(csv.as_str()))
+            .with_n_threads(Some(4))
+            .with_chunk_bytes(chunk_bytes)
+            .finish()?;
+        let a = df.column("a")?.i64()?;
+        assert!(a.into_no_null_iter().eq(0..5000));
+
+        let df = CsvReader::new(Cursor::new(csv.as_str()))
+            .with_n_threads(Some(4))
+            .with_chunk_bytes(chunk_bytes)
+            .with_n_rows(Some(3001))
+            .finish()?;
+        let a = df.column("a")?.i64()?;
+        assert!(a.into_no_null_iter().eq(0..3001));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_chunk_bytes() -> PolarsResult<()> {
+    // quoted fields with separators and new lines, so that many chunk boundaries fall inside
+    // of them
+    let csv = (0..2000).fold(String::new(), |mut csv, i| {
+        writeln!(csv, "{i},\"{i},\n{}\",{}.5", "x".repeat(i % 37), i % 11).unwrap();
+        csv
+    });
+    let csv = format!("a,b,c\n{csv}");
+    let expected = CsvReader::new(Cursor::new(csv.as_str()))
+        .with_n_threads(Some(1))
+        .finish()?;
+    assert_eq!(expected.shape(), (2000, 3));
+
+    for chunk_bytes in [1, 7, 64, 1000, 1 << 20] {
+        let df = CsvReader::new(Cursor::new(csv.as_str()))
+            .with_n_threads(Some(4))
+            .with_chunk_bytes(Some(chunk_bytes))
+            .finish()?;
+        assert!(df.equals(&expected), "chunk_bytes = {chunk_bytes}");
+    }
+
+    let res = CsvReader::new(Cursor::new(csv.as_str()))
+        .with_chunk_bytes(Some(0))
+        .finish();
+    assert!(res.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_schema_inference_windows() -> PolarsResult<()> {
+    // the dtypes only change in the last windows of the sample
+    let csv = (0..20_000).fold(String::new(), |mut csv, i| {
+        let a = if i == 15_000 {
+            "1.5".to_string()
+        } else {
+            i.to_string()
+        };
+        let b = if i == 19_999 {
+            "x".to_string()
+        } else {
+            i.to_string()
+        };
+        let c = if i < 18_000 { "" } else { "true" };
+        writeln!(csv, "{a},{b},{c},{i}").unwrap();
+        csv
+    });
+    let csv = format!("a,b,c,d\n{csv}");
+    let infer = |n_threads| {
+        CsvReader::new(Cursor::new(csv.as_str()))
+            .infer_schema(None)
+            .with_n_threads(Some(n_threads))
+            .schema()
+    };
+
+    // a single thread infers all rows at once
+    let serial = infer(1)?;
+    let expected = Schema::from_iter([
+        Field::new("a", DataType::Float64),
+        Field::new("b", DataType::String),
+        Field::new("c", DataType::Boolean),
+        Field::new("d", DataType::Int64),
+    ]);
+    assert_eq!(serial, expected);
+    for n_threads in [2, 8] {
+        assert_eq!(infer(n_threads)?, serial, "n_threads = {n_threads}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_expected_rows() -> PolarsResult<()> {
+    let csv = (0..5000).fold(String::new(), |mut csv, i| {
+        writeln!(csv, "{i},{},{}", i % 7, "y".repeat(i % 5)).unwrap();
+        csv
+    });
+    let csv = format!("a,b,c\n{csv}");
+    let expected = CsvReader::new(Cursor::new(csv.as_str())).finish()?;
+    assert_eq!(expected.shape(), (5000, 3));
+
+    // far too few, far too many and with fewer rows read
+    for (expected_rows, n_rows) in [(1, None), (1_000_000, None), (1_000_000, Some(10))] {
+        for n_threads in [1, 4] {
+            let df = CsvReader::new(Cursor::new(csv.as_str()))
+                .with_expected_rows(Some(expected_rows))
+                .with_n_rows(n_rows)
+                .with_n_threads(Some(n_threads))
+                .finish()?;
+            let expected = expected.slice(0, n_rows.unwrap_or(usize::MAX));
+            assert!(
+                df.equals_missing(&expected),
+                "expected_rows = {expected_rows}"
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_custom_quote_char_embedded_separator() -> PolarsResult<()> {
+    let csv = r"id,name,city
+1,'Doe, John','New York'
+2,'O''Brien','Dublin, Ireland'
+";
+
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file).with_quote_char(Some(b'\'')).finish()?;
+    assert_eq!(df.shape(), (2, 3));
+    assert_eq!(
+        df.column("name")?
+            .str()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &["Doe, John", "O'Brien"]
+    );
+    assert_eq!(
+        df.column("city")?
+            .str()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &["New York", "Dublin, Ireland"]
+    );
+
+    // without quoting every separator splits a field
+    let csv = r#"a,b
+"x,y
+"#;
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file).with_quote_char(None).finish()?;
+    assert_eq!(
+        df.column("a")?
+            .str()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &["\"x"]
+    );
+    assert_eq!(
+        df.column("b")?
+            .str()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &["y"]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_escape_char() -> PolarsResult<()> {
+    let csv = "id,text,n\n1,\"foo\\\"bar\",1\n2,\"a\\,b\",2\n3,\"line\\\nbreak\",3\n4,plain,4\n";
+
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .with_escape_char(Some(b'\\'))
+        .finish()?;
+    assert_eq!(df.shape(), (4, 3));
+    assert_eq!(
+        df.column("text")?
+            .str()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &["foo\"bar", "a,b", "line\nbreak", "plain"]
+    );
+    assert_eq!(
+        df.column("n")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[1, 2, 3, 4]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_utf8_bom() -> PolarsResult<()> {
+    // Excel exports start with a BOM and use windows line endings
+    let csv = b"\xef\xbb\xbfid,name\r\n1,a\r\n2,b\r\n";
+    let df = CsvReader::new(Cursor::new(csv)).finish()?;
+    assert_eq!(df.get_column_names(), &["id", "name"]);
+    assert_eq!(
+        df.column("id")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[1, 2]
+    );
+
+    // utf-16 is not silently parsed as garbage
+    let csv = b"\xff\xfei\x00d\x00\n\x001\x00\n\x00";
+    let err = CsvReader::new(Cursor::new(csv)).finish().unwrap_err();
+    assert!(err.to_string().contains("utf-16"));
+    Ok(())
+}
+
+#[test]
+fn test_latin1_encoding() -> PolarsResult<()> {
+    let csv = b"name,value\ncaf\xe9,1\n\"na\xefve\",2\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_encoding(CsvEncoding::Latin1)
+        .finish()?;
+    assert_eq!(
+        df.column("name")?
+            .str()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &["café", "naïve"]
+    );
+    assert_eq!(df.column("value")?.dtype(), &DataType::Int64);
+
+    // the header is decoded the same way as the fields
+    let csv = b"caf\xe9,pr\xeds\n1,\xe9t\xe9\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_encoding(CsvEncoding::Latin1)
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["café", "prís"]);
+    assert_eq!(df.column("prís")?.str()?.get(0), Some("été"));
+
+    #[cfg(feature = "dtype-categorical")]
+    {
+        let df = CsvReader::new(Cursor::new(csv))
+            .with_encoding(CsvEncoding::Latin1)
+            .with_categorical_columns(Some(vec!["prís".into()]))
+            .finish()?;
+        let s = df.column("prís")?.cast(&DataType::String)?;
+        assert_eq!(s.str()?.get(0), Some("été"));
+    }
+    Ok(())
+}
+
 #[test]
 fn test_escape_2() {
     // this is harder than it looks.
@@ -479,6 +1061,39 @@ fn test_skip_rows() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_header_row() -> PolarsResult<()> {
+    // the preamble has an unbalanced quote
+    let csv = "Export of \"sales, 2024-01-02\na,b\n1,x\n2,y\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .has_header(false)
+        .with_header_row(1)
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["a", "b"]);
+    assert_eq!(df.dtypes(), &[DataType::Int64, DataType::String]);
+    assert_eq!(df.height(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_projection_range() -> PolarsResult<()> {
+    let df = CsvReader::from_path(FOODS_CSV)?
+        .with_projection_range(Some(1..3))
+        .finish()?;
+    let expected = CsvReader::from_path(FOODS_CSV)?
+        .with_projection(Some(vec![1, 2]))
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["calories", "fats_g"]);
+    assert!(df.equals_missing(&expected));
+
+    // the file has 4 columns
+    let out = CsvReader::from_path(FOODS_CSV)?
+        .with_projection_range(Some(2..5))
+        .finish();
+    assert!(out.is_err());
+    Ok(())
+}
+
 #[test]
 fn test_projection_idx() -> PolarsResult<()> {
     let csv = r"#0 NA 0 0 57 0
@@ -578,6 +1193,66 @@ fn test_comment_lines() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_comment_char() -> PolarsResult<()> {
+    let csv = r"# exported by some tool
+# version: 1.2
+a,b,c
+1,2,3
+  # an indented comment
+4,5,6
+# another comment
+7,8,9
+";
+
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .with_comment_char(Some(b'#'))
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["a", "b", "c"]);
+    assert_eq!(
+        df.dtypes(),
+        &[DataType::Int64, DataType::Int64, DataType::Int64]
+    );
+    assert_eq!(
+        df.column("a")?.i64()?.to_vec(),
+        &[Some(1), Some(4), Some(7)]
+    );
+
+    // comment lines don't count towards `n_rows`
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .with_comment_char(Some(b'#'))
+        .with_n_rows(Some(2))
+        .finish()?;
+    assert_eq!(df.column("a")?.i64()?.to_vec(), &[Some(1), Some(4)]);
+
+    Ok(())
+}
+
+#[test]
+fn test_comment_char_tab_separated() -> PolarsResult<()> {
+    // a leading tab is an empty field, not indentation before a comment
+    let csv = "a\tb\tc\n1\t#x\t3\n  # a comment\n\t#y\t6\n\t# not a comment\n7\t8\t9\n";
+
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .with_separator(b'\t')
+        .with_comment_char(Some(b'#'))
+        .finish()?;
+    assert_eq!(df.shape(), (4, 3));
+    assert_eq!(
+        df.column("a")?.i64()?.to_vec(),
+        &[Some(1), None, None, Some(7)]
+    );
+    assert_eq!(
+        df.column("b")?.str()?.into_iter().collect::<Vec<_>>(),
+        &[Some("#x"), Some("#y"), Some("# not a comment"), Some("8")]
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_null_values_argument() -> PolarsResult<()> {
     let csv = r"1,a,foo
@@ -595,12 +1270,94 @@ null-value,b,bar
 }
 
 #[test]
-fn test_no_newline_at_end() -> PolarsResult<()> {
-    let csv = r"a,b
-foo,foo
-bar,bar";
+fn test_named_null_values() -> PolarsResult<()> {
+    let csv = "text,measurement,other
+a,-999,-999
+,1.5,2
+-999,-999,3
+";
+    let null_values = NullValues::Named(vec![("measurement".into(), "-999".into())]);
     let file = Cursor::new(csv);
-    let df = CsvReader::new(file).finish()?;
+    let df = CsvReader::new(file.clone())
+        .with_null_values(Some(null_values.clone()))
+        .finish()?;
+    assert_eq!(df.column("measurement")?.dtype(), &DataType::Float64);
+    assert_eq!(df.column("measurement")?.null_count(), 2);
+    // columns that are not listed only treat empty fields as null
+    assert_eq!(
+        Vec::from(df.column("text")?.str()?),
+        &[Some("a"), None, Some("-999")]
+    );
+    assert_eq!(df.column("other")?.null_count(), 0);
+
+    // the mapping is resolved by name, also when projecting
+    let df = CsvReader::new(file.clone())
+        .with_null_values(Some(null_values.clone()))
+        .with_columns(Some(vec!["other".into(), "measurement".into()]))
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["measurement", "other"]);
+    assert_eq!(df.column("measurement")?.null_count(), 2);
+    assert_eq!(df.column("other")?.null_count(), 0);
+
+    let df = CsvReader::new(file)
+        .with_null_values(Some(null_values))
+        .with_projection(Some(vec![1]))
+        .finish()?;
+    assert_eq!(df.column("measurement")?.null_count(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_null_values_before_numeric_parsing() -> PolarsResult<()> {
+    let csv = r"id,value
+1,10
+2,NA
+3,NULL
+4,N/A
+5,50
+";
+
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .with_null_values(Some(NullValues::AllColumns(vec![
+            "NA".to_string(),
+            "NULL".to_string(),
+            "N/A".to_string(),
+        ])))
+        .finish()?;
+    assert_eq!(df.height(), 5);
+    let value = df.column("value")?;
+    assert_eq!(value.dtype(), &DataType::Int64);
+    assert_eq!(
+        value.i64()?.to_vec(),
+        &[Some(10), None, None, None, Some(50)]
+    );
+
+    // null values are checked before parsing, so an explicit integer dtype doesn't raise
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .with_dtypes(Some(Arc::new(Schema::from_iter([Field::new(
+            "value",
+            DataType::Int32,
+        )]))))
+        .with_null_values(Some(NullValues::AllColumns(vec![
+            "NA".to_string(),
+            "NULL".to_string(),
+            "N/A".to_string(),
+        ])))
+        .finish()?;
+    assert_eq!(df.column("value")?.dtype(), &DataType::Int32);
+    assert_eq!(df.column("value")?.null_count(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_no_newline_at_end() -> PolarsResult<()> {
+    let csv = r"a,b
+foo,foo
+bar,bar";
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file).finish()?;
 
     use polars_core::df;
     let expect = df![
@@ -947,6 +1704,264 @@ foo,bar
     Ok(())
 }
 
+#[test]
+fn test_low_memory_wide_file() -> PolarsResult<()> {
+    let n_cols = 2000;
+    let header = (0..n_cols).map(|i| format!("c{i}")).collect::<Vec<_>>();
+    let mut csv = header.join(",");
+    csv.push('\n');
+    for row in 0..600 {
+        let values = (0..n_cols)
+            .map(|i| (row * i).to_string())
+            .collect::<Vec<_>>();
+        csv.push_str(&values.join(","));
+        csv.push('\n');
+    }
+
+    let file = Cursor::new(csv);
+    let expected = CsvReader::new(file.clone()).finish()?;
+    let df = CsvReader::new(file).low_memory(true).finish()?;
+    assert_eq!(df.shape(), (600, n_cols));
+    assert_eq!(df.n_chunks(), 1);
+    assert!(df.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_batched_mmap() -> PolarsResult<()> {
+    let mut csv = "a,b,c\n".to_string();
+    for i in 0..1000 {
+        csv.push_str(&format!("{i},{}.5,s{i}\n", i % 3));
+    }
+    let file = Cursor::new(csv);
+    let expected = CsvReader::new(file.clone()).finish()?;
+
+    let mut reader = CsvReader::new(file).with_chunk_size(100);
+    let mut batched = reader.batched_borrowed_mmap()?;
+    let mut batches = vec![];
+    while let Some(dfs) = batched.next_batches(2)? {
+        batches.extend(dfs);
+    }
+    assert!(batches.len() > 1);
+    for df in &batches {
+        assert_eq!(df.schema(), expected.schema());
+    }
+    assert!(batched.next_batches(1)?.is_none());
+
+    let df = polars_core::utils::accumulate_dataframes_vertical(batches)?;
+    assert!(df.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_n_rows_above_row_count() -> PolarsResult<()> {
+    let mut csv = "a,b\n".to_string();
+    for i in 0..1000 {
+        // lines of different lengths, so the line statistics are off
+        csv.push_str(&format!("{i},{}\n", "x".repeat(i % 37)));
+    }
+    let read = |csv: &str, n_rows, n_threads| {
+        CsvReader::new(Cursor::new(csv))
+            .with_n_rows(Some(n_rows))
+            .with_n_threads(Some(n_threads))
+            .with_row_index(Some(RowIndex {
+                name: "index".into(),
+                offset: 10,
+            }))
+            .finish()
+    };
+
+    let expected = read(&csv, 1000, 1)?;
+    assert_eq!(expected.height(), 1000);
+    // a limit above `IdxSize::MAX` must not wrap around
+    for n_rows in [1001, 1 << 40, usize::MAX] {
+        for n_threads in [1, 2, 4] {
+            let df = read(&csv, n_rows, n_threads)?;
+            assert!(
+                df.equals_missing(&expected),
+                "n_rows: {n_rows}, n_threads: {n_threads}"
+            );
+        }
+    }
+
+    // the line statistics are sampled at the start and at 75% of the file, where the lines are
+    // short, so the bytes estimated for `n_rows` run out and the rest is read from the tail
+    let mut tail_csv = "a,b\n".to_string();
+    for i in 0..5000 {
+        let len = if (1000..2000).contains(&i) { 60 } else { 1 };
+        tail_csv.push_str(&format!("{i},{}\n", "x".repeat(len)));
+    }
+    let expected = read(&tail_csv, 5000, 1)?.slice(0, 2500);
+    for n_threads in [1, 2, 4] {
+        let df = read(&tail_csv, 2500, n_threads)?;
+        assert!(
+            df.equals_missing(&expected),
+            "n_rows: 2500, n_threads: {n_threads}"
+        );
+    }
+
+    let mut reader = CsvReader::new(Cursor::new(csv.as_str()))
+        .with_chunk_size(100)
+        .with_n_rows(Some((1 << 32) + 5));
+    let mut batched = reader.batched_borrowed_mmap()?;
+    let mut height = 0;
+    while let Some(dfs) = batched.next_batches(2)? {
+        height += dfs.iter().map(|df| df.height()).sum::<usize>();
+    }
+    assert_eq!(height, 1000);
+    Ok(())
+}
+
+#[test]
+fn test_ragged_lines_policy() -> PolarsResult<()> {
+    let short_row = "a,b,c\n1,2,3\n4,5\n6,7,8\n";
+    let long_row = "a,b,c\n1,2,3\n4,5,6,7\n8,9,10\n";
+    let read = |csv: &str, policy| {
+        CsvReader::new(Cursor::new(csv.to_string()))
+            .with_ragged_lines(policy)
+            .finish()
+    };
+
+    // short rows are padded with nulls, unless they are skipped
+    for policy in [RaggedPolicy::Error, RaggedPolicy::Truncate] {
+        let df = read(short_row, policy)?;
+        assert_eq!(df.shape(), (3, 3));
+        assert_eq!(Vec::from(df.column("c")?.i64()?), &[Some(3), None, Some(8)]);
+    }
+    let df = read(short_row, RaggedPolicy::Skip)?;
+    assert_eq!(Vec::from(df.column("a")?.i64()?), &[Some(1), Some(6)]);
+
+    assert!(read(long_row, RaggedPolicy::Error).is_err());
+    let df = read(long_row, RaggedPolicy::Truncate)?;
+    assert_eq!(
+        Vec::from(df.column("c")?.i64()?),
+        &[Some(3), Some(6), Some(10)]
+    );
+    let df = read(long_row, RaggedPolicy::Skip)?;
+    assert_eq!(Vec::from(df.column("a")?.i64()?), &[Some(1), Some(8)]);
+    Ok(())
+}
+
+#[test]
+fn test_csv_error_variants() {
+    let read = |csv: &str| {
+        CsvReader::new(Cursor::new(csv.to_string()))
+            .finish()
+            .unwrap_err()
+    };
+
+    let err = read("a,b,c\n1,2,3\n4,5,6,7\n8,9,10\n");
+    assert_eq!(
+        CsvError::from_polars(&err),
+        Some(&CsvError::RaggedRow {
+            line: 3,
+            offset: 12,
+            expected: 3,
+            found: 4
+        })
+    );
+    assert!(err.to_string().contains("found more fields than defined"));
+
+    let err = read("a,b\n1,x\n2,\"abc\n3,y\n");
+    assert_eq!(
+        CsvError::from_polars(&err),
+        Some(&CsvError::UnterminatedQuote {
+            line: 3,
+            offset: 10
+        })
+    );
+}
+
+#[test]
+fn test_skip_footer() -> PolarsResult<()> {
+    let mut csv = "a,b\n".to_string();
+    for i in 0..1000 {
+        csv.push_str(&format!("{i},{}\n", i * 2));
+    }
+    csv.push_str("Total: 1000 rows\nGenerated by report\n");
+
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file.clone())
+        .with_n_threads(Some(4))
+        .with_skip_footer(2)
+        .finish()?;
+    assert_eq!(df.shape(), (1000, 2));
+    assert_eq!(df.dtypes(), &[DataType::Int64, DataType::Int64]);
+    assert_eq!(df.column("a")?.i64()?.get(999), Some(999));
+
+    // the footer is skipped first and `n_rows` applies to the remaining rows
+    let df = CsvReader::new(file.clone())
+        .with_skip_footer(2)
+        .with_n_rows(Some(10))
+        .finish()?;
+    assert_eq!(df.shape(), (10, 2));
+    assert_eq!(df.column("b")?.i64()?.get(9), Some(18));
+
+    let df = CsvReader::new(file)
+        .with_skip_footer(1002)
+        .with_n_rows(Some(10))
+        .finish()?;
+    assert_eq!(df.height(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_from_paths() -> PolarsResult<()> {
+    let paths = [FOODS_CSV, "../../examples/datasets/foods2.csv"];
+    let df = CsvReader::from_paths(&paths)?.finish()?;
+    assert_eq!(df.shape(), (54, 4));
+    assert_eq!(df.n_chunks(), 1);
+
+    // `n_rows` is a global limit and the row index continues over files
+    let df = CsvReader::from_paths(&paths)?
+        .with_n_rows(Some(30))
+        .with_row_index(Some(RowIndex {
+            name: "index".into(),
+            offset: 10,
+        }))
+        .finish()?;
+    assert_eq!(df.shape(), (30, 5));
+    assert_eq!(
+        df.column("index")?
+            .idx()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        (10 as IdxSize..40).collect::<Vec<_>>()
+    );
+
+    let paths = [FOODS_CSV, "../../examples/datasets/null_nutriscore.csv"];
+    let err = CsvReader::from_paths(&paths)?.finish().unwrap_err();
+    assert!(matches!(err, PolarsError::SchemaMismatch(_)));
+
+    // same columns, but a dtype that differs from the first file
+    let dir = std::env::temp_dir();
+    let paths = ["ints", "floats"].map(|name| {
+        dir.join(format!(
+            "polars_from_paths_{name}_{}.csv",
+            std::process::id()
+        ))
+    });
+    std::fs::write(&paths[0], "a,b\n1,x\n2,y\n")?;
+    std::fs::write(&paths[1], "a,b\n3.5,z\n")?;
+    let err = CsvReader::from_paths(&paths)?.finish().unwrap_err();
+    assert!(matches!(err, PolarsError::SchemaMismatch(_)));
+    // unless the dtype is set for all files
+    let df = CsvReader::from_paths(&paths)?
+        .with_dtypes(Some(Arc::new(Schema::from_iter([Field::new(
+            "a",
+            DataType::Float64,
+        )]))))
+        .finish()?;
+    assert_eq!(
+        Vec::from(df.column("a")?.f64()?),
+        &[Some(1.0), Some(2.0), Some(3.5)]
+    );
+    for path in &paths {
+        std::fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
 #[test]
 fn test_with_row_index() -> PolarsResult<()> {
     let df = CsvReader::from_path(FOODS_CSV)?
@@ -974,6 +1989,32 @@ fn test_with_row_index() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_with_row_index_multithreaded() -> PolarsResult<()> {
+    let mut csv = "a,b\n".to_string();
+    for i in 0..10_000 {
+        csv.push_str(&format!("{i},{}\n", i % 7));
+    }
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .with_n_threads(Some(4))
+        .with_chunk_size(100)
+        .with_row_index(Some(RowIndex {
+            name: "rc".into(),
+            offset: 5,
+        }))
+        .finish()?;
+    let rc = df.column("rc")?;
+    assert_eq!(
+        rc.idx()?.into_no_null_iter().collect::<Vec<_>>(),
+        (5 as IdxSize..10_005).collect::<Vec<_>>()
+    );
+    // the index follows the original row order
+    let a = df.column("a")?.i64()?;
+    assert_eq!(a.get(9_999), Some(9_999));
+    Ok(())
+}
+
 #[test]
 fn test_empty_string_cols() -> PolarsResult<()> {
     let csv = "\nabc\n\nxyz\n";
@@ -997,6 +2038,180 @@ fn test_empty_string_cols() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_skip_blank_lines() -> PolarsResult<()> {
+    let csv = "a,b,c\n1,x,2\n\n  \t\r\n,,\n3,y,4\n\n";
+
+    let df = CsvReader::new(Cursor::new(csv)).finish()?;
+    // the line of empty fields is a row, the blank lines are not
+    let expected = df![
+        "a" => [Some(1i64), None, Some(3)],
+        "b" => [Some("x"), None, Some("y")],
+        "c" => [Some(2i64), None, Some(4)]
+    ]?;
+    assert!(df.equals_missing(&expected));
+
+    // blank lines don't count towards `n_rows`
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_n_rows(Some(2))
+        .finish()?;
+    assert_eq!(df.height(), 2);
+    assert!(df.equals_missing(&expected.head(Some(2))));
+
+    // without skipping a blank line is a row of nulls
+    let csv = "a,b\n1,2\n\n3,4\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_skip_blank_lines(false)
+        .finish()?;
+    let expected = df![
+        "a" => [Some(1i64), None, Some(3)],
+        "b" => [Some(2i64), None, Some(4)]
+    ]?;
+    assert!(df.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_auto_separator() -> PolarsResult<()> {
+    // the comma in a field doesn't split every line in the same number of fields
+    let csv = "# exported\nname\tcity\tscore\nAnn\tParis, France\t1\n\nBob\tLima\t2\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_comment_prefix(Some("#"))
+        .with_auto_separator(true)
+        .finish()?;
+    let expected = df![
+        "name" => ["Ann", "Bob"],
+        "city" => ["Paris, France", "Lima"],
+        "score" => [1i64, 2]
+    ]?;
+    assert!(df.equals(&expected));
+
+    // an explicit separator isn't overridden
+    let df = CsvReader::new(Cursor::new("a;b\n1;2\n"))
+        .with_separator(b'|')
+        .with_auto_separator(true)
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["a;b"]);
+
+    // a single column falls back to the default
+    let df = CsvReader::new(Cursor::new("a\n1\n"))
+        .with_auto_separator(true)
+        .finish()?;
+    assert_eq!(df.shape(), (1, 1));
+    Ok(())
+}
+
+#[test]
+fn test_auto_separator_ambiguous() {
+    let csv = "a,b;c\n1,2;3\n";
+    let err = CsvReader::new(Cursor::new(csv))
+        .with_auto_separator(true)
+        .finish()
+        .unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("','") && msg.contains("';'"), "{msg}");
+    assert!(!msg.contains("'|'"), "{msg}");
+}
+
+#[test]
+fn test_max_field_len() -> PolarsResult<()> {
+    // the quote in the third line is never closed
+    let csv = "a,b,c\n1,x,y\n2,\"abcdef\n3,def,z\n4,g,h\n";
+    let too_long = CsvError::FieldTooLong {
+        line: 3,
+        offset: 14,
+        column: "b".into(),
+        max_field_len: 4,
+    };
+
+    let err = CsvReader::new(Cursor::new(csv))
+        .with_max_field_len(Some(4))
+        .finish()
+        .unwrap_err();
+    assert_eq!(CsvError::from_polars(&err), Some(&too_long));
+    // fields after the projected ones are checked as well
+    let err = CsvReader::new(Cursor::new(csv))
+        .with_max_field_len(Some(4))
+        .with_projection(Some(vec![0]))
+        .finish()
+        .unwrap_err();
+    assert_eq!(CsvError::from_polars(&err), Some(&too_long));
+
+    // the field and the rest of its row are nulls
+    let (df, report) = CsvReader::new(Cursor::new(csv))
+        .with_max_field_len(Some(4))
+        .with_ignore_errors(true)
+        .finish_with_error_report()?;
+    let expected = df![
+        "a" => [1i64, 2, 3, 4],
+        "b" => [Some("x"), None, Some("def"), Some("g")],
+        "c" => [Some("y"), None, Some("z"), Some("h")]
+    ]?;
+    assert!(df.equals_missing(&expected));
+    assert_eq!(report.len(), 1);
+    assert_eq!((report[0].line, report[0].offset), (3, 14));
+    assert_eq!(report[0].column, "b");
+
+    // closed quotes within the limit are fine
+    let csv = "a,b\n1,\"ab\"\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_max_field_len(Some(4))
+        .finish()?;
+    assert_eq!(df.column("b")?.str()?.get(0), Some("ab"));
+    Ok(())
+}
+
+#[test]
+fn test_projection_wide_rows() -> PolarsResult<()> {
+    // 100 columns of which only the first 3 are read, the tail has quoted separators
+    let n_rows = 1_000;
+    let mut csv = (0..100)
+        .map(|i| format!("c{i}"))
+        .collect::<Vec<_>>()
+        .join(",");
+    csv.push('\n');
+    for i in 0..n_rows {
+        csv.push_str(&format!("{i},{},s{i}", 2 * i));
+        for j in 3..100 {
+            match j % 3 {
+                0 => csv.push_str(&format!(",{j}")),
+                1 => csv.push_str(",\"a,b,c\""),
+                _ => csv.push_str(",\"say \"\"x,y\"\"\""),
+            }
+        }
+        csv.push('\n');
+    }
+
+    let check = |df: DataFrame| -> PolarsResult<()> {
+        assert_eq!(df.get_column_names(), &["c0", "c1", "c2"]);
+        assert_eq!(df.height(), n_rows);
+        assert_eq!(
+            df.column("c0")?.i64()?.sum(),
+            Some((0..n_rows as i64).sum())
+        );
+        assert_eq!(
+            df.column("c1")?.i64()?.get(n_rows - 1),
+            Some(2 * (n_rows as i64 - 1))
+        );
+        assert_eq!(df.column("c2")?.str()?.get(7), Some("s7"));
+        Ok(())
+    };
+    check(
+        CsvReader::new(Cursor::new(&csv))
+            .with_projection(Some(vec![0, 1, 2]))
+            .finish()?,
+    )?;
+
+    // an escaped quote doesn't close the field
+    let csv = csv.replace("\"say \"\"x,y\"\"\"", "\"say \\\"x,y\"");
+    check(
+        CsvReader::new(Cursor::new(&csv))
+            .with_escape_char(Some(b'\\'))
+            .with_projection(Some(vec![0, 1, 2]))
+            .finish()?,
+    )
+}
+
 #[test]
 fn test_empty_col_names() -> PolarsResult<()> {
     let csv = "a,b,c\n1,2,3";
@@ -1096,6 +2311,17 @@ fn test_header_only() -> PolarsResult<()> {
             df.dtypes(),
             &[DataType::String, DataType::String, DataType::String]
         );
+
+        // only the selected columns
+        let df = CsvReader::new(Cursor::new(csv))
+            .with_columns(Some(vec!["y".to_string()]))
+            .finish()?;
+        assert_eq!(df.shape(), (0, 1));
+        assert_eq!(df.get_column_names(), &["y"]);
+        let df = CsvReader::new(Cursor::new(csv))
+            .with_projection(Some(vec![0, 2]))
+            .finish()?;
+        assert_eq!(df.get_column_names(), &["x", "z"]);
     }
 
     Ok(())
@@ -1131,6 +2357,31 @@ fn test_try_parse_dates() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_try_parse_dates_ymd_patterns() -> PolarsResult<()> {
+    let csv = "iso,slash
+2021-03-04,2021/03/04
+2021-03-05,2021/03/05
+2021-03-06,not a date
+";
+    let file = Cursor::new(csv);
+    let out = CsvReader::new(file)
+        .infer_schema(Some(2))
+        .with_try_parse_dates(true)
+        .with_ignore_errors(true)
+        .finish()?;
+    assert_eq!(out.dtypes(), &[DataType::Date, DataType::Date]);
+    assert_eq!(out.column("iso")?.null_count(), 0);
+    // a field that does not match the inferred format becomes null
+    assert_eq!(out.column("slash")?.null_count(), 1);
+
+    // opt-in: dates stay strings by default
+    let file = Cursor::new(csv);
+    let out = CsvReader::new(file).finish()?;
+    assert_eq!(out.dtypes(), &[DataType::String, DataType::String]);
+    Ok(())
+}
+
 #[test]
 fn test_try_parse_dates_3380() -> PolarsResult<()> {
     let csv = "lat;lon;validdate;t_2m:C;precip_1h:mm
@@ -1159,3 +2410,554 @@ fn test_leading_whitespace_with_quote() -> PolarsResult<()> {
     assert_eq!(col_2.get(0)?, AnyValue::String("  4.1"));
     Ok(())
 }
+
+#[test]
+fn test_error_report() -> PolarsResult<()> {
+    let csv = "a,b\n1,x\n2,3\n\"multi\nline\",4\n5,6.5\n";
+    let schema = Schema::from_iter([
+        Field::new("a", DataType::Int64),
+        Field::new("b", DataType::Int64),
+    ]);
+    let (df, errors) = CsvReader::new(Cursor::new(csv))
+        .with_schema(Some(Arc::new(schema.clone())))
+        .with_ignore_errors(true)
+        .finish_with_error_report()?;
+    assert_eq!(
+        Vec::from(df.column("a")?.i64()?),
+        &[Some(1), Some(2), None, Some(5)]
+    );
+    assert_eq!(
+        Vec::from(df.column("b")?.i64()?),
+        &[None, Some(3), Some(4), None]
+    );
+
+    let lines = errors.iter().map(|e| e.line).collect::<Vec<_>>();
+    assert_eq!(lines, &[2, 4, 6]);
+    let columns = errors.iter().map(|e| e.column.as_str()).collect::<Vec<_>>();
+    assert_eq!(columns, &["b", "a", "b"]);
+    assert_eq!(errors[0].raw, "x");
+    assert_eq!(errors[1].raw, "\"multi\nline\"");
+    assert_eq!(errors[2].raw, "6.5");
+    assert_eq!(&csv[errors[2].offset..errors[2].offset + 3], "6.5");
+    assert!(errors[0].reason.contains("i64"));
+
+    // without `ignore_errors` the first error is raised
+    let out = CsvReader::new(Cursor::new(csv))
+        .with_schema(Some(Arc::new(schema)))
+        .finish_with_error_report();
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_read_stats() -> PolarsResult<()> {
+    // a short row, a long row and two fields that aren't integers
+    let csv = "a,b\n1,x\n2\n3,4\n5,6,7\n8,9.5\n";
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("a", DataType::Int64),
+        Field::new("b", DataType::Int64),
+    ]));
+    let (df, stats) = CsvReader::new(Cursor::new(csv))
+        .with_schema(Some(schema.clone()))
+        .with_ignore_errors(true)
+        .with_ragged_lines(RaggedPolicy::Skip)
+        .finish_with_stats()?;
+    assert_eq!(
+        Vec::from(df.column("a")?.i64()?),
+        &[Some(1), Some(3), Some(8)]
+    );
+    assert_eq!(
+        stats,
+        ReadStats {
+            rows_parsed: 3,
+            rows_skipped: 2,
+            fields_coerced_to_null: 2,
+        }
+    );
+
+    // nothing is lost in a clean file
+    let (_, stats) = CsvReader::new(Cursor::new("a,b\n1,2\n3,4\n"))
+        .with_schema(Some(schema))
+        .finish_with_stats()?;
+    assert_eq!(
+        stats,
+        ReadStats {
+            rows_parsed: 2,
+            ..Default::default()
+        }
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_categorical_columns() -> PolarsResult<()> {
+    let csv = "sepal_length,variety\n5.1,Setosa\n7.0,Versicolor\n6.3,Virginica\n4.9,Setosa\n";
+    let expected = [
+        Some("Setosa"),
+        Some("Versicolor"),
+        Some("Virginica"),
+        Some("Setosa"),
+    ];
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_categorical_columns(Some(vec!["variety".into()]))
+        .finish()?;
+    let variety = df.column("variety")?;
+    assert!(matches!(variety.dtype(), DataType::Categorical(_, _)));
+    assert_eq!(variety.n_unique()?, 3);
+    assert_eq!(
+        Vec::from(variety.cast(&DataType::String)?.str()?),
+        &expected
+    );
+    assert_eq!(df.column("sepal_length")?.dtype(), &DataType::Float64);
+
+    // this takes precedence over a given schema
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("sepal_length", DataType::Float64),
+        Field::new("variety", DataType::String),
+    ]));
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_schema(Some(schema.clone()))
+        .with_categorical_columns(Some(vec!["variety".into()]))
+        .finish()?;
+    let variety = df.column("variety")?;
+    assert!(matches!(variety.dtype(), DataType::Categorical(_, _)));
+    assert_eq!(
+        Vec::from(variety.cast(&DataType::String)?.str()?),
+        &expected
+    );
+
+    let res = CsvReader::new(Cursor::new(csv))
+        .with_schema(Some(schema))
+        .with_categorical_columns(Some(vec!["species".into()]))
+        .finish();
+    assert!(res.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_numeric_coercion() -> PolarsResult<()> {
+    let csv = "a,b,c\n1,2.5,true\nn/a,x,false\n3,4,true\n";
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("a", DataType::Int64),
+        Field::new("b", DataType::Float64),
+        Field::new("c", DataType::Boolean),
+    ]));
+    let read = |csv: &'static str, mode| {
+        CsvReader::new(Cursor::new(csv))
+            .with_schema(Some(schema.clone()))
+            .with_numeric_coercion(mode)
+            .finish_with_stats()
+    };
+    assert!(read(csv, CoercionMode::Strict).is_err());
+
+    let (df, stats) = read(csv, CoercionMode::LenientNull)?;
+    assert_eq!(Vec::from(df.column("a")?.i64()?), &[Some(1), None, Some(3)]);
+    assert_eq!(
+        Vec::from(df.column("b")?.f64()?),
+        &[Some(2.5), None, Some(4.0)]
+    );
+    assert_eq!(stats.fields_coerced_to_null, 2);
+
+    // other columns are still strict
+    assert!(read("a,b,c\n1,2.5,maybe\n", CoercionMode::LenientNull).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_truncate_whitespace() -> PolarsResult<()> {
+    let csv = "a,b,c\n 5.1, 3 ,\" padded \"\n   ,4 ,  y\n2.5,NA ,z\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_truncate_whitespace(true)
+        .with_null_values(Some(NullValues::AllColumnsSingle("NA".to_string())))
+        .finish()?;
+    assert_eq!(
+        df.dtypes(),
+        &[DataType::Float64, DataType::Int64, DataType::String]
+    );
+    assert_eq!(
+        Vec::from(df.column("a")?.f64()?),
+        &[Some(5.1), None, Some(2.5)]
+    );
+    assert_eq!(Vec::from(df.column("b")?.i64()?), &[Some(3), Some(4), None]);
+    // whitespace inside quotes is kept
+    assert_eq!(
+        Vec::from(df.column("c")?.str()?),
+        &[Some(" padded "), Some("y"), Some("z")]
+    );
+
+    // opt-in: padded fields are read as strings by default
+    let df = CsvReader::new(Cursor::new(csv)).finish()?;
+    assert_eq!(df.column("a")?.dtype(), &DataType::String);
+    Ok(())
+}
+
+#[test]
+fn test_column_renames() -> PolarsResult<()> {
+    let csv = "Sepal Length (cm),species\n5.1,setosa\n7.0,versicolor\n";
+    let renames = Some(vec![(
+        "Sepal Length (cm)".to_string(),
+        "sepal_length".to_string(),
+    )]);
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_column_renames(renames.clone())
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["sepal_length", "species"]);
+    assert_eq!(df.column("sepal_length")?.dtype(), &DataType::Float64);
+
+    // projections may use the old or the new name
+    for name in ["Sepal Length (cm)", "sepal_length"] {
+        let df = CsvReader::new(Cursor::new(csv))
+            .with_column_renames(renames.clone())
+            .with_columns(Some(vec![name.to_string()]))
+            .finish()?;
+        assert_eq!(df.get_column_names(), &["sepal_length"]);
+    }
+
+    let out = CsvReader::new(Cursor::new(csv))
+        .with_column_renames(Some(vec![(
+            "Sepal Length (cm)".to_string(),
+            "species".to_string(),
+        )]))
+        .finish();
+    assert!(matches!(out, Err(PolarsError::Duplicate(_))));
+    Ok(())
+}
+
+#[test]
+fn test_dedup_column_names() -> PolarsResult<()> {
+    let csv = "value,value,value,value_duplicated_1\n1,2,3,4\n";
+    let df = CsvReader::new(Cursor::new(csv)).finish()?;
+    assert_eq!(
+        df.get_column_names(),
+        &[
+            "value",
+            "value_duplicated_0",
+            "value_duplicated_2",
+            "value_duplicated_1"
+        ]
+    );
+    assert_eq!(df.column("value_duplicated_2")?.get(0)?, AnyValue::Int64(3));
+
+    let out = CsvReader::new(Cursor::new(csv))
+        .with_dedup_column_names(false)
+        .finish();
+    assert!(matches!(out, Err(PolarsError::Duplicate(_))));
+    Ok(())
+}
+
+#[test]
+fn test_with_columns_skips_unselected() -> PolarsResult<()> {
+    let n_cols = 200;
+    let header = (0..n_cols).map(|i| format!("c{i}")).collect::<Vec<_>>();
+    let mut csv = header.join(",");
+    csv.push('\n');
+    for row in 0..100 {
+        let fields = (0..n_cols)
+            .map(|i| {
+                if i % 40 == 0 {
+                    format!("{}", row * i)
+                } else {
+                    // would fail to parse as an integer
+                    "x".to_string()
+                }
+            })
+            .collect::<Vec<_>>();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    let schema = header
+        .iter()
+        .map(|name| Field::new(name, DataType::Int64))
+        .collect::<Schema>();
+    let selected = ["c0", "c40", "c80", "c120", "c160"].map(String::from);
+
+    let df = CsvReader::new(Cursor::new(csv.clone()))
+        .with_schema(Some(Arc::new(schema)))
+        .with_columns(Some(selected.to_vec()))
+        .finish()?;
+    assert_eq!(df.shape(), (100, 5));
+    assert_eq!(df.get_column_names(), &selected);
+    assert_eq!(df.column("c40")?.get(2)?, AnyValue::Int64(80));
+
+    let out = CsvReader::new(Cursor::new(csv))
+        .with_columns(Some(selected.to_vec()))
+        .with_projection(Some(vec![0, 1]))
+        .finish();
+    assert!(matches!(out, Err(PolarsError::InvalidOperation(_))));
+    Ok(())
+}
+
+#[test]
+fn test_columns_regex() -> PolarsResult<()> {
+    let header = (1..=12)
+        .map(|i| format!("sensor_{i:02}"))
+        .chain(["time".to_string(), "sensor_note".to_string()])
+        .collect::<Vec<_>>();
+    let row = (0..header.len()).map(|i| i.to_string()).collect::<Vec<_>>();
+    let csv = format!(
+        "{}\n{}\n{}\n",
+        header.join(","),
+        row.join(","),
+        row.join(",")
+    );
+
+    let df = CsvReader::new(Cursor::new(csv.as_str()))
+        .with_columns_regex(Some(r"^sensor_\d+$".into()))
+        .finish()?;
+    assert_eq!(df.shape(), (2, 12));
+    assert_eq!(df.get_column_names(), &header[..12]);
+    assert_eq!(df.column("sensor_12")?.get(0)?, AnyValue::Int64(11));
+
+    let out = CsvReader::new(Cursor::new(csv.as_str()))
+        .with_columns_regex(Some("^sensor_(".into()))
+        .finish();
+    assert!(matches!(out, Err(PolarsError::ComputeError(_))));
+    let out = CsvReader::new(Cursor::new(csv.as_str()))
+        .with_columns_regex(Some("^pressure_".into()))
+        .finish();
+    assert!(matches!(out, Err(PolarsError::ColumnNotFound(_))));
+    Ok(())
+}
+
+#[test]
+fn test_schema_only() -> PolarsResult<()> {
+    let csv = "# comment\na,b,c\n1,x,2.5\n2,y,3.5\n";
+    let mut reader = CsvReader::new(Cursor::new(csv))
+        .with_comment_prefix(Some("#"))
+        .with_dtypes_slice(Some(&[DataType::Float64]));
+    let schema = reader.schema()?;
+    assert_eq!(
+        schema,
+        Schema::from_iter([
+            Field::new("a", DataType::Float64),
+            Field::new("b", DataType::String),
+            Field::new("c", DataType::Float64),
+        ])
+    );
+
+    // the reader can still be finished and sees all rows
+    let df = reader.finish()?;
+    assert_eq!(df.schema(), schema);
+    assert_eq!(df.height(), 2);
+
+    let schema = CsvReader::new(Cursor::new(csv))
+        .with_comment_prefix(Some("#"))
+        .has_header(false)
+        .schema()?;
+    assert_eq!(
+        schema.iter_names().map(|s| s.as_str()).collect::<Vec<_>>(),
+        &["column_1", "column_2", "column_3"]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_from_bytes() -> PolarsResult<()> {
+    let csv = "a,b\n1,x\n2,y\n";
+    let df = CsvReader::from_bytes(csv.as_bytes())
+        .with_n_rows(Some(1))
+        .finish()?;
+    assert_eq!(df.shape(), (1, 2));
+
+    // owned buffers work as well
+    let df = CsvReader::from_bytes(csv.to_string())
+        .has_header(false)
+        .finish()?;
+    assert_eq!(df.shape(), (3, 2));
+    Ok(())
+}
+
+#[test]
+fn test_read_csv_from_reader_stream() -> PolarsResult<()> {
+    // like a pipe, it can only be read front to back and hands out small reads
+    struct Pipe<'a>(&'a [u8]);
+
+    impl std::io::Read for Pipe<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = buf.len().min(self.0.len()).min(3);
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    let csv = "a,b\n1,x\n2,y\n3,z\n";
+    let df = CsvReader::from_reader_stream(Pipe(csv.as_bytes()))?
+        .with_n_threads(Some(4))
+        .finish()?;
+    let expected = df![
+        "a" => [1i64, 2, 3],
+        "b" => ["x", "y", "z"]
+    ]?;
+    assert!(df.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_decimal_comma() -> PolarsResult<()> {
+    let csv = "a;b;c\n3,5;1;x,y\n-0,25;2;z\n1e3;3;w\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_separator(b';')
+        .with_decimal_comma(true)
+        .finish()?;
+    assert_eq!(
+        df.dtypes(),
+        &[DataType::Float64, DataType::Int64, DataType::String]
+    );
+    assert_eq!(
+        Vec::from(df.column("a")?.f64()?),
+        &[Some(3.5), Some(-0.25), Some(1000.0)]
+    );
+    assert_eq!(
+        Vec::from(df.column("b")?.i64()?),
+        &[Some(1), Some(2), Some(3)]
+    );
+    // string columns are not affected
+    assert_eq!(df.column("c")?.get(0)?, AnyValue::String("x,y"));
+
+    // an explicit float dtype is parsed with the decimal comma as well
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_separator(b';')
+        .with_decimal_comma(true)
+        .with_dtypes_slice(Some(&[DataType::Float32]))
+        .finish()?;
+    assert_eq!(
+        Vec::from(df.column("a")?.f32()?),
+        &[Some(3.5), Some(-0.25), Some(1000.0)]
+    );
+
+    let out = CsvReader::new(Cursor::new(csv))
+        .with_decimal_comma(true)
+        .finish();
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_thousands_separator() -> PolarsResult<()> {
+    let csv = "a,b,c\n\"1,234\",\"1,234,567.89\",\"x,y\"\n5,\"-2,000.5\",z\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_thousands_separator(Some(b','))
+        .finish()?;
+    assert_eq!(Vec::from(df.column("a")?.i64()?), &[Some(1234), Some(5)]);
+    assert_eq!(
+        Vec::from(df.column("b")?.f64()?),
+        &[Some(1234567.89), Some(-2000.5)]
+    );
+    // the separator is kept in string fields
+    assert_eq!(Vec::from(df.column("c")?.str()?), &[Some("x,y"), Some("z")]);
+
+    let csv = "a;b\n1.234.567,89;1.000\n2,5;7\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_separator(b';')
+        .with_decimal_comma(true)
+        .with_thousands_separator(Some(b'.'))
+        .finish()?;
+    assert_eq!(
+        Vec::from(df.column("a")?.f64()?),
+        &[Some(1234567.89), Some(2.5)]
+    );
+    assert_eq!(Vec::from(df.column("b")?.i64()?), &[Some(1000), Some(7)]);
+
+    let out = CsvReader::new(Cursor::new(csv))
+        .with_separator(b';')
+        .with_thousands_separator(Some(b'.'))
+        .finish();
+    assert!(out.is_err());
+
+    // the separator is only removed between groups of three digits
+    let csv = "a\n\"1,234\"\n\"1,2,3\"\n\"12,34\"\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_thousands_separator(Some(b','))
+        .finish()?;
+    assert_eq!(df.column("a")?.dtype(), &DataType::String);
+    let dtypes = [DataType::Int64];
+    let out = CsvReader::new(Cursor::new(csv))
+        .with_thousands_separator(Some(b','))
+        .with_dtypes_slice(Some(&dtypes))
+        .finish();
+    assert!(out.is_err());
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_thousands_separator(Some(b','))
+        .with_dtypes_slice(Some(&dtypes))
+        .with_ignore_errors(true)
+        .finish()?;
+    assert_eq!(Vec::from(df.column("a")?.i64()?), &[Some(1234), None, None]);
+    Ok(())
+}
+
+#[test]
+fn test_infer_bool_columns() -> PolarsResult<()> {
+    let csv = "a,b,c\ntrue,1,True\nfalse,2,\n,3,FALSE\n";
+    let df = CsvReader::new(Cursor::new(csv)).finish()?;
+    assert_eq!(
+        df.dtypes(),
+        &[DataType::Boolean, DataType::Int64, DataType::Boolean]
+    );
+    assert_eq!(
+        Vec::from(df.column("a")?.bool()?),
+        &[Some(true), Some(false), None]
+    );
+    assert_eq!(
+        Vec::from(df.column("c")?.bool()?),
+        &[Some(true), None, Some(false)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_bool_values() -> PolarsResult<()> {
+    let csv = "a,b\nYes,T\nNo,F\nYes,unknown\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_bool_values(
+            vec!["Yes".into(), "T".into()],
+            vec!["No".into(), "F".into()],
+        )
+        .with_dtypes(Some(Arc::new(Schema::from_iter([Field::new(
+            "b",
+            DataType::Boolean,
+        )]))))
+        .finish()?;
+    assert_eq!(df.column("a")?.dtype(), &DataType::Boolean);
+    assert_eq!(
+        Vec::from(df.column("a")?.bool()?),
+        &[Some(true), Some(false), Some(true)]
+    );
+    // tokens that are in neither set are null
+    assert_eq!(
+        Vec::from(df.column("b")?.bool()?),
+        &[Some(true), Some(false), None]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_inference_null_threshold() -> PolarsResult<()> {
+    let mut csv = "a,b\n1,1\n2,\n3,2\n".to_string();
+    for i in 4..=10 {
+        csv.push_str(&format!("{i},\n"));
+    }
+    let df = CsvReader::new(Cursor::new(csv.as_str())).finish()?;
+    assert_eq!(df.column("b")?.dtype(), &DataType::Int64);
+
+    // 2 of the 10 rows have a value in "b"
+    let df = CsvReader::new(Cursor::new(csv.as_str()))
+        .with_inference_null_threshold(0.5)
+        .finish()?;
+    assert_eq!(df.column("a")?.dtype(), &DataType::Int64);
+    assert_eq!(df.column("b")?.dtype(), &DataType::String);
+    assert_eq!(df.column("b")?.null_count(), 8);
+    assert_eq!(df.column("b")?.str()?.get(2), Some("2"));
+
+    let df = CsvReader::new(Cursor::new(csv.as_str()))
+        .with_inference_null_threshold(0.2)
+        .finish()?;
+    assert_eq!(df.column("b")?.dtype(), &DataType::Int64);
+
+    let res = CsvReader::new(Cursor::new(csv.as_str()))
+        .with_inference_null_threshold(1.5)
+        .finish();
+    assert!(res.is_err());
+    Ok(())
+}
@@ -1,6 +1,8 @@
 use std::fmt::{Debug, Formatter};
 use std::io::{Error, ErrorKind};
 
+#[cfg(feature = "csv")]
+use polars::io::csv::CsvError;
 use polars::prelude::PolarsError;
 use polars_error::PolarsWarning;
 use pyo3::exceptions::{
@@ -41,6 +43,11 @@ impl std::convert::From<PyPolarsErr> for PyErr {
                     InvalidOperationError::new_err(err.to_string())
                 },
                 PolarsError::Io(err) => match err.kind() {
+                    // malformed csv data is reported like the other compute errors
+                    #[cfg(feature = "csv")]
+                    ErrorKind::InvalidData if err.get_ref().is_some_and(|e| e.is::<CsvError>()) => {
+                        ComputeError::new_err(err.to_string())
+                    },
                     ErrorKind::NotFound => PyFileNotFoundError::new_err(err.to_string()),
                     ErrorKind::PermissionDenied => PyPermissionError::new_err(err.to_string()),
                     ErrorKind::AlreadyExists => PyFileExistsError::new_err(err.to_string()),
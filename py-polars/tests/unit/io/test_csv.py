@@ -621,7 +621,7 @@ def test_empty_line_with_multiple_columns() -> None:
         comment_prefix="#",
         use_pyarrow=False,
     )
-    expected = pl.DataFrame({"A": ["a", None, "c"], "B": ["b", None, "d"]})
+    expected = pl.DataFrame({"A": ["a", "c"], "B": ["b", "d"]})
     assert_frame_equal(df, expected)
 
 